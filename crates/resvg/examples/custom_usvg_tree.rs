@@ -9,6 +9,8 @@ fn main() {
             aspect: usvg::AspectRatio::default(),
         },
         root: usvg::Node::new(usvg::NodeKind::Group(usvg::Group::default())),
+        clip_to_viewport: true,
+        font_faces: Vec::new(),
     };
 
     let gradient = usvg::LinearGradient {
@@ -33,6 +35,7 @@ fn main() {
                     opacity: usvg::Opacity::ZERO,
                 },
             ],
+            color_interpolation: usvg::filter::ColorInterpolation::SRGB,
         },
     };
 
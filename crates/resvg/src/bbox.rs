@@ -0,0 +1,113 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::clip::ClipPath;
+use crate::mask::Mask;
+use crate::tree::{Group, Node, Tree};
+
+impl Tree {
+    /// Calculates a bounding box of all painted content in canvas coordinates.
+    ///
+    /// Unlike [`Tree::content_area`], the result is in the coordinates of an image
+    /// of [`Tree::size`], i.e. with the `viewBox` transform already applied.
+    /// Includes strokes and filter regions and is reduced by clip paths, masks
+    /// and the viewport, unless [`Tree::clip_to_viewport`] is disabled.
+    ///
+    /// Can be used to trim empty space around the content.
+    ///
+    /// Returns `None` when nothing would be painted.
+    pub fn content_bbox(&self) -> Option<tiny_skia::Rect> {
+        let ts =
+            usvg::utils::view_box_to_transform(self.view_box.rect, self.view_box.aspect, self.size);
+        let bbox = nodes_bbox(&self.children, ts).to_rect()?;
+        if self.clip_to_viewport {
+            bbox.intersect(&self.size.to_non_zero_rect(0.0, 0.0).to_rect())
+        } else {
+            Some(bbox)
+        }
+    }
+}
+
+fn nodes_bbox(nodes: &[Node], transform: tiny_skia::Transform) -> usvg::BBox {
+    let mut bbox = usvg::BBox::default();
+    for node in nodes {
+        let node_bbox = match node {
+            Node::Group(ref group) => group_bbox(group, transform),
+            Node::FillPath(ref path) => {
+                let ts = transform.pre_concat(path.transform);
+                path_bbox(&path.path, ts)
+            }
+            Node::StrokePath(ref path) => {
+                let ts = transform.pre_concat(path.transform);
+                let res_scale = tiny_skia::PathStroker::compute_resolution_scale(&ts);
+                path.path
+                    .stroke(&path.stroke, res_scale)
+                    .and_then(|p| path_bbox(&p, ts))
+            }
+            Node::Image(ref image) => {
+                let ts = transform.pre_concat(image.transform);
+                image.view_box.rect.to_rect().transform(ts)
+            }
+        };
+
+        if let Some(node_bbox) = node_bbox {
+            bbox = bbox.expand(node_bbox);
+        }
+    }
+
+    bbox
+}
+
+fn path_bbox(path: &tiny_skia::Path, transform: tiny_skia::Transform) -> Option<tiny_skia::Rect> {
+    // Transform the path itself and not its bounds to get a tight bbox for rotated paths.
+    path.clone().transform(transform).map(|p| p.bounds())
+}
+
+fn group_bbox(group: &Group, transform: tiny_skia::Transform) -> Option<tiny_skia::Rect> {
+    if !group.visible {
+        return None;
+    }
+
+    let transform = transform.pre_concat(group.transform);
+
+    // Filters can paint anywhere inside the filter region.
+    let mut bbox = if !group.filters.is_empty() {
+        group.bbox.transform(transform)?
+    } else {
+        nodes_bbox(&group.children, transform).to_rect()?
+    };
+
+    if let Some(ref clip_path) = group.clip_path {
+        bbox = bbox.intersect(&clip_path_bbox(clip_path, transform)?)?;
+    }
+
+    if let Some(ref mask) = group.mask {
+        bbox = bbox.intersect(&mask_bbox(mask, transform)?)?;
+    }
+
+    Some(bbox)
+}
+
+fn clip_path_bbox(clip: &ClipPath, transform: tiny_skia::Transform) -> Option<tiny_skia::Rect> {
+    let bbox = nodes_bbox(&clip.children, transform.pre_concat(clip.transform)).to_rect()?;
+    match clip.clip_path {
+        Some(ref sub_clip) => bbox.intersect(&clip_path_bbox(sub_clip, transform)?),
+        None => Some(bbox),
+    }
+}
+
+fn mask_bbox(mask: &Mask, transform: tiny_skia::Transform) -> Option<tiny_skia::Rect> {
+    if mask.mask_all {
+        return None;
+    }
+
+    // Everything outside the mask region and the mask content is transparent.
+    let region = mask.region.transform(transform)?;
+    let content = nodes_bbox(&mask.children, transform.pre_concat(mask.content_transform));
+    let bbox = region.intersect(&content.to_rect()?)?;
+    match mask.mask {
+        Some(ref sub_mask) => bbox.intersect(&mask_bbox(sub_mask, transform)?),
+        None => Some(bbox),
+    }
+}
@@ -38,19 +38,26 @@ pub fn convert(
     })
 }
 
-pub fn apply(clip: &ClipPath, transform: tiny_skia::Transform, pixmap: &mut tiny_skia::Pixmap) {
+pub fn apply(
+    clip: &ClipPath,
+    ctx: &Context,
+    transform: tiny_skia::Transform,
+    pixmap: &mut tiny_skia::Pixmap,
+) {
     let mut clip_pixmap = tiny_skia::Pixmap::new(pixmap.width(), pixmap.height()).unwrap();
     clip_pixmap.fill(tiny_skia::Color::BLACK);
+    ctx.update_stats(|stats| stats.layers += 1);
 
     draw_children(
         &clip.children,
         tiny_skia::BlendMode::Clear,
+        ctx,
         transform.pre_concat(clip.transform),
         &mut clip_pixmap.as_mut(),
     );
 
     if let Some(ref clip) = clip.clip_path {
-        apply(clip, transform, pixmap);
+        apply(clip, ctx, transform, pixmap);
     }
 
     let mut mask = tiny_skia::Mask::from_pixmap(clip_pixmap.as_ref(), tiny_skia::MaskType::Alpha);
@@ -61,18 +68,15 @@ pub fn apply(clip: &ClipPath, transform: tiny_skia::Transform, pixmap: &mut tiny
 fn draw_children(
     children: &[Node],
     mode: tiny_skia::BlendMode,
+    ctx: &Context,
     transform: tiny_skia::Transform,
     pixmap: &mut tiny_skia::PixmapMut,
 ) {
     for child in children {
         match child {
             Node::FillPath(ref path) => {
-                // We could use any values here. They will not be used anyway.
-                let ctx = Context {
-                    max_bbox: tiny_skia::IntRect::from_xywh(0, 0, 1, 1).unwrap(),
-                };
-
-                crate::path::render_fill_path(path, mode, &ctx, transform, pixmap);
+                ctx.update_stats(|stats| stats.nodes_painted += 1);
+                crate::path::render_fill_path(path, mode, ctx, transform, pixmap);
             }
             Node::Group(ref group) => {
                 let transform = transform.pre_concat(group.transform);
@@ -81,9 +85,9 @@ fn draw_children(
                     // If a `clipPath` child also has a `clip-path`
                     // then we should render this child on a new canvas,
                     // clip it, and only then draw it to the `clipPath`.
-                    clip_group(&group.children, clip, transform, pixmap);
+                    clip_group(&group.children, clip, ctx, transform, pixmap);
                 } else {
-                    draw_children(&group.children, mode, transform, pixmap);
+                    draw_children(&group.children, mode, ctx, transform, pixmap);
                 }
             }
             _ => {}
@@ -94,18 +98,21 @@ fn draw_children(
 fn clip_group(
     children: &[Node],
     clip: &ClipPath,
+    ctx: &Context,
     transform: tiny_skia::Transform,
     pixmap: &mut tiny_skia::PixmapMut,
 ) -> Option<()> {
     let mut clip_pixmap = tiny_skia::Pixmap::new(pixmap.width(), pixmap.height()).unwrap();
+    ctx.update_stats(|stats| stats.layers += 1);
 
     draw_children(
         children,
         tiny_skia::BlendMode::SourceOver,
+        ctx,
         transform,
         &mut clip_pixmap.as_mut(),
     );
-    apply(clip, transform, &mut clip_pixmap);
+    apply(clip, ctx, transform, &mut clip_pixmap);
 
     let mut paint = tiny_skia::PixmapPaint::default();
     paint.blend_mode = tiny_skia::BlendMode::Xor;
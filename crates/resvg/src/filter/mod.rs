@@ -220,7 +220,7 @@ pub(crate) enum Error {
     NoResults,
 }
 
-trait PixmapExt: Sized {
+pub(crate) trait PixmapExt: Sized {
     fn try_create(width: u32, height: u32) -> Result<tiny_skia::Pixmap, Error>;
     fn copy_region(&self, region: IntRect) -> Result<tiny_skia::Pixmap, Error>;
     fn clear(&mut self);
@@ -372,15 +372,16 @@ fn from_linear_rgb(data: &mut [RGBA8]) {
 #[inline]
 fn f32_bound(min: f32, val: f32, max: f32) -> f32 {
     debug_assert!(min.is_finite());
-    debug_assert!(val.is_finite());
     debug_assert!(max.is_finite());
 
+    // `val` can be infinite or NaN when filter parameters are extremely large.
+    // NaN is treated as `min`.
     if val > max {
         max
-    } else if val < min {
-        min
-    } else {
+    } else if val >= min {
         val
+    } else {
+        min
     }
 }
 
@@ -645,7 +646,7 @@ fn calc_region(
     object_bbox: Option<tiny_skia::NonZeroRect>,
 ) -> Option<tiny_skia::NonZeroRect> {
     if filter.units == usvg::Units::ObjectBoundingBox {
-        Some(filter.rect.bbox_transform(object_bbox?))
+        crate::geom::bbox_transform(filter.rect, object_bbox?)
     } else {
         Some(filter.rect)
     }
@@ -694,10 +695,8 @@ fn calc_subregion(
                     primitive.height.unwrap_or(1.0),
                 )?;
 
-                let r = r.bbox_transform(bbox);
                 // .bbox_transform(ts_bbox);
-
-                return Some(r);
+                return crate::geom::bbox_transform(r, bbox);
             } else {
                 region
             }
@@ -714,7 +713,7 @@ fn calc_subregion(
             primitive.height.unwrap_or(1.0),
         )?;
 
-        region.bbox_transform(subregion_bbox)
+        crate::geom::bbox_transform(region, subregion_bbox)?
     } else {
         tiny_skia::NonZeroRect::from_xywh(
             primitive.x.unwrap_or(region.x()),
@@ -824,14 +823,17 @@ fn apply_drop_shadow(
         usvg::filter::ColorInterpolation::LinearRGB => shadow_pixmap.into_linear_rgb(),
     }
 
-    pixmap.draw_pixmap(
-        (fe.dx * sx) as i32,
-        (fe.dy * sy) as i32,
-        shadow_pixmap.as_ref(),
-        &tiny_skia::PixmapPaint::default(),
-        tiny_skia::Transform::identity(),
-        None,
-    );
+    let (dx, dy) = (fe.dx * sx, fe.dy * sy);
+    if is_valid_offset(dx, dy, &pixmap) {
+        pixmap.draw_pixmap(
+            dx as i32,
+            dy as i32,
+            shadow_pixmap.as_ref(),
+            &tiny_skia::PixmapPaint::default(),
+            tiny_skia::Transform::identity(),
+            None,
+        );
+    }
 
     pixmap.draw_pixmap(
         0,
@@ -883,6 +885,11 @@ fn apply_offset(
     }
 
     let mut pixmap = tiny_skia::Pixmap::try_create(input.width(), input.height())?;
+
+    // Everything was shifted outside the region.
+    if !is_valid_offset(dx, dy, &pixmap) {
+        return Ok(Image::from_image(pixmap, input.color_space));
+    }
     pixmap.draw_pixmap(
         dx as i32,
         dy as i32,
@@ -895,6 +902,11 @@ fn apply_offset(
     Ok(Image::from_image(pixmap, input.color_space))
 }
 
+/// Checks that an offset image would still intersect the region.
+fn is_valid_offset(dx: f32, dy: f32, pixmap: &tiny_skia::Pixmap) -> bool {
+    dx.abs() < pixmap.width() as f32 && dy.abs() < pixmap.height() as f32
+}
+
 fn apply_blend(
     fe: &usvg::filter::Blend,
     cs: usvg::filter::ColorInterpolation,
@@ -1356,6 +1368,12 @@ fn resolve_std_dev(mut std_dx: f32, mut std_dy: f32) -> Option<(f64, f64, bool)>
         std_dy = 0.0;
     }
 
+    // Blurring with a sigma this large produces a uniformly averaged image anyway.
+    // Larger values would only overflow box sizes.
+    const MAX_SIGMA: f32 = 1_000_000.0;
+    std_dx = std_dx.min(MAX_SIGMA);
+    std_dy = std_dy.min(MAX_SIGMA);
+
     const BLUR_SIGMA_THRESHOLD: f32 = 2.0;
     // Check that the current feGaussianBlur filter can be applied using a box blur.
     let box_blur = std_dx >= BLUR_SIGMA_THRESHOLD || std_dy >= BLUR_SIGMA_THRESHOLD;
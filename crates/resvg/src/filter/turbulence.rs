@@ -27,6 +27,10 @@ struct StitchInfo {
 
 /// Applies a turbulence filter.
 ///
+/// Mirrors the reference implementation from the SVG specification operation by operation,
+/// including the random numbers generator. Do not reorder floating-point operations,
+/// since the output must not depend on the platform.
+///
 /// `dest` image pixels will have an **unpremultiplied alpha**.
 ///
 /// - `offset_x` and `offset_y` indicate filter region offset.
@@ -232,13 +236,13 @@ fn noise2(
 ) -> f64 {
     let t = x + PERLIN_N as f64;
     let mut bx0 = t as i32;
-    let mut bx1 = bx0 + 1;
-    let rx0 = t - t as i64 as f64;
+    let mut bx1 = bx0.wrapping_add(1);
+    let rx0 = t - t.trunc();
     let rx1 = rx0 - 1.0;
     let t = y + PERLIN_N as f64;
     let mut by0 = t as i32;
-    let mut by1 = by0 + 1;
-    let ry0 = t - t as i64 as f64;
+    let mut by1 = by0.wrapping_add(1);
+    let ry0 = t - t.trunc();
     let ry1 = ry0 - 1.0;
 
     // If stitching, adjust lattice points accordingly.
@@ -30,6 +30,21 @@ pub fn fit_to_rect(
     tiny_skia::IntRect::from_ltrb(left, top, right, bottom)
 }
 
+/// Applies a bounding box transform.
+///
+/// Unlike `NonZeroRect::bbox_transform`, returns `None` on overflow instead of panicking.
+pub fn bbox_transform(
+    r: tiny_skia::NonZeroRect,
+    bbox: tiny_skia::NonZeroRect,
+) -> Option<tiny_skia::NonZeroRect> {
+    tiny_skia::NonZeroRect::from_xywh(
+        r.x() * bbox.width() + bbox.x(),
+        r.y() * bbox.height() + bbox.y(),
+        r.width() * bbox.width(),
+        r.height() * bbox.height(),
+    )
+}
+
 /// Converts `viewBox` to `Transform` with an optional clip rectangle.
 ///
 /// Unlike `view_box_to_transform`, returns an optional clip rectangle
@@ -12,6 +12,8 @@ pub enum ImageKind {
 }
 
 pub struct Image {
+    /// Element's ID. Can be empty.
+    pub id: String,
     pub transform: tiny_skia::Transform,
     pub view_box: usvg::ViewBox,
     pub quality: tiny_skia::FilterQuality,
@@ -36,9 +38,14 @@ pub fn convert(image: &usvg::Image, children: &mut Vec<Node>) -> Option<BBoxes>
     }
 
     let kind = match image.kind {
-        usvg::ImageKind::SVG(ref utree) => ImageKind::Vector(Tree::from_usvg(utree)),
+        usvg::ImageKind::SVG(ref utree) => {
+            let mut tree = Tree::from_usvg(utree);
+            // Images are already clipped by `render_vector`.
+            tree.clip_to_viewport = false;
+            ImageKind::Vector(tree)
+        }
         #[cfg(feature = "raster-images")]
-        _ => ImageKind::Raster(raster_images::decode_raster(image)?),
+        _ => ImageKind::Raster(raster_images::decode_raster(&image.kind)?),
         #[cfg(not(feature = "raster-images"))]
         _ => {
             log::warn!("Images decoding was disabled by a build feature.");
@@ -47,6 +54,7 @@ pub fn convert(image: &usvg::Image, children: &mut Vec<Node>) -> Option<BBoxes>
     };
 
     children.push(Node::Image(Image {
+        id: String::new(),
         transform: image.transform,
         view_box: image.view_box,
         quality,
@@ -56,6 +64,15 @@ pub fn convert(image: &usvg::Image, children: &mut Vec<Node>) -> Option<BBoxes>
     Some(bboxes)
 }
 
+/// Decodes a raster image.
+///
+/// Returns `None` for SVG images or when decoding fails.
+/// Can be used together with [`usvg::Tree::images`].
+#[cfg(feature = "raster-images")]
+pub fn decode_raster_image(kind: &usvg::ImageKind) -> Option<tiny_skia::Pixmap> {
+    raster_images::decode_raster(kind)
+}
+
 pub fn render_image(
     image: &Image,
     transform: tiny_skia::Transform,
@@ -112,8 +129,8 @@ mod raster_images {
     use crate::render::TinySkiaPixmapMutExt;
     use crate::tree::OptionLog;
 
-    pub fn decode_raster(image: &usvg::Image) -> Option<tiny_skia::Pixmap> {
-        match image.kind {
+    pub fn decode_raster(kind: &usvg::ImageKind) -> Option<tiny_skia::Pixmap> {
+        match kind {
             usvg::ImageKind::SVG(_) => None,
             usvg::ImageKind::JPEG(ref data) => {
                 decode_jpeg(data).log_none(|| log::warn!("Failed to decode a JPEG image."))
@@ -159,10 +176,16 @@ mod raster_images {
         Some(pixmap)
     }
 
+    /// Decodes the first frame of a GIF image.
+    ///
+    /// Animated images are not supported, so the first frame is always used,
+    /// just like in browsers when animations are disabled.
     fn decode_gif(data: &[u8]) -> Option<tiny_skia::Pixmap> {
         let mut decoder = gif::DecodeOptions::new();
         decoder.set_color_output(gif::ColorOutput::RGBA);
         let mut decoder = decoder.read_info(data).ok()?;
+        let screen_size =
+            tiny_skia::IntSize::from_wh(u32::from(decoder.width()), u32::from(decoder.height()))?;
         let first_frame = decoder.read_next_frame().ok()??;
 
         let size = tiny_skia::IntSize::from_wh(
@@ -171,8 +194,25 @@ mod raster_images {
         )?;
 
         let (w, h) = size.dimensions();
+        let mut frame = tiny_skia::Pixmap::new(w, h)?;
+        rgba_to_pixmap(&first_frame.buffer, &mut frame);
+
+        if size == screen_size && first_frame.left == 0 && first_frame.top == 0 {
+            return Some(frame);
+        }
+
+        // A frame can cover only a part of the logical screen.
+        // The rest is transparent, since the background color is ignored by browsers.
+        let (w, h) = screen_size.dimensions();
         let mut pixmap = tiny_skia::Pixmap::new(w, h)?;
-        rgba_to_pixmap(&first_frame.buffer, &mut pixmap);
+        pixmap.draw_pixmap(
+            i32::from(first_frame.left),
+            i32::from(first_frame.top),
+            frame.as_ref(),
+            &tiny_skia::PixmapPaint::default(),
+            tiny_skia::Transform::default(),
+            None,
+        );
         Some(pixmap)
     }
 
@@ -18,6 +18,7 @@
 pub use tiny_skia;
 pub use usvg;
 
+mod bbox;
 mod clip;
 mod filter;
 mod geom;
@@ -26,6 +27,13 @@ mod mask;
 mod paint_server;
 mod path;
 mod render;
+mod selection;
+mod stats;
 mod tree;
 
+#[cfg(feature = "raster-images")]
+pub use crate::image::decode_raster_image;
+pub use crate::render::MAX_SUPERSAMPLING_FACTOR;
+pub use crate::selection::LayerSelection;
+pub use crate::stats::RenderStats;
 pub use crate::tree::Tree;
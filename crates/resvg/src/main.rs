@@ -541,12 +541,21 @@ fn parse_args() -> Result<Args, String> {
             .take()
             .unwrap_or_else(|| "Times New Roman".to_string()),
         font_size: args.font_size as f32,
+        font_substitutions: std::collections::HashMap::new(),
         languages: args.languages,
         shape_rendering: args.shape_rendering,
         text_rendering: args.text_rendering,
         image_rendering: args.image_rendering,
         default_size,
         image_href_resolver: usvg::ImageHrefResolver::default(),
+        href_rewrite: None,
+        color_transform: None,
+        grid_snap: None,
+        preserve_use_sources: false,
+        preserve_unknown_attributes: false,
+        normalize_winding: false,
+        max_image_pixels: None,
+        max_image_bytes: None,
     };
 
     Ok(Args {
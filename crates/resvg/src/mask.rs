@@ -4,6 +4,7 @@
 
 use std::rc::Rc;
 
+use crate::filter::PixmapExt;
 use crate::render::Context;
 use crate::tree::{Node, OptionLog};
 
@@ -12,6 +13,7 @@ pub struct Mask {
     pub region: tiny_skia::Rect,
     pub content_transform: tiny_skia::Transform,
     pub kind: usvg::MaskType,
+    pub color_interpolation: usvg::filter::ColorInterpolation,
     pub mask: Option<Box<Self>>,
     pub children: Vec<Node>,
 }
@@ -38,7 +40,11 @@ pub fn convert(umask: Option<Rc<usvg::Mask>>, object_bbox: tiny_skia::Rect) -> O
 
     let region = if umask.units == usvg::Units::ObjectBoundingBox {
         if let Some(bbox) = object_bbox.to_non_zero_rect() {
-            umask.rect.bbox_transform(bbox)
+            crate::geom::bbox_transform(umask.rect, bbox).unwrap_or_else(|| {
+                // An overflowed mask region. Mask everything, just like with a zero-sized bbox.
+                mask_all = true;
+                tiny_skia::NonZeroRect::from_xywh(0.0, 0.0, 1.0, 1.0).unwrap()
+            })
         } else {
             // The actual values does not matter. Will not be used anyway.
             tiny_skia::NonZeroRect::from_xywh(0.0, 0.0, 1.0, 1.0).unwrap()
@@ -53,6 +59,7 @@ pub fn convert(umask: Option<Rc<usvg::Mask>>, object_bbox: tiny_skia::Rect) -> O
         region: region.to_rect(),
         content_transform,
         kind: umask.kind,
+        color_interpolation: umask.color_interpolation,
         mask: convert(umask.mask.clone(), object_bbox).map(Box::new),
         children,
     })
@@ -70,6 +77,7 @@ pub fn apply(
     }
 
     let mut mask_pixmap = tiny_skia::Pixmap::new(pixmap.width(), pixmap.height()).unwrap();
+    ctx.update_stats(|stats| stats.layers += 1);
 
     {
         // TODO: only when needed
@@ -98,7 +106,14 @@ pub fn apply(
     }
 
     let mask_type = match mask.kind {
-        usvg::MaskType::Luminance => tiny_skia::MaskType::Luminance,
+        usvg::MaskType::Luminance => {
+            if mask.color_interpolation == usvg::filter::ColorInterpolation::LinearRGB {
+                ctx.update_stats(|stats| stats.linear_rgb_masks += 1);
+                mask_pixmap.into_linear_rgb();
+            }
+
+            tiny_skia::MaskType::Luminance
+        }
         usvg::MaskType::Alpha => tiny_skia::MaskType::Alpha,
     };
 
@@ -16,10 +16,20 @@ pub struct Pattern {
     pub children: Vec<Node>,
 }
 
+pub struct ConicGradient {
+    pub center: tiny_skia::Point,
+    /// Start angle in radians.
+    pub angle: f32,
+    pub transform: tiny_skia::Transform,
+    /// Stop offsets and colors with opacity already applied.
+    pub stops: Vec<(f32, tiny_skia::Color)>,
+}
+
 #[derive(Clone)]
 pub enum Paint {
     Shader(tiny_skia::Shader<'static>),
     Pattern(Rc<Pattern>),
+    ConicGradient(Rc<ConicGradient>),
 }
 
 pub fn convert(
@@ -34,6 +44,7 @@ pub fn convert(
         }
         usvg::Paint::LinearGradient(ref lg) => convert_linear_gradient(lg, opacity, object_bbox),
         usvg::Paint::RadialGradient(ref rg) => convert_radial_gradient(rg, opacity, object_bbox),
+        usvg::Paint::ConicGradient(ref cg) => convert_conic_gradient(cg, opacity, object_bbox),
         usvg::Paint::Pattern(ref patt) => convert_pattern(patt, opacity, object_bbox),
     }
 }
@@ -75,6 +86,20 @@ fn convert_radial_gradient(
     Some(Paint::Shader(shader))
 }
 
+fn convert_conic_gradient(
+    gradient: &usvg::ConicGradient,
+    opacity: usvg::Opacity,
+    object_bbox: Option<tiny_skia::NonZeroRect>,
+) -> Option<Paint> {
+    let transform = convert_gradient_transform(gradient, object_bbox)?;
+    Some(Paint::ConicGradient(Rc::new(ConicGradient {
+        center: tiny_skia::Point::from_xy(gradient.cx, gradient.cy),
+        angle: gradient.angle.to_radians(),
+        transform,
+        stops: convert_stops(gradient, opacity),
+    })))
+}
+
 fn convert_base_gradient(
     gradient: &usvg::BaseGradient,
     opacity: usvg::Opacity,
@@ -90,16 +115,35 @@ fn convert_base_gradient(
         usvg::SpreadMethod::Repeat => tiny_skia::SpreadMode::Repeat,
     };
 
-    let transform = if gradient.units == usvg::Units::ObjectBoundingBox {
+    let transform = convert_gradient_transform(gradient, object_bbox)?;
+    let points = convert_stops(gradient, opacity)
+        .into_iter()
+        .map(|(offset, color)| tiny_skia::GradientStop::new(offset, color))
+        .collect();
+
+    Some((mode, transform, points))
+}
+
+fn convert_gradient_transform(
+    gradient: &usvg::BaseGradient,
+    object_bbox: Option<tiny_skia::NonZeroRect>,
+) -> Option<tiny_skia::Transform> {
+    if gradient.units == usvg::Units::ObjectBoundingBox {
         let bbox =
             object_bbox.log_none(|| log::warn!("Gradient on zero-sized shapes is not allowed."))?;
         let ts = tiny_skia::Transform::from_bbox(bbox);
-        ts.pre_concat(gradient.transform)
+        Some(ts.pre_concat(gradient.transform))
     } else {
-        gradient.transform
-    };
+        Some(gradient.transform)
+    }
+}
 
+fn convert_stops(
+    gradient: &usvg::BaseGradient,
+    opacity: usvg::Opacity,
+) -> Vec<(f32, tiny_skia::Color)> {
     let mut points = Vec::with_capacity(gradient.stops.len());
+    let mut prev: Option<(f32, tiny_skia::Color)> = None;
     for stop in &gradient.stops {
         let alpha = stop.opacity * opacity;
         let color = tiny_skia::Color::from_rgba8(
@@ -108,10 +152,84 @@ fn convert_base_gradient(
             stop.color.blue,
             alpha.to_u8(),
         );
-        points.push(tiny_skia::GradientStop::new(stop.offset.get(), color))
+        let offset = stop.offset.get();
+
+        if gradient.color_interpolation == usvg::filter::ColorInterpolation::LinearRGB {
+            if let Some((prev_offset, prev_color)) = prev {
+                if offset > prev_offset {
+                    push_linear_rgb_stops(prev_offset, prev_color, offset, color, &mut points);
+                }
+            }
+        }
+
+        points.push((offset, color));
+        prev = Some((offset, color));
     }
 
-    Some((mode, transform, points))
+    points
+}
+
+/// Number of intermediate stops used to approximate `linearRGB` interpolation,
+/// since tiny-skia can interpolate stops only in sRGB.
+const LINEAR_RGB_STEPS: u32 = 16;
+
+/// Adds stops between two stops interpolated in premultiplied linearRGB.
+fn push_linear_rgb_stops(
+    offset1: f32,
+    color1: tiny_skia::Color,
+    offset2: f32,
+    color2: tiny_skia::Color,
+    points: &mut Vec<(f32, tiny_skia::Color)>,
+) {
+    let premultiplied_linear = |c: tiny_skia::Color| {
+        [
+            srgb_to_linear(c.red()) * c.alpha(),
+            srgb_to_linear(c.green()) * c.alpha(),
+            srgb_to_linear(c.blue()) * c.alpha(),
+            c.alpha(),
+        ]
+    };
+
+    let c1 = premultiplied_linear(color1);
+    let c2 = premultiplied_linear(color2);
+    for i in 1..LINEAR_RGB_STEPS {
+        let t = i as f32 / LINEAR_RGB_STEPS as f32;
+        let mut c = [0.0; 4];
+        for (n, c) in c.iter_mut().enumerate() {
+            *c = c1[n] + (c2[n] - c1[n]) * t;
+        }
+
+        let a = c[3];
+        let unpremultiply = |v: f32| if a > 0.0 { linear_to_srgb(v / a) } else { 0.0 };
+        let color = tiny_skia::Color::from_rgba(
+            unpremultiply(c[0]),
+            unpremultiply(c[1]),
+            unpremultiply(c[2]),
+            a,
+        );
+
+        if let Some(color) = color {
+            let offset = offset1 + (offset2 - offset1) * t;
+            points.push((offset, color));
+        }
+    }
+}
+
+fn srgb_to_linear(v: f32) -> f32 {
+    if v <= 0.04045 {
+        v / 12.92
+    } else {
+        ((v + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn linear_to_srgb(v: f32) -> f32 {
+    let v = v.clamp(0.0, 1.0);
+    if v <= 0.0031308 {
+        v * 12.92
+    } else {
+        1.055 * v.powf(1.0 / 2.4) - 0.055
+    }
 }
 
 fn convert_pattern(
@@ -139,7 +257,7 @@ fn convert_pattern(
         let bbox =
             object_bbox.log_none(|| log::warn!("Pattern on zero-sized shapes is not allowed."))?;
 
-        pattern.rect.bbox_transform(bbox)
+        crate::geom::bbox_transform(pattern.rect, bbox)?
     } else {
         pattern.rect
     };
@@ -169,6 +287,7 @@ pub fn prepare_pattern_pixmap(
         (pattern.rect.height() * sy).round() as u32,
     )?;
     let mut pixmap = tiny_skia::Pixmap::new(img_size.width(), img_size.height())?;
+    ctx.update_stats(|stats| stats.layers += 1);
 
     let mut transform = tiny_skia::Transform::from_scale(sx, sy);
     if let Some(vbox) = pattern.view_box {
@@ -187,3 +306,69 @@ pub fn prepare_pattern_pixmap(
 
     Some((pixmap, ts))
 }
+
+/// Renders a conic gradient into a pixmap covering `bbox` on the canvas.
+///
+/// tiny-skia doesn't support sweep gradients, so each pixel is computed manually
+/// and painted using a pattern shader.
+///
+/// `bbox` is in the path coordinates and `transform` maps it onto the canvas.
+pub fn prepare_conic_gradient_pixmap(
+    gradient: &ConicGradient,
+    bbox: tiny_skia::Rect,
+    transform: tiny_skia::Transform,
+    canvas_size: tiny_skia::IntSize,
+) -> Option<(tiny_skia::Pixmap, tiny_skia::Transform)> {
+    let canvas_rect = canvas_size.to_int_rect(0, 0).to_rect();
+    let rect = bbox
+        .transform(transform)?
+        .intersect(&canvas_rect)?
+        .round_out()?;
+
+    let mut pixmap = tiny_skia::Pixmap::new(rect.width(), rect.height())?;
+    let inv_ts = transform.pre_concat(gradient.transform).invert()?;
+    let width = rect.width() as usize;
+    for (i, pixel) in pixmap.pixels_mut().iter_mut().enumerate() {
+        let mut p = tiny_skia::Point::from_xy(
+            rect.x() as f32 + (i % width) as f32 + 0.5,
+            rect.y() as f32 + (i / width) as f32 + 0.5,
+        );
+        inv_ts.map_point(&mut p);
+
+        // Zero angle points up and angles grow clockwise.
+        let angle = (p.x - gradient.center.x).atan2(gradient.center.y - p.y);
+        let t = (angle - gradient.angle) / std::f32::consts::TAU;
+        *pixel = sample_stops(&gradient.stops, t - t.floor());
+    }
+
+    let ts = transform
+        .invert()?
+        .pre_translate(rect.x() as f32, rect.y() as f32);
+    Some((pixmap, ts))
+}
+
+/// Interpolates stops in unpremultiplied sRGB, just like tiny-skia.
+fn sample_stops(stops: &[(f32, tiny_skia::Color)], t: f32) -> tiny_skia::PremultipliedColorU8 {
+    let color = match stops.iter().position(|(offset, _)| *offset > t) {
+        Some(0) => stops[0].1,
+        Some(idx) => {
+            let (offset1, c1) = stops[idx - 1];
+            let (offset2, c2) = stops[idx];
+            let k = (t - offset1) / (offset2 - offset1);
+            let lerp = |a: f32, b: f32| a + (b - a) * k;
+            tiny_skia::Color::from_rgba(
+                lerp(c1.red(), c2.red()),
+                lerp(c1.green(), c2.green()),
+                lerp(c1.blue(), c2.blue()),
+                lerp(c1.alpha(), c2.alpha()),
+            )
+            .unwrap_or(c2)
+        }
+        None => stops
+            .last()
+            .map(|(_, c)| *c)
+            .unwrap_or(tiny_skia::Color::TRANSPARENT),
+    };
+
+    color.premultiply().to_color_u8()
+}
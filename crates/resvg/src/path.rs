@@ -9,6 +9,8 @@ use crate::render::Context;
 use crate::tree::{BBoxes, Node};
 
 pub struct FillPath {
+    /// Element's ID. Can be empty.
+    pub id: String,
     pub transform: tiny_skia::Transform,
     pub paint: Paint,
     pub rule: tiny_skia::FillRule,
@@ -17,6 +19,8 @@ pub struct FillPath {
 }
 
 pub struct StrokePath {
+    /// Element's ID. Can be empty.
+    pub id: String,
     pub transform: tiny_skia::Transform,
     pub paint: Paint,
     pub stroke: tiny_skia::Stroke,
@@ -118,6 +122,7 @@ fn convert_fill_path(
         crate::paint_server::convert(&ufill.paint, ufill.opacity, object_bbox.to_non_zero_rect())?;
 
     let path = FillPath {
+        id: String::new(),
         transform,
         paint,
         rule,
@@ -186,6 +191,7 @@ fn convert_stroke_path(
     // TODO: preserve stroked path
 
     let path = StrokePath {
+        id: String::new(),
         transform,
         paint,
         stroke,
@@ -222,13 +228,28 @@ pub fn render_fill_path(
                 patt_ts,
             )
         }
+        Paint::ConicGradient(ref gradient) => {
+            let (grad_pix, grad_ts) = crate::paint_server::prepare_conic_gradient_pixmap(
+                gradient,
+                path.path.bounds(),
+                transform.pre_concat(path.transform),
+                pixmap_size(pixmap)?,
+            )?;
+
+            pattern_pixmap = grad_pix;
+            paint.shader = conic_gradient_shader(&pattern_pixmap, grad_ts);
+        }
     }
 
     paint.anti_alias = path.anti_alias;
     paint.blend_mode = blend_mode;
 
     let transform = transform.pre_concat(path.transform);
-    pixmap.fill_path(&path.path, &paint, path.rule, transform, None);
+    let flattened = ctx
+        .flattening_tolerance
+        .and_then(|tol| flatten(&path.path, tol, transform));
+    let data = flattened.as_ref().unwrap_or(&path.path);
+    pixmap.fill_path(data, &paint, path.rule, transform, None);
 
     Some(())
 }
@@ -259,6 +280,20 @@ pub fn render_stroke_path(
                 patt_ts,
             )
         }
+        Paint::ConicGradient(ref gradient) => {
+            let transform = transform.pre_concat(path.transform);
+            let res_scale = tiny_skia::PathStroker::compute_resolution_scale(&transform);
+            let stroked_path = path.path.stroke(&path.stroke, res_scale)?;
+            let (grad_pix, grad_ts) = crate::paint_server::prepare_conic_gradient_pixmap(
+                gradient,
+                stroked_path.bounds(),
+                transform,
+                pixmap_size(pixmap)?,
+            )?;
+
+            pattern_pixmap = grad_pix;
+            paint.shader = conic_gradient_shader(&pattern_pixmap, grad_ts);
+        }
     }
 
     paint.anti_alias = path.anti_alias;
@@ -267,7 +302,107 @@ pub fn render_stroke_path(
     // TODO: fallback to a stroked path when possible
 
     let transform = transform.pre_concat(path.transform);
-    pixmap.stroke_path(&path.path, &paint, &path.stroke, transform, None);
+    let flattened = ctx
+        .flattening_tolerance
+        .and_then(|tol| flatten(&path.path, tol, transform));
+    let data = flattened.as_ref().unwrap_or(&path.path);
+    pixmap.stroke_path(data, &paint, &path.stroke, transform, None);
 
     Some(())
 }
+
+/// Replaces curves with line segments.
+///
+/// `tolerance` is the maximum distance between a curve and its approximation
+/// in device pixels, i.e. after applying `transform`.
+///
+/// Returns `None` when the path has no curves.
+fn flatten(
+    path: &tiny_skia::Path,
+    tolerance: f32,
+    transform: tiny_skia::Transform,
+) -> Option<tiny_skia::Path> {
+    use tiny_skia::{PathSegment, Point};
+
+    // Prevents huge paths on extreme zoom levels.
+    const MAX_SEGMENTS: f32 = 1000.0;
+
+    let has_curves = path
+        .segments()
+        .any(|s| matches!(s, PathSegment::QuadTo(..) | PathSegment::CubicTo(..)));
+    if !has_curves {
+        return None;
+    }
+
+    // The number of segments is calculated using Wang's formula in device space.
+    let segments_count = |points: &mut [Point], degree: f32| -> usize {
+        transform.map_points(points);
+        let mut max_dist = 0.0f32;
+        for p in points.windows(3) {
+            let x = p[0].x - 2.0 * p[1].x + p[2].x;
+            let y = p[0].y - 2.0 * p[1].y + p[2].y;
+            max_dist = max_dist.max((x * x + y * y).sqrt());
+        }
+
+        let n = (degree * (degree - 1.0) / 8.0 * max_dist / tolerance).sqrt();
+        n.ceil().clamp(1.0, MAX_SEGMENTS) as usize
+    };
+
+    let mut builder = tiny_skia::PathBuilder::new();
+    let mut prev = Point::zero();
+    for segment in path.segments() {
+        match segment {
+            PathSegment::MoveTo(p) => builder.move_to(p.x, p.y),
+            PathSegment::LineTo(p) => builder.line_to(p.x, p.y),
+            PathSegment::QuadTo(p1, p) => {
+                let n = segments_count(&mut [prev, p1, p], 2.0);
+                for i in 1..=n {
+                    let t = i as f32 / n as f32;
+                    let mt = 1.0 - t;
+                    let x = mt * mt * prev.x + 2.0 * mt * t * p1.x + t * t * p.x;
+                    let y = mt * mt * prev.y + 2.0 * mt * t * p1.y + t * t * p.y;
+                    builder.line_to(x, y);
+                }
+            }
+            PathSegment::CubicTo(p1, p2, p) => {
+                let n = segments_count(&mut [prev, p1, p2, p], 3.0);
+                for i in 1..=n {
+                    let t = i as f32 / n as f32;
+                    let mt = 1.0 - t;
+                    let (a, b, c, d) =
+                        (mt * mt * mt, 3.0 * mt * mt * t, 3.0 * mt * t * t, t * t * t);
+                    let x = a * prev.x + b * p1.x + c * p2.x + d * p.x;
+                    let y = a * prev.y + b * p1.y + c * p2.y + d * p.y;
+                    builder.line_to(x, y);
+                }
+            }
+            PathSegment::Close => builder.close(),
+        }
+
+        // `Close` is always followed by `MoveTo`, so the last point is enough.
+        if let Some(p) = builder.last_point() {
+            prev = p;
+        }
+    }
+
+    builder.finish()
+}
+
+fn pixmap_size(pixmap: &tiny_skia::PixmapMut) -> Option<tiny_skia::IntSize> {
+    tiny_skia::IntSize::from_wh(pixmap.width(), pixmap.height())
+}
+
+/// Creates a shader from a pixmap prepared by `prepare_conic_gradient_pixmap`.
+fn conic_gradient_shader(
+    pixmap: &tiny_skia::Pixmap,
+    transform: tiny_skia::Transform,
+) -> tiny_skia::Shader<'_> {
+    // Pixels are aligned with the canvas, so no filtering is needed.
+    tiny_skia::Pattern::new(
+        pixmap.as_ref(),
+        tiny_skia::SpreadMode::Pad,
+        tiny_skia::FilterQuality::Nearest,
+        1.0,
+        transform,
+    )
+}
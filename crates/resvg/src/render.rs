@@ -2,10 +2,42 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
+use std::cell::RefCell;
+use std::time::{Duration, Instant};
+
+use crate::stats::RenderStats;
 use crate::tree::{Group, Node, OptionLog, Tree};
 
 pub struct Context {
     pub max_bbox: tiny_skia::IntRect,
+    /// Collected only when requested.
+    pub stats: Option<RefCell<RenderStats>>,
+    /// Curves flattening tolerance in device pixels.
+    pub flattening_tolerance: Option<f32>,
+}
+
+impl Context {
+    pub fn update_stats<F: FnOnce(&mut RenderStats)>(&self, f: F) {
+        if let Some(ref stats) = self.stats {
+            f(&mut stats.borrow_mut());
+        }
+    }
+
+    /// Runs `f` and adds its execution time to the selected phase.
+    pub fn measure<T>(
+        &self,
+        phase: fn(&mut RenderStats) -> &mut Duration,
+        f: impl FnOnce() -> T,
+    ) -> T {
+        if self.stats.is_none() {
+            return f();
+        }
+
+        let start = Instant::now();
+        let result = f();
+        self.update_stats(|stats| *phase(stats) += start.elapsed());
+        result
+    }
 }
 
 impl Tree {
@@ -16,6 +48,100 @@ impl Tree {
     ///
     /// The produced content is in the sRGB color space.
     pub fn render(&self, transform: tiny_skia::Transform, pixmap: &mut tiny_skia::PixmapMut) {
+        self.render_inner(transform, pixmap, None);
+    }
+
+    /// Renders the tree into a region of the pixmap.
+    ///
+    /// The same as [`Tree::render`], but `transform` is relative to `dest_offset`,
+    /// i.e. the top-left corner of the region in `pixmap` pixels.
+    /// Can be used to compose multiple images, like in a sprite atlas,
+    /// without rendering each one into a separate pixmap first.
+    ///
+    /// The content is painted directly over the existing `pixmap` content
+    /// and is clipped by the `pixmap` bounds. The region can be partially outside of it.
+    /// Just like with [`Tree::render`], content outside of the tree's viewport is clipped
+    /// only when [`Tree::clip_to_viewport`] is set.
+    pub fn render_onto(
+        &self,
+        pixmap: &mut tiny_skia::PixmapMut,
+        dest_offset: (i32, i32),
+        transform: tiny_skia::Transform,
+    ) {
+        let offset =
+            tiny_skia::Transform::from_translate(dest_offset.0 as f32, dest_offset.1 as f32);
+        self.render(offset.pre_concat(transform), pixmap);
+    }
+
+    /// Renders the tree onto the pixmap and collects rendering statistics.
+    ///
+    /// The same as [`Tree::render`], but also adds statistics to `stats`.
+    pub fn render_with_stats(
+        &self,
+        transform: tiny_skia::Transform,
+        pixmap: &mut tiny_skia::PixmapMut,
+        stats: &mut RenderStats,
+    ) {
+        let collected = self.render_inner(transform, pixmap, Some(std::mem::take(stats)));
+        *stats = collected.unwrap_or_default();
+    }
+
+    /// Renders the tree onto the pixmap using supersampling.
+    ///
+    /// The same as [`Tree::render`], but the tree is rendered into a `factor` times larger
+    /// pixmap first, which is then box-downsampled onto `pixmap`.
+    /// Improves thin strokes and small text quality at the cost of `factor`² times
+    /// more memory and rendering time. Factors between 2 and 4 are usually enough.
+    ///
+    /// A `factor` of 0 or 1 is the same as [`Tree::render`].
+    /// Factors above [`MAX_SUPERSAMPLING_FACTOR`] are clamped to it.
+    pub fn render_supersampled(
+        &self,
+        factor: u32,
+        transform: tiny_skia::Transform,
+        pixmap: &mut tiny_skia::PixmapMut,
+    ) {
+        if factor <= 1 {
+            self.render(transform, pixmap);
+            return;
+        }
+
+        let factor = factor.min(MAX_SUPERSAMPLING_FACTOR);
+
+        let width = pixmap.width();
+        let height = pixmap.height();
+        let mut buffer = match width
+            .checked_mul(factor)
+            .zip(height.checked_mul(factor))
+            .and_then(|(w, h)| tiny_skia::Pixmap::new(w, h))
+        {
+            Some(v) => v,
+            None => {
+                log::warn!("Failed to allocate a {}x supersampling buffer.", factor);
+                return;
+            }
+        };
+
+        let scale = tiny_skia::Transform::from_scale(factor as f32, factor as f32);
+        self.render(scale.pre_concat(transform), &mut buffer.as_mut());
+
+        let layer = downsample(&buffer, factor);
+        pixmap.draw_pixmap(
+            0,
+            0,
+            layer.as_ref(),
+            &tiny_skia::PixmapPaint::default(),
+            tiny_skia::Transform::default(),
+            None,
+        );
+    }
+
+    fn render_inner(
+        &self,
+        transform: tiny_skia::Transform,
+        pixmap: &mut tiny_skia::PixmapMut,
+        stats: Option<RenderStats>,
+    ) -> Option<RenderStats> {
         let target_size = tiny_skia::IntSize::from_wh(pixmap.width(), pixmap.height()).unwrap();
         let max_bbox = tiny_skia::IntRect::from_xywh(
             -(target_size.width() as i32) * 2,
@@ -30,11 +156,149 @@ impl Tree {
 
         let root_transform = transform.pre_concat(ts);
 
-        let ctx = Context { max_bbox };
-        render_nodes(&self.children, &ctx, root_transform, pixmap);
+        let ctx = Context {
+            max_bbox,
+            stats: stats.map(RefCell::new),
+            flattening_tolerance: self
+                .flattening_tolerance
+                .filter(|tol| tol.is_finite() && *tol > 0.0),
+        };
+
+        let viewport = self.size.to_non_zero_rect(0.0, 0.0).to_rect();
+        let clipped = self.clip_to_viewport
+            && !covers_pixmap(viewport, transform, pixmap)
+            && render_clipped(
+                &self.children,
+                &ctx,
+                root_transform,
+                viewport,
+                transform,
+                pixmap,
+            )
+            .is_some();
+
+        // Render without clipping when a clipping layer cannot be allocated.
+        if !clipped {
+            render_nodes(&self.children, &ctx, root_transform, pixmap);
+        }
+
+        ctx.stats.map(RefCell::into_inner)
     }
 }
 
+/// Checks that a rectangle covers the whole pixmap after applying `transform`.
+fn covers_pixmap(
+    rect: tiny_skia::Rect,
+    transform: tiny_skia::Transform,
+    pixmap: &tiny_skia::PixmapMut,
+) -> bool {
+    // Allow for rounding errors in transforms that fit the rect into the pixmap.
+    const EPSILON: f32 = 0.001;
+
+    if transform.has_skew() {
+        return false;
+    }
+
+    match rect.transform(transform) {
+        Some(r) => {
+            r.left() <= EPSILON
+                && r.top() <= EPSILON
+                && r.right() >= pixmap.width() as f32 - EPSILON
+                && r.bottom() >= pixmap.height() as f32 - EPSILON
+        }
+        None => false,
+    }
+}
+
+/// Renders nodes into a layer clipped by `clip_rect` mapped using `clip_transform`.
+///
+/// The layer covers only the visible part of the mapped `clip_rect`.
+/// Returns `None` when the layer cannot be allocated.
+fn render_clipped(
+    children: &[Node],
+    ctx: &Context,
+    transform: tiny_skia::Transform,
+    clip_rect: tiny_skia::Rect,
+    clip_transform: tiny_skia::Transform,
+    pixmap: &mut tiny_skia::PixmapMut,
+) -> Option<()> {
+    let pixmap_rect = tiny_skia::IntSize::from_wh(pixmap.width(), pixmap.height())?
+        .to_int_rect(0, 0)
+        .to_rect();
+    let region = match clip_rect
+        .transform(clip_transform)?
+        .intersect(&pixmap_rect)
+        .and_then(|r| r.round_out())
+    {
+        Some(v) => v,
+        // The clip rect is outside of the pixmap. Nothing to render.
+        None => return Some(()),
+    };
+
+    let shift_ts = tiny_skia::Transform::from_translate(-region.x() as f32, -region.y() as f32);
+
+    let mut layer = tiny_skia::Pixmap::new(region.width(), region.height())
+        .log_none(|| log::warn!("Failed to allocate a viewport layer for: {:?}.", region))?;
+    ctx.update_stats(|stats| stats.layers += 1);
+    render_nodes(
+        children,
+        ctx,
+        shift_ts.pre_concat(transform),
+        &mut layer.as_mut(),
+    );
+
+    let mask = layer
+        .as_mut()
+        .create_rect_mask(shift_ts.pre_concat(clip_transform), clip_rect)?;
+    layer.apply_mask(&mask);
+
+    pixmap.draw_pixmap(
+        region.x(),
+        region.y(),
+        layer.as_ref(),
+        &tiny_skia::PixmapPaint::default(),
+        tiny_skia::Transform::default(),
+        None,
+    );
+
+    Some(())
+}
+
+/// The largest factor supported by [`Tree::render_supersampled`].
+pub const MAX_SUPERSAMPLING_FACTOR: u32 = 8;
+
+/// Averages each `factor`x`factor` block of premultiplied pixels.
+fn downsample(buffer: &tiny_skia::Pixmap, factor: u32) -> tiny_skia::Pixmap {
+    let width = buffer.width() / factor;
+    let height = buffer.height() / factor;
+    // Cannot fail, since the buffer is always larger.
+    let mut pixmap = tiny_skia::Pixmap::new(width, height).unwrap();
+
+    let samples = factor * factor;
+    let src = buffer.data();
+    let stride = buffer.width() as usize * 4;
+    for (i, dst) in pixmap.data_mut().chunks_exact_mut(4).enumerate() {
+        let x = (i as u32 % width * factor) as usize;
+        let y = (i as u32 / width * factor) as usize;
+
+        let mut sum = [0u32; 4];
+        for sy in y..y + factor as usize {
+            let row = &src[sy * stride + x * 4..sy * stride + (x + factor as usize) * 4];
+            for p in row.chunks_exact(4) {
+                for c in 0..4 {
+                    sum[c] += p[c] as u32;
+                }
+            }
+        }
+
+        for c in 0..4 {
+            dst[c] = ((sum[c] + samples / 2) / samples) as u8;
+        }
+    }
+
+    pixmap
+}
+
 pub fn render_nodes(
     children: &[Node],
     ctx: &Context,
@@ -57,25 +321,41 @@ fn render_node(
             render_group(group, ctx, transform, pixmap);
         }
         Node::FillPath(ref path) => {
-            crate::path::render_fill_path(
-                path,
-                tiny_skia::BlendMode::SourceOver,
-                ctx,
-                transform,
-                pixmap,
+            ctx.update_stats(|stats| stats.nodes_painted += 1);
+            ctx.measure(
+                |stats| &mut stats.fill_time,
+                || {
+                    crate::path::render_fill_path(
+                        path,
+                        tiny_skia::BlendMode::SourceOver,
+                        ctx,
+                        transform,
+                        pixmap,
+                    )
+                },
             );
         }
         Node::StrokePath(ref path) => {
-            crate::path::render_stroke_path(
-                path,
-                tiny_skia::BlendMode::SourceOver,
-                ctx,
-                transform,
-                pixmap,
+            ctx.update_stats(|stats| stats.nodes_painted += 1);
+            ctx.measure(
+                |stats| &mut stats.stroke_time,
+                || {
+                    crate::path::render_stroke_path(
+                        path,
+                        tiny_skia::BlendMode::SourceOver,
+                        ctx,
+                        transform,
+                        pixmap,
+                    )
+                },
             );
         }
         Node::Image(ref image) => {
-            crate::image::render_image(image, transform, pixmap);
+            ctx.update_stats(|stats| stats.nodes_painted += 1);
+            ctx.measure(
+                |stats| &mut stats.image_time,
+                || crate::image::render_image(image, transform, pixmap),
+            );
         }
     }
 }
@@ -86,6 +366,10 @@ fn render_group(
     transform: tiny_skia::Transform,
     pixmap: &mut tiny_skia::PixmapMut,
 ) -> Option<()> {
+    if !group.visible {
+        return Some(());
+    }
+
     let transform = transform.pre_concat(group.transform);
 
     if group.is_transform_only() {
@@ -136,21 +420,35 @@ fn render_group(
 
     let mut sub_pixmap = tiny_skia::Pixmap::new(ibbox.width(), ibbox.height())
         .log_none(|| log::warn!("Failed to allocate a group layer for: {:?}.", ibbox))?;
+    ctx.update_stats(|stats| stats.layers += 1);
 
     render_nodes(&group.children, ctx, transform, &mut sub_pixmap.as_mut());
 
     if !group.filters.is_empty() {
         for filter in &group.filters {
-            crate::filter::apply(filter, transform, &mut sub_pixmap);
+            ctx.update_stats(|stats| {
+                stats.filters += 1;
+                stats.filtered_pixels += sub_pixmap.width() as u64 * sub_pixmap.height() as u64;
+            });
+            ctx.measure(
+                |stats| &mut stats.filter_time,
+                || crate::filter::apply(filter, transform, &mut sub_pixmap),
+            );
         }
     }
 
     if let Some(ref clip_path) = group.clip_path {
-        crate::clip::apply(clip_path, transform, &mut sub_pixmap);
+        ctx.measure(
+            |stats| &mut stats.mask_time,
+            || crate::clip::apply(clip_path, ctx, transform, &mut sub_pixmap),
+        );
     }
 
     if let Some(ref mask) = group.mask {
-        crate::mask::apply(mask, ctx, transform, &mut sub_pixmap);
+        ctx.measure(
+            |stats| &mut stats.mask_time,
+            || crate::mask::apply(mask, ctx, transform, &mut sub_pixmap),
+        );
     }
 
     let paint = tiny_skia::PixmapPaint {
@@ -0,0 +1,79 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use std::collections::HashSet;
+
+use usvg::NodeExt;
+
+/// A set of elements to show or hide, like layers in an editor.
+///
+/// Used by [`Tree::from_usvg_with_selection`](crate::Tree::from_usvg_with_selection).
+/// Unlike [`Tree::from_usvg_node`](crate::Tree::from_usvg_node), the document is rendered as a whole,
+/// so the position and backdrop of the remaining elements are preserved.
+///
+/// IDs are matched against [`usvg::Node::id`], so only elements that are preserved
+/// in the `usvg` tree can be selected.
+/// Clip paths, masks and patterns are not affected.
+#[derive(Clone, Default, Debug)]
+pub struct LayerSelection {
+    /// Elements to render.
+    ///
+    /// When not empty, only these elements and their descendants are rendered.
+    pub include: HashSet<String>,
+
+    /// Elements to hide, including their descendants.
+    ///
+    /// Takes precedence over `include`.
+    pub exclude: HashSet<String>,
+}
+
+impl LayerSelection {
+    /// Checks that the selection doesn't hide anything.
+    pub fn is_empty(&self) -> bool {
+        self.include.is_empty() && self.exclude.is_empty()
+    }
+
+    /// Removes hidden nodes from `root`.
+    pub(crate) fn apply(&self, root: &usvg::Node) {
+        let mut hidden = Vec::new();
+        self.collect_hidden(root, self.include.is_empty(), &mut hidden);
+        for node in hidden {
+            node.detach();
+        }
+    }
+
+    /// Collects hidden children of `parent`.
+    ///
+    /// Returns `false` when all children are hidden.
+    fn collect_hidden(
+        &self,
+        parent: &usvg::Node,
+        included: bool,
+        hidden: &mut Vec<usvg::Node>,
+    ) -> bool {
+        let mut has_visible = false;
+        for node in parent.children() {
+            let id = node.id();
+            if self.exclude.contains(&*id) {
+                hidden.push(node.clone());
+                continue;
+            }
+
+            let included = included || self.include.contains(&*id);
+            let is_visible = if node.has_children() {
+                self.collect_hidden(&node, included, hidden) || included
+            } else {
+                included
+            };
+
+            if is_visible {
+                has_visible = true;
+            } else {
+                hidden.push(node.clone());
+            }
+        }
+
+        has_visible
+    }
+}
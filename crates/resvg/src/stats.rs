@@ -0,0 +1,52 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use std::time::Duration;
+
+/// Rendering statistics.
+///
+/// Collected by [`Tree::render_with_stats`](crate::Tree::render_with_stats).
+/// Values are accumulated, so the same instance can be reused across multiple renders.
+///
+/// Phases can be nested. For example, the time spent on paths inside a mask
+/// is counted both in `fill_time` and `mask_time`.
+#[derive(Clone, Default, Debug)]
+pub struct RenderStats {
+    /// Number of painted paths and images.
+    ///
+    /// Includes nodes inside clip paths, masks and patterns.
+    pub nodes_painted: usize,
+
+    /// Number of allocated offscreen layers.
+    ///
+    /// Includes group, clip path, mask and pattern layers.
+    /// Temporary images allocated by filter primitives are not included.
+    pub layers: usize,
+
+    /// Number of applied filters.
+    pub filters: usize,
+
+    /// Total number of pixels processed by filters.
+    pub filtered_pixels: u64,
+
+    /// Number of luminance masks calculated in the linearRGB color space.
+    ///
+    /// Other luminance masks use sRGB, which is the default.
+    pub linear_rgb_masks: usize,
+
+    /// Time spent filling paths.
+    pub fill_time: Duration,
+
+    /// Time spent stroking paths.
+    pub stroke_time: Duration,
+
+    /// Time spent drawing images.
+    pub image_time: Duration,
+
+    /// Time spent applying filters.
+    pub filter_time: Duration,
+
+    /// Time spent applying clip paths and masks.
+    pub mask_time: Duration,
+}
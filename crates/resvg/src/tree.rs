@@ -10,6 +10,10 @@ use crate::mask::Mask;
 use crate::path::{FillPath, StrokePath};
 
 pub struct Group {
+    /// Element's ID. Can be empty.
+    pub id: String,
+    /// Hidden groups are not rendered. See [`Tree::set_visible`].
+    pub visible: bool,
     pub transform: tiny_skia::Transform,
     pub opacity: usvg::Opacity,
     pub blend_mode: tiny_skia::BlendMode,
@@ -24,6 +28,10 @@ pub struct Group {
 }
 
 impl Group {
+    /// Checks that a group can be rendered directly onto the parent canvas.
+    ///
+    /// Any other property, including `opacity`, requires an isolated layer,
+    /// since it must be applied to the composited group content and not to each child.
     pub fn is_transform_only(&self) -> bool {
         self.opacity == usvg::Opacity::ONE
             && self.blend_mode == tiny_skia::BlendMode::SourceOver
@@ -41,6 +49,27 @@ pub enum Node {
     Image(Image),
 }
 
+impl Node {
+    /// Returns an ID of a path or an image.
+    fn leaf_id(&self) -> Option<&str> {
+        match self {
+            Node::Group(_) => None,
+            Node::FillPath(ref path) => Some(&path.id),
+            Node::StrokePath(ref path) => Some(&path.id),
+            Node::Image(ref image) => Some(&image.id),
+        }
+    }
+
+    fn leaf_id_mut(&mut self) -> Option<&mut String> {
+        match self {
+            Node::Group(_) => None,
+            Node::FillPath(ref mut path) => Some(&mut path.id),
+            Node::StrokePath(ref mut path) => Some(&mut path.id),
+            Node::Image(ref mut image) => Some(&mut image.id),
+        }
+    }
+}
+
 // - No hidden nodes.
 // - No text.
 // - Uses mostly tiny-skia types.
@@ -72,6 +101,28 @@ pub struct Tree {
     /// Can be `None` when the tree has no children.
     pub content_area: Option<tiny_skia::Rect>,
 
+    /// Whether the content should be clipped by the viewport,
+    /// i.e. by a rectangle at the origin with the tree's `size`.
+    ///
+    /// Taken from [`usvg::Tree::clip_to_viewport`]. When disabled, content outside
+    /// of the viewport is clipped only by the pixmap bounds
+    /// and [`Tree::content_bbox`] can be used to find the rendered area.
+    pub clip_to_viewport: bool,
+
+    /// Curves flattening tolerance in device pixels.
+    ///
+    /// When set, curves are replaced with line segments that deviate from them
+    /// by at most this distance before filling and stroking.
+    /// Larger values render faster, smaller ones are more precise.
+    ///
+    /// `None`, non-finite and non-positive values leave curves to `tiny-skia`.
+    ///
+    /// Default: `None`
+    pub flattening_tolerance: Option<f32>,
+
+    /// Content area with all elements visible.
+    pub(crate) full_content_area: Option<tiny_skia::Rect>,
+
     pub(crate) children: Vec<Node>,
 }
 
@@ -91,10 +142,29 @@ impl Tree {
             size: tree.size,
             view_box: tree.view_box,
             content_area: layer_bbox,
+            clip_to_viewport: tree.clip_to_viewport,
+            flattening_tolerance: None,
+            full_content_area: layer_bbox,
             children,
         }
     }
 
+    /// Creates a rendering tree from [`usvg::Tree`] with some elements hidden.
+    ///
+    /// Text nodes should be already converted into paths using
+    /// [`usvg::TreeTextToPath::convert_text`].
+    pub fn from_usvg_with_selection(tree: &usvg::Tree, selection: &crate::LayerSelection) -> Self {
+        if selection.is_empty() {
+            return Self::from_usvg(tree);
+        }
+
+        // Do not modify the original tree.
+        let mut tree = tree.clone();
+        tree.root = tree.root.make_deep_copy();
+        selection.apply(&tree.root);
+        Self::from_usvg(&tree)
+    }
+
     /// Creates a rendering tree from [`usvg::Node`].
     ///
     /// Text nodes should be already converted into paths using
@@ -121,11 +191,152 @@ impl Tree {
             size: node_bbox.size(),
             view_box,
             content_area: layer_bbox,
+            // The viewport is the node's bbox, which doesn't include strokes and filters.
+            clip_to_viewport: false,
+            flattening_tolerance: None,
+            full_content_area: layer_bbox,
             children,
         })
     }
 }
 
+impl Tree {
+    /// Shows or hides elements with the specified ID, including their descendants.
+    ///
+    /// Unlike [`Tree::from_usvg_with_selection`], doesn't require a new tree,
+    /// which allows toggling elements between renders.
+    /// [`Tree::content_area`] is updated accordingly.
+    /// Filter regions, clip paths and masks with `objectBoundingBox` units
+    /// still use the bounding box of the whole element.
+    /// Clip paths, masks and patterns content is not affected.
+    ///
+    /// Returns `false` when there are no elements with such ID.
+    pub fn set_visible(&mut self, id: &str, visible: bool) -> bool {
+        if id.is_empty() || !set_visible(&mut self.children, id, visible) {
+            return false;
+        }
+
+        self.content_area = if has_hidden(&self.children) {
+            visible_layer_bbox(&self.children).to_rect()
+        } else {
+            self.full_content_area
+        };
+
+        true
+    }
+}
+
+fn set_visible(nodes: &mut Vec<Node>, id: &str, visible: bool) -> bool {
+    let mut found = false;
+    let mut i = 0;
+    while i < nodes.len() {
+        if nodes[i].leaf_id() == Some(id) {
+            found = true;
+            wrap_leaves(nodes, i, id);
+        }
+
+        if let Node::Group(ref mut group) = nodes[i] {
+            if group.id == id {
+                group.visible = visible;
+                found = true;
+            }
+
+            found |= set_visible(&mut group.children, id, visible);
+        }
+
+        i += 1;
+    }
+
+    found
+}
+
+/// Wraps paths and images with the specified ID, starting at `start`, into a group.
+///
+/// A path can be converted into a fill and a stroke nodes, which are always adjacent.
+/// Groups are created only on demand, so most paths and images are rendered without one.
+/// Nodes without a bounding box are left as is, since they are not rendered anyway.
+fn wrap_leaves(nodes: &mut Vec<Node>, start: usize, id: &str) {
+    let len = nodes[start..]
+        .iter()
+        .take_while(|node| node.leaf_id() == Some(id))
+        .count();
+
+    let bbox = match visible_layer_bbox(&nodes[start..start + len]).to_rect() {
+        Some(v) => v,
+        None => return,
+    };
+
+    let mut children: Vec<Node> = nodes.drain(start..start + len).collect();
+    for child in &mut children {
+        if let Some(child_id) = child.leaf_id_mut() {
+            child_id.clear();
+        }
+    }
+
+    nodes.insert(
+        start,
+        Node::Group(Group {
+            id: id.to_string(),
+            visible: true,
+            transform: tiny_skia::Transform::default(),
+            opacity: usvg::Opacity::ONE,
+            blend_mode: tiny_skia::BlendMode::SourceOver,
+            clip_path: None,
+            mask: None,
+            filters: Vec::new(),
+            isolate: false,
+            bbox,
+            children,
+        }),
+    );
+}
+
+fn has_hidden(nodes: &[Node]) -> bool {
+    nodes.iter().any(|node| match node {
+        Node::Group(ref group) => !group.visible || has_hidden(&group.children),
+        _ => false,
+    })
+}
+
+/// Calculates a layer bounding box of visible nodes.
+///
+/// Just like during conversion, paths and images bboxes do not include their transform.
+fn visible_layer_bbox(nodes: &[Node]) -> usvg::BBox {
+    let mut bbox = usvg::BBox::default();
+    for node in nodes {
+        let node_bbox = match node {
+            Node::Group(ref group) => {
+                if !group.visible {
+                    continue;
+                }
+
+                // Filter regions do not depend on the children visibility.
+                let group_bbox = if group.filters.is_empty() && has_hidden(&group.children) {
+                    visible_layer_bbox(&group.children).to_rect()
+                } else {
+                    Some(group.bbox)
+                };
+
+                group_bbox.and_then(|r| r.transform(group.transform))
+            }
+            Node::FillPath(ref path) => Some(path.path.bounds()),
+            Node::StrokePath(ref path) => {
+                let res_scale = tiny_skia::PathStroker::compute_resolution_scale(&path.transform);
+                path.path
+                    .stroke(&path.stroke, res_scale.max(10.0))
+                    .map(|p| p.bounds())
+            }
+            Node::Image(ref image) => Some(image.view_box.rect.to_rect()),
+        };
+
+        if let Some(node_bbox) = node_bbox {
+            bbox = bbox.expand(node_bbox);
+        }
+    }
+
+    bbox
+}
+
 pub fn convert_node(node: usvg::Node) -> (Vec<Node>, Option<tiny_skia::Rect>) {
     let mut children = Vec::new();
     let bboxes = convert_node_inner(node, &mut children);
@@ -149,12 +360,35 @@ pub struct BBoxes {
 fn convert_node_inner(node: usvg::Node, children: &mut Vec<Node>) -> Option<BBoxes> {
     match &*node.borrow() {
         usvg::NodeKind::Group(ref ugroup) => convert_group(node.clone(), ugroup, children),
-        usvg::NodeKind::Path(ref upath) => crate::path::convert(upath, children),
-        usvg::NodeKind::Image(ref uimage) => crate::image::convert(uimage, children),
+        usvg::NodeKind::Path(ref upath) => convert_with_id(&upath.id, children, |children| {
+            crate::path::convert(upath, children)
+        }),
+        usvg::NodeKind::Image(ref uimage) => convert_with_id(&uimage.id, children, |children| {
+            crate::image::convert(uimage, children)
+        }),
         usvg::NodeKind::Text(_) => None, // should be already converted into paths
     }
 }
 
+/// Assigns an ID to converted paths and images, so they can be hidden via [`Tree::set_visible`].
+fn convert_with_id(
+    id: &str,
+    children: &mut Vec<Node>,
+    convert: impl FnOnce(&mut Vec<Node>) -> Option<BBoxes>,
+) -> Option<BBoxes> {
+    let start = children.len();
+    let bboxes = convert(children);
+    if !id.is_empty() {
+        for child in &mut children[start..] {
+            if let Some(child_id) = child.leaf_id_mut() {
+                *child_id = id.to_string();
+            }
+        }
+    }
+
+    bboxes
+}
+
 fn convert_group(
     node: usvg::Node,
     ugroup: &usvg::Group,
@@ -180,6 +414,8 @@ fn convert_group(
     }
 
     let group = Group {
+        id: ugroup.id.clone(),
+        visible: true,
         transform: ugroup.transform,
         opacity: ugroup.opacity,
         blend_mode: convert_blend_mode(ugroup.blend_mode),
@@ -208,6 +444,8 @@ fn convert_empty_group(ugroup: &usvg::Group, children: &mut Vec<Node>) -> Option
     let layer_bbox = layer_bbox?;
 
     let group = Group {
+        id: ugroup.id.clone(),
+        visible: true,
         transform: ugroup.transform,
         opacity: ugroup.opacity,
         blend_mode: convert_blend_mode(ugroup.blend_mode),
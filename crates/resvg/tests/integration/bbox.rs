@@ -0,0 +1,78 @@
+use usvg::TreeParsing;
+
+fn content_bbox(svg: &str) -> Option<tiny_skia::Rect> {
+    let tree = usvg::Tree::from_str(svg, &usvg::Options::default()).unwrap();
+    resvg::Tree::from_usvg(&tree).content_bbox()
+}
+
+#[test]
+fn small_shape_in_large_viewport() {
+    let bbox = content_bbox(
+        "
+    <svg width='200' height='200' viewBox='0 0 1000 1000' xmlns='http://www.w3.org/2000/svg'>
+        <rect x='450' y='400' width='100' height='200' stroke='black' stroke-width='20'/>
+    </svg>
+    ",
+    )
+    .unwrap();
+
+    // Includes the stroke and is in canvas coordinates.
+    assert_eq!(
+        bbox,
+        tiny_skia::Rect::from_xywh(88.0, 78.0, 24.0, 44.0).unwrap()
+    );
+}
+
+#[test]
+fn reduced_by_clip_path() {
+    let bbox = content_bbox(
+        "
+    <svg viewBox='0 0 200 200' xmlns='http://www.w3.org/2000/svg'>
+        <clipPath id='clip1'>
+            <rect x='50' y='0' width='200' height='100'/>
+        </clipPath>
+        <rect x='20' y='20' width='100' height='100' clip-path='url(#clip1)'/>
+        <circle cx='150' cy='150' r='10' fill='none'/>
+    </svg>
+    ",
+    )
+    .unwrap();
+
+    assert_eq!(
+        bbox,
+        tiny_skia::Rect::from_xywh(50.0, 20.0, 70.0, 80.0).unwrap()
+    );
+}
+
+#[test]
+fn reduced_by_mask() {
+    let bbox = content_bbox(
+        "
+    <svg viewBox='0 0 200 200' xmlns='http://www.w3.org/2000/svg'>
+        <mask id='mask1'>
+            <circle cx='100' cy='100' r='20' fill='white'/>
+        </mask>
+        <rect x='0' y='0' width='200' height='200' mask='url(#mask1)'/>
+    </svg>
+    ",
+    )
+    .unwrap();
+
+    assert_eq!(
+        bbox,
+        tiny_skia::Rect::from_xywh(80.0, 80.0, 40.0, 40.0).unwrap()
+    );
+}
+
+#[test]
+fn nothing_painted() {
+    let bbox = content_bbox(
+        "
+    <svg viewBox='0 0 200 200' xmlns='http://www.w3.org/2000/svg'>
+        <rect x='20' y='20' width='100' height='100' fill='none'/>
+    </svg>
+    ",
+    );
+
+    assert_eq!(bbox, None);
+}
@@ -0,0 +1,29 @@
+use crate::{alpha, render_svg};
+
+#[test]
+fn rotated_clip_path() {
+    let svg = "
+    <svg viewBox='0 0 200 200' xmlns='http://www.w3.org/2000/svg'>
+        <clipPath id='clip1' transform='rotate(45 100 100)'>
+            <rect x='50' y='50' width='100' height='100'/>
+        </clipPath>
+        <rect width='200' height='200' fill='black' clip-path='url(#clip1)'/>
+    </svg>
+    ";
+
+    let pixmap = render_svg(svg, 200, 200);
+    let alpha = |x, y| alpha(&pixmap, x, y);
+
+    // The clip region is a diamond with vertices at a distance of ~70.7 from the center.
+    assert_eq!(alpha(100, 100), 255);
+    assert_eq!(alpha(100, 35), 255);
+    assert_eq!(alpha(35, 100), 255);
+    assert_eq!(alpha(164, 100), 255);
+    assert_eq!(alpha(100, 164), 255);
+
+    // Corners of the untransformed clip rect.
+    assert_eq!(alpha(55, 55), 0);
+    assert_eq!(alpha(144, 55), 0);
+    assert_eq!(alpha(55, 144), 0);
+    assert_eq!(alpha(144, 144), 0);
+}
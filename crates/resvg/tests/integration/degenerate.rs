@@ -0,0 +1,117 @@
+use crate::render_svg;
+
+fn render(content: &str) -> tiny_skia::Pixmap {
+    let svg = format!(
+        "<svg viewBox='0 0 100 100' xmlns='http://www.w3.org/2000/svg'>{}</svg>",
+        content
+    );
+
+    render_svg(&svg, 100, 100)
+}
+
+fn render_with_filter(primitive: &str) -> tiny_skia::Pixmap {
+    render(&format!(
+        "<filter id='filter1'>{}</filter>\
+         <rect width='50' height='50' filter='url(#filter1)'/>",
+        primitive
+    ))
+}
+
+#[track_caller]
+fn assert_empty(pixmap: tiny_skia::Pixmap) {
+    assert!(pixmap.pixels().iter().all(|p| p.alpha() == 0));
+}
+
+#[track_caller]
+fn assert_same(pixmap: tiny_skia::Pixmap, expected: &str) {
+    assert!(pixmap.data() == render(expected).data());
+}
+
+const RECT: &str = "<rect width='50' height='50'/>";
+
+#[test]
+fn huge_filter_parameters() {
+    assert_empty(render_with_filter("<feGaussianBlur stdDeviation='3e38'/>"));
+    assert_empty(render_with_filter("<feOffset dx='3e38'/>"));
+    // Only the shadow is moved away.
+    assert_same(
+        render_with_filter("<feDropShadow dx='3e38' stdDeviation='3e38'/>"),
+        RECT,
+    );
+    assert_empty(render_with_filter("<feMorphology radius='3e38'/>"));
+    // Noise is zero at lattice points and all points are lattice points at this frequency.
+    assert_empty(render_with_filter("<feTurbulence baseFrequency='3e38'/>"));
+    assert_empty(render_with_filter(
+        "<feDisplacementMap scale='3e38' in2='SourceGraphic'/>",
+    ));
+    // An opaque black source stays the same after being saturated.
+    assert_same(
+        render_with_filter(
+            "<feComposite operator='arithmetic' k1='3e38' k2='3e38' in2='SourceGraphic'/>",
+        ),
+        RECT,
+    );
+}
+
+#[test]
+fn huge_regions() {
+    assert_empty(render(
+        "<filter id='filter1' x='-3e38' width='3e38'><feFlood/></filter>\
+         <rect width='50' height='50' filter='url(#filter1)'/>",
+    ));
+    assert_empty(render(
+        "<mask id='mask1' x='-3e38' width='3e38'><rect width='5' height='5' fill='white'/></mask>\
+         <rect width='50' height='50' mask='url(#mask1)'/>",
+    ));
+    assert_empty(render(
+        "<pattern id='patt1' width='3e38' height='3e38' patternUnits='userSpaceOnUse'>\
+         <rect width='5' height='5'/></pattern>\
+         <rect width='50' height='50' fill='url(#patt1)'/>",
+    ));
+    assert_empty(render(
+        "<pattern id='patt1' width='1e-30' height='1e-30' patternUnits='userSpaceOnUse'>\
+         <rect width='5' height='5'/></pattern>\
+         <rect width='50' height='50' fill='url(#patt1)'/>",
+    ));
+}
+
+#[test]
+fn huge_geometry() {
+    // A stroke this wide cannot be outlined, so only the fill is rendered.
+    assert_same(
+        render("<rect width='50' height='50' stroke='black' stroke-width='3e38'/>"),
+        RECT,
+    );
+    // Ends right at the left edge.
+    assert_empty(render(
+        "<rect x='-3e38' width='3e38' height='3e38' fill='green'/>",
+    ));
+    // Shapes whose coordinates overflow `f32` after conversion are invalid.
+    assert_empty(render("<circle r='3e38' stroke='black'/>"));
+    assert_empty(render(
+        "<path d='M 10 10 A 3e38 3e38 0 0 1 50 50' stroke='black'/>",
+    ));
+    assert_empty(render(
+        "<rect width='10' height='10' transform='scale(3e38)' stroke='black'/>",
+    ));
+    // The first dash covers the whole outline.
+    assert_same(
+        render("<rect width='50' height='50' stroke='black' stroke-dasharray='3e38 3e38'/>"),
+        "<rect width='50' height='50' stroke='black'/>",
+    );
+}
+
+#[test]
+fn degenerate_shapes() {
+    assert_empty(render("<rect width='0' height='50'/>"));
+    assert_empty(render("<rect width='50' height='-50'/>"));
+    assert_empty(render("<ellipse cx='50' cy='50' rx='0' ry='50'/>"));
+    assert_empty(render("<circle cx='50' cy='50' r='-1'/>"));
+    assert_empty(render(
+        "<rect width='50' height='50' stroke='black' stroke-width='0' fill='none'/>",
+    ));
+    assert_empty(render(
+        "<rect width='50' height='50' stroke='black' stroke-width='-5' fill='none'/>",
+    ));
+    assert_empty(render("<path d='M 10 10 L 1e39 10 L 50 50 Z'/>"));
+}
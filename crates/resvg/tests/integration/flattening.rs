@@ -0,0 +1,68 @@
+use usvg::TreeParsing;
+
+use crate::render_tree;
+
+const CENTER: f32 = 256.0;
+const RADIUS: f32 = 200.0;
+
+fn render_circle(tolerance: Option<f32>) -> tiny_skia::Pixmap {
+    let svg = "
+    <svg viewBox='0 0 512 512' xmlns='http://www.w3.org/2000/svg'>
+        <circle cx='256' cy='256' r='200'/>
+    </svg>
+    ";
+
+    let tree = usvg::Tree::from_str(svg, &usvg::Options::default()).unwrap();
+    let mut tree = resvg::Tree::from_usvg(&tree);
+    tree.flattening_tolerance = tolerance;
+    render_tree(&tree, 512, 512)
+}
+
+/// Returns the maximum deviation of the rasterized circle edge from the ideal radius.
+///
+/// Each row coverage gives the span width with subpixel precision.
+/// Rows near the top and bottom are skipped, since the edge is almost horizontal there.
+fn max_edge_deviation(pixmap: &tiny_skia::Pixmap) -> f32 {
+    let mut max_deviation = 0.0f32;
+    for y in 0..pixmap.height() {
+        let dy = y as f32 + 0.5 - CENTER;
+        if dy.abs() > RADIUS * 0.9 {
+            continue;
+        }
+
+        let coverage: f32 = (0..pixmap.width())
+            .map(|x| pixmap.pixel(x, y).unwrap().alpha() as f32 / 255.0)
+            .sum();
+
+        let radius = ((coverage / 2.0).powi(2) + dy * dy).sqrt();
+        max_deviation = max_deviation.max((radius - RADIUS).abs());
+    }
+
+    max_deviation
+}
+
+#[test]
+fn default_tolerance() {
+    let deviation = max_edge_deviation(&render_circle(None));
+    assert!(deviation < 0.5, "deviation: {}", deviation);
+}
+
+#[test]
+fn tight_tolerance() {
+    let deviation = max_edge_deviation(&render_circle(Some(0.05)));
+    assert!(deviation < 0.5, "deviation: {}", deviation);
+}
+
+#[test]
+fn loose_tolerance() {
+    let deviation = max_edge_deviation(&render_circle(Some(8.0)));
+    assert!(deviation > 2.0, "deviation: {}", deviation);
+    assert!(deviation < 8.5, "deviation: {}", deviation);
+}
+
+#[test]
+fn invalid_tolerance_is_ignored() {
+    let expected = render_circle(None);
+    assert_eq!(render_circle(Some(0.0)).data(), expected.data());
+    assert_eq!(render_circle(Some(f32::NAN)).data(), expected.data());
+}
@@ -0,0 +1,198 @@
+use usvg::TreeParsing;
+
+use crate::{pixel, render_svg};
+
+#[test]
+fn extract_embedded_images() {
+    let svg = "
+    <svg viewBox='0 0 200 200' xmlns='http://www.w3.org/2000/svg'
+         xmlns:xlink='http://www.w3.org/1999/xlink'>
+        <g transform='translate(10 20)'>
+            <image id='image1' x='8' y='8' width='64' height='64' xlink:href='image.png'/>
+        </g>
+    </svg>
+    ";
+
+    let opt = usvg::Options {
+        resources_dir: Some(std::path::PathBuf::from("tests/resources")),
+        ..usvg::Options::default()
+    };
+    let tree = usvg::Tree::from_str(svg, &opt).unwrap();
+
+    let images: Vec<_> = tree.images().collect();
+    assert_eq!(images.len(), 1);
+
+    let image = &images[0];
+    assert_eq!(image.id, "image1");
+
+    let data = match image.kind {
+        usvg::ImageKind::PNG(ref data) => data,
+        _ => unreachable!(),
+    };
+    assert_eq!(**data, std::fs::read("tests/resources/image.png").unwrap());
+
+    let rect = image.view_box.rect;
+    let mut origin = tiny_skia::Point::from_xy(rect.x(), rect.y());
+    image.abs_transform.map_point(&mut origin);
+    assert_eq!(origin, tiny_skia::Point::from_xy(18.0, 28.0));
+    assert_eq!((rect.width(), rect.height()), (64.0, 64.0));
+
+    let pixmap = resvg::decode_raster_image(&image.kind).unwrap();
+    assert_eq!((pixmap.width(), pixmap.height()), (64, 64));
+}
+
+fn render_image_with_aspect(aspect: &str) -> tiny_skia::Pixmap {
+    // A 20x10 image with a red left half and a blue right half.
+    let svg = format!(
+        "
+    <svg viewBox='0 0 200 200' xmlns='http://www.w3.org/2000/svg'
+         xmlns:xlink='http://www.w3.org/1999/xlink'>
+        <image x='50' y='50' width='100' height='100' preserveAspectRatio='{}'
+               xlink:href=\"data:image/svg+xml;utf8,\
+               &lt;svg xmlns='http://www.w3.org/2000/svg' width='20' height='10'&gt;\
+               &lt;rect width='10' height='10' fill='red'/&gt;\
+               &lt;rect x='10' width='10' height='10' fill='blue'/&gt;\
+               &lt;/svg&gt;\"/>
+    </svg>
+    ",
+        aspect
+    );
+
+    render_svg(&svg, 200, 200)
+}
+
+#[test]
+fn image_aspect_x_min_y_min_slice() {
+    let pixmap = render_image_with_aspect("xMinYMin slice");
+
+    // Scaled to 200x100 and aligned to the left, so only the red half is visible.
+    assert_eq!(pixel(&pixmap, 60, 60), (255, 0, 0, 255));
+    assert_eq!(pixel(&pixmap, 140, 140), (255, 0, 0, 255));
+    // The blue half is cropped by the viewport.
+    assert_eq!(pixel(&pixmap, 170, 100).3, 0);
+}
+
+#[test]
+fn image_aspect_x_max_y_max_meet() {
+    let pixmap = render_image_with_aspect("xMaxYMax meet");
+
+    // Scaled to 100x50 and aligned to the bottom.
+    assert_eq!(pixel(&pixmap, 60, 60).3, 0);
+    assert_eq!(pixel(&pixmap, 60, 140), (255, 0, 0, 255));
+    assert_eq!(pixel(&pixmap, 140, 140), (0, 0, 255, 255));
+    assert_eq!(pixel(&pixmap, 100, 160).3, 0);
+}
+
+struct GifFrame {
+    left: u16,
+    top: u16,
+    width: u16,
+    height: u16,
+    color: [u8; 4],
+}
+
+fn encode_gif(width: u16, height: u16, frames: &[GifFrame]) -> Vec<u8> {
+    let mut data = Vec::new();
+    {
+        let mut encoder = gif::Encoder::new(&mut data, width, height, &[]).unwrap();
+        for f in frames {
+            let mut pixels = f.color.repeat(f.width as usize * f.height as usize);
+            let mut frame = gif::Frame::from_rgba_speed(f.width, f.height, &mut pixels, 10);
+            frame.left = f.left;
+            frame.top = f.top;
+            encoder.write_frame(&frame).unwrap();
+        }
+    }
+
+    data
+}
+
+#[test]
+fn gif_first_frame() {
+    let data = encode_gif(
+        4,
+        4,
+        &[
+            GifFrame {
+                left: 0,
+                top: 0,
+                width: 4,
+                height: 4,
+                color: [255, 0, 0, 255],
+            },
+            GifFrame {
+                left: 0,
+                top: 0,
+                width: 4,
+                height: 4,
+                color: [0, 0, 255, 255],
+            },
+        ],
+    );
+
+    let kind = usvg::ImageKind::GIF(std::sync::Arc::new(data));
+    let pixmap = resvg::decode_raster_image(&kind).unwrap();
+    assert_eq!((pixmap.width(), pixmap.height()), (4, 4));
+    for y in 0..4 {
+        for x in 0..4 {
+            assert_eq!(pixel(&pixmap, x, y), (255, 0, 0, 255));
+        }
+    }
+}
+
+#[test]
+fn gif_first_frame_with_offset() {
+    let data = encode_gif(
+        4,
+        4,
+        &[
+            GifFrame {
+                left: 2,
+                top: 1,
+                width: 2,
+                height: 2,
+                color: [255, 0, 0, 255],
+            },
+            GifFrame {
+                left: 0,
+                top: 0,
+                width: 4,
+                height: 4,
+                color: [0, 0, 255, 255],
+            },
+        ],
+    );
+
+    let kind = usvg::ImageKind::GIF(std::sync::Arc::new(data));
+    let pixmap = resvg::decode_raster_image(&kind).unwrap();
+    // The image has the logical screen size and not the first frame one.
+    assert_eq!((pixmap.width(), pixmap.height()), (4, 4));
+    assert_eq!(pixel(&pixmap, 2, 1), (255, 0, 0, 255));
+    assert_eq!(pixel(&pixmap, 3, 2), (255, 0, 0, 255));
+    assert_eq!(pixel(&pixmap, 1, 1).3, 0);
+    assert_eq!(pixel(&pixmap, 2, 0).3, 0);
+    assert_eq!(pixel(&pixmap, 2, 3).3, 0);
+}
+
+#[test]
+fn apng_default_image() {
+    let mut data = Vec::new();
+    {
+        let mut encoder = png::Encoder::new(&mut data, 4, 4);
+        encoder.set_color(png::ColorType::Rgba);
+        encoder.set_animated(2, 0).unwrap();
+        let mut writer = encoder.write_header().unwrap();
+        writer
+            .write_image_data(&[0, 255, 0, 255].repeat(16))
+            .unwrap();
+        writer
+            .write_image_data(&[0, 0, 255, 255].repeat(16))
+            .unwrap();
+    }
+
+    let kind = usvg::ImageKind::PNG(std::sync::Arc::new(data));
+    let pixmap = resvg::decode_raster_image(&kind).unwrap();
+    assert_eq!((pixmap.width(), pixmap.height()), (4, 4));
+    assert_eq!(pixel(&pixmap, 0, 0), (0, 255, 0, 255));
+    assert_eq!(pixel(&pixmap, 3, 3), (0, 255, 0, 255));
+}
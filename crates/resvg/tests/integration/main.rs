@@ -5,7 +5,25 @@ use usvg::{fontdb, TreeParsing, TreeTextToPath};
 #[rustfmt::skip]
 mod render;
 
+mod bbox;
+mod clip;
+mod degenerate;
 mod extra;
+mod flattening;
+mod image;
+mod mask;
+mod opacity;
+mod paint_server;
+mod postprocess;
+mod render_onto;
+mod selection;
+mod stats;
+mod stroke;
+mod supersample;
+mod text;
+mod turbulence;
+mod unknown_attributes;
+mod viewport;
 
 const IMAGE_SIZE: u32 = 300;
 
@@ -129,6 +147,29 @@ pub fn render_extra(name: &str) -> usize {
     render_extra_with_scale(name, 1.0)
 }
 
+/// Renders an SVG string into a pixmap of the specified size.
+pub fn render_svg(svg: &str, width: u32, height: u32) -> tiny_skia::Pixmap {
+    let tree = usvg::Tree::from_str(svg, &usvg::Options::default()).unwrap();
+    render_tree(&resvg::Tree::from_usvg(&tree), width, height)
+}
+
+/// Renders a tree into a pixmap of the specified size.
+pub fn render_tree(rtree: &resvg::Tree, width: u32, height: u32) -> tiny_skia::Pixmap {
+    let mut pixmap = tiny_skia::Pixmap::new(width, height).unwrap();
+    rtree.render(tiny_skia::Transform::default(), &mut pixmap.as_mut());
+    pixmap
+}
+
+/// Returns a demultiplied pixel as an RGBA tuple.
+pub fn pixel(pixmap: &tiny_skia::Pixmap, x: u32, y: u32) -> (u8, u8, u8, u8) {
+    let c = pixmap.pixel(x, y).unwrap().demultiply();
+    (c.red(), c.green(), c.blue(), c.alpha())
+}
+
+pub fn alpha(pixmap: &tiny_skia::Pixmap, x: u32, y: u32) -> u8 {
+    pixmap.pixel(x, y).unwrap().alpha()
+}
+
 fn load_png(path: &str) -> Vec<u8> {
     let data = std::fs::read(path).unwrap();
     let mut decoder = png::Decoder::new(data.as_slice());
@@ -0,0 +1,90 @@
+use usvg::TreeParsing;
+
+const SRGB_COVERAGE: i32 = 128;
+// A mid-gray in linearRGB.
+const LINEAR_RGB_COVERAGE: i32 = 55;
+
+/// Renders a mid-gray rect with `attrs` and returns the alpha in the center.
+fn render(defs: &str, attrs: &str) -> (i32, resvg::RenderStats) {
+    let svg = format!(
+        "
+        <svg viewBox='0 0 10 10' xmlns='http://www.w3.org/2000/svg'>
+            {}
+            <rect width='10' height='10' fill='rgb(128, 128, 128)' {}/>
+        </svg>
+        ",
+        defs, attrs
+    );
+
+    let tree = usvg::Tree::from_str(&svg, &usvg::Options::default()).unwrap();
+    let rtree = resvg::Tree::from_usvg(&tree);
+    let mut pixmap = tiny_skia::Pixmap::new(10, 10).unwrap();
+    let mut stats = resvg::RenderStats::default();
+    rtree.render_with_stats(
+        tiny_skia::Transform::default(),
+        &mut pixmap.as_mut(),
+        &mut stats,
+    );
+
+    (pixmap.pixel(5, 5).unwrap().alpha() as i32, stats)
+}
+
+fn render_mask(color_interpolation: Option<&str>) -> (i32, resvg::RenderStats) {
+    let attr = color_interpolation
+        .map(|v| format!("color-interpolation='{}'", v))
+        .unwrap_or_default();
+    let defs = format!(
+        "
+        <mask id='mask1' {}>
+            <rect width='10' height='10' fill='rgb(128, 128, 128)'/>
+        </mask>
+        <rect id='rect1' width='10' height='10' fill='black' mask='url(#mask1)'/>
+        ",
+        attr
+    );
+
+    // Render only the masked rect.
+    render(&defs, "visibility='hidden'")
+}
+
+fn render_luminance_to_alpha(color_interpolation: Option<&str>) -> i32 {
+    let attr = color_interpolation
+        .map(|v| format!("color-interpolation-filters='{}'", v))
+        .unwrap_or_default();
+    let defs = format!(
+        "
+        <filter id='filter1' {}>
+            <feColorMatrix type='luminanceToAlpha'/>
+        </filter>
+        ",
+        attr
+    );
+
+    render(&defs, "filter='url(#filter1)'").0
+}
+
+#[test]
+fn luminance_mask_uses_srgb_by_default() {
+    let (alpha, stats) = render_mask(None);
+    assert!((alpha - SRGB_COVERAGE).abs() <= 1, "alpha: {}", alpha);
+    assert_eq!(stats.linear_rgb_masks, 0);
+}
+
+#[test]
+fn luminance_mask_with_linear_rgb() {
+    let (alpha, stats) = render_mask(Some("linearRGB"));
+    assert!((alpha - LINEAR_RGB_COVERAGE).abs() <= 1, "alpha: {}", alpha);
+    assert_eq!(stats.linear_rgb_masks, 1);
+}
+
+#[test]
+fn luminance_to_alpha_uses_linear_rgb_by_default() {
+    let alpha = render_luminance_to_alpha(None);
+    assert!((alpha - LINEAR_RGB_COVERAGE).abs() <= 1, "alpha: {}", alpha);
+}
+
+#[test]
+fn luminance_to_alpha_with_srgb() {
+    let alpha = render_luminance_to_alpha(Some("sRGB"));
+    assert!((alpha - SRGB_COVERAGE).abs() <= 1, "alpha: {}", alpha);
+}
@@ -0,0 +1,40 @@
+use crate::{pixel, render_svg};
+
+#[test]
+fn group_opacity_is_applied_after_compositing() {
+    let pixmap = render_svg(
+        "
+    <svg viewBox='0 0 100 100' xmlns='http://www.w3.org/2000/svg'>
+        <g opacity='0.5'>
+            <rect x='10' y='10' width='50' height='50' fill='red'/>
+            <rect x='40' y='40' width='50' height='50' fill='blue'/>
+        </g>
+    </svg>
+    ",
+        100,
+        100,
+    );
+
+    // Only the top shape is visible in the overlap region.
+    assert_eq!(pixel(&pixmap, 50, 50), (0, 0, 255, 128));
+    assert_eq!(pixel(&pixmap, 20, 20), (255, 0, 0, 128));
+    assert_eq!(pixel(&pixmap, 80, 80), (0, 0, 255, 128));
+}
+
+#[test]
+fn element_opacity_with_fill_and_stroke() {
+    let pixmap = render_svg(
+        "
+    <svg viewBox='0 0 100 100' xmlns='http://www.w3.org/2000/svg'>
+        <rect x='20' y='20' width='60' height='60' fill='red'
+              stroke='blue' stroke-width='20' opacity='0.5'/>
+    </svg>
+    ",
+        100,
+        100,
+    );
+
+    // The fill must not be visible through the stroke.
+    assert_eq!(pixel(&pixmap, 20, 50), (0, 0, 255, 128));
+    assert_eq!(pixel(&pixmap, 50, 50), (255, 0, 0, 128));
+}
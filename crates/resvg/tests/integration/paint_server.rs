@@ -0,0 +1,128 @@
+use usvg::TreeParsing;
+
+use crate::{pixel, render_svg};
+
+fn gradient_midpoint(color_interpolation: &str) -> (u8, u8, u8, u8) {
+    let svg = format!(
+        "
+    <svg viewBox='0 0 100 10' xmlns='http://www.w3.org/2000/svg'>
+        <linearGradient id='lg1' color-interpolation='{}'>
+            <stop offset='0' stop-color='red'/>
+            <stop offset='1' stop-color='lime'/>
+        </linearGradient>
+        <rect x='0' y='0' width='100' height='10' fill='url(#lg1)'/>
+    </svg>
+    ",
+        color_interpolation
+    );
+
+    pixel(&render_svg(&svg, 100, 10), 50, 5)
+}
+
+fn assert_color_eq(a: (u8, u8, u8, u8), b: (u8, u8, u8, u8)) {
+    let diff = |a: u8, b: u8| (a as i16 - b as i16).abs();
+    assert!(
+        diff(a.0, b.0) <= 2 && diff(a.1, b.1) <= 2 && diff(a.2, b.2) <= 2 && a.3 == b.3,
+        "{:?} != {:?}",
+        a,
+        b
+    );
+}
+
+#[test]
+fn gradient_in_srgb() {
+    assert_color_eq(gradient_midpoint("sRGB"), (128, 127, 0, 255));
+}
+
+#[test]
+fn gradient_in_linear_rgb() {
+    // 50% in linearRGB is 188 in sRGB.
+    assert_color_eq(gradient_midpoint("linearRGB"), (188, 188, 0, 255));
+}
+
+fn conic_gradient_pixel(x: u32, y: u32) -> (u8, u8, u8, u8) {
+    let svg = "
+    <svg viewBox='0 0 100 100' xmlns='http://www.w3.org/2000/svg'>
+        <rect x='0' y='0' width='100' height='100'
+              fill='conic-gradient(red, yellow, lime, blue)'/>
+    </svg>
+    ";
+
+    pixel(&render_svg(svg, 100, 100), x, y)
+}
+
+#[test]
+fn conic_gradient_angular_distribution() {
+    // Stops are evenly distributed at 0, 1/3, 2/3 and 1 of the turn,
+    // starting at the top and going clockwise.
+    assert_color_eq(conic_gradient_pixel(50, 0), (255, 0, 0, 255));
+    // 90deg is 3/4 of the way from red to yellow.
+    assert_color_eq(conic_gradient_pixel(99, 49), (255, 191, 0, 255));
+    // 180deg is halfway between yellow and lime.
+    assert_color_eq(conic_gradient_pixel(49, 99), (128, 255, 0, 255));
+    // 270deg is 1/4 of the way from lime to blue.
+    assert_color_eq(conic_gradient_pixel(0, 50), (0, 191, 64, 255));
+}
+
+#[test]
+fn conic_gradient_writing() {
+    use usvg::TreeWriting;
+
+    let svg = "
+    <svg viewBox='0 0 100 100' xmlns='http://www.w3.org/2000/svg'>
+        <rect x='5' y='5' width='40' height='60'
+              fill='conic-gradient(from 30deg at 25% 75%, red, lime 40%, rgba(0, 0, 255, 0.5) 40% 70%, red)'/>
+        <circle cx='70' cy='70' r='20' fill='none' stroke-width='10'
+                stroke='conic-gradient(at 70px 70px, yellow, blue)' transform='rotate(30 70 70)'/>
+    </svg>
+    ";
+
+    let tree = usvg::Tree::from_str(svg, &usvg::Options::default()).unwrap();
+    let written = tree.to_string(&usvg::XmlOptions::default());
+    assert!(!written.contains("conic-gradient("));
+
+    let expected = render_svg(svg, 100, 100);
+    let actual = render_svg(&written, 100, 100);
+    let different = actual
+        .data()
+        .chunks(4)
+        .zip(expected.data().chunks(4))
+        .filter(|(a, b)| {
+            a.iter()
+                .zip(b.iter())
+                .any(|(a, b)| (*a as i32 - *b as i32).abs() > 8)
+        })
+        .count();
+    // Only pixels near the center and along hard stops, where a color changes within a pixel.
+    assert!(different < 200, "{}", different);
+}
+
+#[test]
+fn figma_conic_gradient() {
+    // Figma exports angular gradients as a clipped `foreignObject` with a CSS background.
+    let svg = r#"
+    <svg width="100" height="100" viewBox="0 0 100 100" fill="none" xmlns="http://www.w3.org/2000/svg">
+        <g clip-path="url(#paint0_angular_1_2_clip_path)" data-figma-skip-parse="true">
+            <g transform="matrix(0 0.05 -0.05 0 50 50)">
+                <foreignObject x="-1000" y="-1000" width="2000" height="2000">
+                    <div xmlns="http://www.w3.org/1999/xhtml" style="background:conic-gradient(from 90deg,rgba(255, 0, 0, 1) 0deg,rgba(0, 0, 255, 1) 360deg);height:100%;width:100%;opacity:1"></div>
+                </foreignObject>
+            </g>
+        </g>
+        <rect width="100" height="100" data-figma-gradient-fill="{}"/>
+        <defs>
+            <clipPath id="paint0_angular_1_2_clip_path"><rect x="10" width="90" height="100"/></clipPath>
+        </defs>
+    </svg>
+    "#;
+
+    let pixmap = render_svg(svg, 100, 100);
+    let pixel = |x, y| pixel(&pixmap, x, y);
+
+    // The gradient starts at 90deg and is rotated by 90deg, so it starts at the bottom.
+    assert_color_eq(pixel(50, 0), (128, 0, 127, 255));
+    assert_color_eq(pixel(99, 50), (64, 0, 191, 255));
+    assert_color_eq(pixel(10, 50), (191, 0, 64, 255));
+    // Clipped.
+    assert_color_eq(pixel(9, 50), (0, 0, 0, 0));
+}
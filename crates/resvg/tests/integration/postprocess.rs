@@ -0,0 +1,64 @@
+use usvg::TreeParsing;
+
+use crate::{alpha, render_tree};
+
+#[test]
+fn recolor_fills_to_black() {
+    let svg = "
+    <svg viewBox='0 0 100 100' xmlns='http://www.w3.org/2000/svg'>
+        <linearGradient id='lg1'>
+            <stop offset='0' stop-color='red'/>
+            <stop offset='1' stop-color='blue'/>
+        </linearGradient>
+        <pattern id='patt1' width='10' height='10' patternUnits='userSpaceOnUse'>
+            <rect width='5' height='5' fill='lime'/>
+        </pattern>
+        <rect x='10' y='10' width='30' height='30' fill='seagreen'/>
+        <rect x='60' y='10' width='30' height='30' fill='url(#lg1)'/>
+        <rect x='10' y='60' width='30' height='30' fill='url(#patt1)'/>
+        <circle cx='75' cy='75' r='15' fill='gold' opacity='0.5'/>
+    </svg>
+    ";
+
+    let mut tree = usvg::Tree::from_str(svg, &usvg::Options::default()).unwrap();
+    let mut visited = 0;
+    tree.postprocess(|node| {
+        node.set_fill_paint(usvg::Paint::Color(usvg::Color::black()));
+        visited += 1;
+    });
+    // Root, four shapes and the opacity group.
+    // The pattern is replaced before its content could be visited.
+    assert_eq!(visited, 6);
+
+    let pixmap = render_tree(&resvg::Tree::from_usvg(&tree), 100, 100);
+
+    assert!(pixmap
+        .pixels()
+        .iter()
+        .all(|p| p.red() == 0 && p.green() == 0 && p.blue() == 0));
+    // Make sure that something was actually rendered.
+    let alpha = |x, y| alpha(&pixmap, x, y);
+    assert_eq!(alpha(25, 25), 255);
+    assert_eq!(alpha(75, 25), 255);
+    assert_eq!(alpha(12, 62), 255);
+    assert_eq!(alpha(75, 75), 128);
+}
+
+#[test]
+fn visit_shared_pattern_once() {
+    let svg = "
+    <svg viewBox='0 0 100 100' xmlns='http://www.w3.org/2000/svg'>
+        <pattern id='patt1' width='10' height='10' patternUnits='userSpaceOnUse'>
+            <rect id='rect1' width='5' height='5' fill='lime'/>
+        </pattern>
+        <rect x='10' y='10' width='30' height='30' fill='url(#patt1)'/>
+        <rect x='60' y='10' width='30' height='30' fill='url(#patt1)'/>
+    </svg>
+    ";
+
+    let mut tree = usvg::Tree::from_str(svg, &usvg::Options::default()).unwrap();
+    let mut ids = Vec::new();
+    tree.postprocess(|node| ids.push(node.id().to_string()));
+    // Root, first rect, pattern root, pattern rect and second rect.
+    assert_eq!(ids, ["", "", "", "rect1", ""]);
+}
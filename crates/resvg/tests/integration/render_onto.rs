@@ -0,0 +1,75 @@
+use usvg::TreeParsing;
+
+use crate::pixel;
+
+fn tree(svg: &str) -> resvg::Tree {
+    let tree = usvg::Tree::from_str(svg, &usvg::Options::default()).unwrap();
+    resvg::Tree::from_usvg(&tree)
+}
+
+const SQUARE: &str = "
+<svg width='40' height='40' xmlns='http://www.w3.org/2000/svg'>
+    <rect width='40' height='40' fill='red'/>
+</svg>
+";
+
+const CIRCLE: &str = "
+<svg width='40' height='40' xmlns='http://www.w3.org/2000/svg'>
+    <circle cx='20' cy='20' r='20' fill='blue'/>
+</svg>
+";
+
+const WHITE: (u8, u8, u8, u8) = (255, 255, 255, 255);
+const RED: (u8, u8, u8, u8) = (255, 0, 0, 255);
+const BLUE: (u8, u8, u8, u8) = (0, 0, 255, 255);
+
+#[test]
+fn two_regions() {
+    let mut pixmap = tiny_skia::Pixmap::new(100, 50).unwrap();
+    pixmap.fill(tiny_skia::Color::WHITE);
+
+    let ts = tiny_skia::Transform::default();
+    tree(SQUARE).render_onto(&mut pixmap.as_mut(), (5, 5), ts);
+    tree(CIRCLE).render_onto(&mut pixmap.as_mut(), (55, 5), ts);
+
+    assert_eq!(pixel(&pixmap, 4, 4), WHITE);
+    assert_eq!(pixel(&pixmap, 5, 5), RED);
+    assert_eq!(pixel(&pixmap, 44, 44), RED);
+    assert_eq!(pixel(&pixmap, 45, 45), WHITE);
+    assert_eq!(pixel(&pixmap, 50, 25), WHITE);
+
+    assert_eq!(pixel(&pixmap, 56, 6), WHITE);
+    assert_eq!(pixel(&pixmap, 75, 25), BLUE);
+    assert_eq!(pixel(&pixmap, 94, 25), BLUE);
+
+    // Anti-aliased edges are blended with the existing content and not with transparency.
+    let edge = pixel(&pixmap, 61, 10);
+    assert_eq!(edge.3, 255);
+    assert!(edge.0 > 0 && edge.0 < 255 && edge.2 == 255, "{:?}", edge);
+}
+
+#[test]
+fn region_with_transform() {
+    let mut pixmap = tiny_skia::Pixmap::new(100, 100).unwrap();
+
+    let ts = tiny_skia::Transform::from_scale(0.5, 0.5);
+    tree(SQUARE).render_onto(&mut pixmap.as_mut(), (10, 60), ts);
+
+    assert_eq!(pixel(&pixmap, 10, 60), RED);
+    assert_eq!(pixel(&pixmap, 29, 79), RED);
+    assert_eq!(pixel(&pixmap, 30, 80).3, 0);
+    assert_eq!(pixel(&pixmap, 9, 59).3, 0);
+}
+
+#[test]
+fn region_outside_of_pixmap() {
+    let mut pixmap = tiny_skia::Pixmap::new(50, 50).unwrap();
+
+    let ts = tiny_skia::Transform::default();
+    tree(SQUARE).render_onto(&mut pixmap.as_mut(), (-20, 30), ts);
+
+    assert_eq!(pixel(&pixmap, 0, 30), RED);
+    assert_eq!(pixel(&pixmap, 19, 49), RED);
+    assert_eq!(pixel(&pixmap, 20, 30).3, 0);
+    assert_eq!(pixel(&pixmap, 0, 29).3, 0);
+}
@@ -0,0 +1,149 @@
+use usvg::TreeParsing;
+
+use crate::{pixel, render_tree};
+
+const SVG: &str = "
+<svg viewBox='0 0 100 100' xmlns='http://www.w3.org/2000/svg'>
+    <rect width='100' height='100' fill='white'/>
+    <g opacity='0.5'>
+        <rect id='rect1' x='10' y='10' width='20' height='80' fill='red'/>
+        <rect id='rect2' x='40' y='10' width='20' height='80' fill='green'/>
+        <rect id='rect3' x='70' y='10' width='20' height='80' fill='blue'/>
+    </g>
+</svg>
+";
+
+fn render(selection: &resvg::LayerSelection) -> tiny_skia::Pixmap {
+    let tree = usvg::Tree::from_str(SVG, &usvg::Options::default()).unwrap();
+    let rtree = resvg::Tree::from_usvg_with_selection(&tree, selection);
+    render_tree(&rtree, 100, 100)
+}
+
+fn ids(list: &[&str]) -> std::collections::HashSet<String> {
+    list.iter().map(|s| s.to_string()).collect()
+}
+
+#[test]
+fn exclude_element() {
+    let full = render(&resvg::LayerSelection::default());
+    let selection = resvg::LayerSelection {
+        exclude: ids(&["rect2"]),
+        ..resvg::LayerSelection::default()
+    };
+    let pixmap = render(&selection);
+
+    // Other shapes are rendered in place, with the same backdrop.
+    assert_eq!(pixel(&pixmap, 20, 50), pixel(&full, 20, 50));
+    assert_eq!(pixel(&pixmap, 80, 50), pixel(&full, 80, 50));
+    assert_eq!(pixel(&pixmap, 5, 5), pixel(&full, 5, 5));
+    assert_ne!(pixel(&full, 50, 50), (255, 255, 255, 255));
+    assert_eq!(pixel(&pixmap, 50, 50), (255, 255, 255, 255));
+}
+
+#[test]
+fn include_elements() {
+    let selection = resvg::LayerSelection {
+        include: ids(&["rect1", "rect2"]),
+        exclude: ids(&["rect1"]),
+    };
+    let pixmap = render(&selection);
+
+    // Exclusion takes precedence.
+    assert_eq!(pixel(&pixmap, 20, 50).3, 0);
+    // Group opacity is still applied.
+    assert_eq!(pixel(&pixmap, 50, 50), (0, 128, 0, 128));
+    // Not included.
+    assert_eq!(pixel(&pixmap, 80, 50).3, 0);
+    assert_eq!(pixel(&pixmap, 5, 5).3, 0);
+}
+
+#[test]
+fn toggle_visibility() {
+    let tree = usvg::Tree::from_str(SVG, &usvg::Options::default()).unwrap();
+    let mut rtree = resvg::Tree::from_usvg(&tree);
+    let full = render_tree(&rtree, 100, 100);
+
+    assert!(rtree.set_visible("rect2", false));
+    let selection = resvg::LayerSelection {
+        exclude: ids(&["rect2"]),
+        ..resvg::LayerSelection::default()
+    };
+    assert_eq!(
+        render_tree(&rtree, 100, 100).data(),
+        render(&selection).data()
+    );
+
+    assert!(rtree.set_visible("rect2", true));
+    assert_eq!(render_tree(&rtree, 100, 100).data(), full.data());
+
+    assert!(!rtree.set_visible("missing", false));
+}
+
+#[test]
+fn toggle_group_visibility() {
+    let svg = "
+    <svg viewBox='0 0 100 100' xmlns='http://www.w3.org/2000/svg'>
+        <rect id='rect1' x='10' y='10' width='20' height='20' fill='red'/>
+        <g id='g1'>
+            <rect id='rect2' x='40' y='10' width='20' height='80' fill='green'/>
+            <rect id='rect3' x='70' y='60' width='20' height='20' fill='blue'/>
+        </g>
+    </svg>
+    ";
+
+    let tree = usvg::Tree::from_str(svg, &usvg::Options::default()).unwrap();
+    let mut rtree = resvg::Tree::from_usvg(&tree);
+    let full_area = rtree.content_area;
+    let full = render_tree(&rtree, 100, 100);
+
+    // Hiding a group hides its children.
+    assert!(rtree.set_visible("g1", false));
+    let pixmap = render_tree(&rtree, 100, 100);
+    assert_eq!(pixel(&pixmap, 50, 50), (0, 0, 0, 0));
+    assert_eq!(pixel(&pixmap, 80, 70), (0, 0, 0, 0));
+    assert_eq!(pixel(&pixmap, 20, 20), (255, 0, 0, 255));
+    assert_eq!(
+        rtree.content_area,
+        tiny_skia::Rect::from_xywh(10.0, 10.0, 20.0, 20.0)
+    );
+
+    assert!(rtree.set_visible("g1", true));
+    assert!(rtree.set_visible("rect3", false));
+    assert_eq!(
+        rtree.content_area,
+        tiny_skia::Rect::from_xywh(10.0, 10.0, 50.0, 80.0)
+    );
+
+    assert!(rtree.set_visible("rect3", true));
+    assert_eq!(rtree.content_area, full_area);
+    assert_eq!(render_tree(&rtree, 100, 100).data(), full.data());
+}
+
+#[test]
+fn toggle_stroked_path_visibility() {
+    let svg = "
+    <svg viewBox='0 0 100 100' xmlns='http://www.w3.org/2000/svg'>
+        <rect id='rect1' x='20' y='20' width='60' height='60' fill='green'
+              stroke='blue' stroke-width='10' paint-order='stroke'/>
+    </svg>
+    ";
+
+    let tree = usvg::Tree::from_str(svg, &usvg::Options::default()).unwrap();
+    let mut rtree = resvg::Tree::from_usvg(&tree);
+    let full = render_tree(&rtree, 100, 100);
+
+    // Both the fill and the stroke are hidden. Repeated toggles are fine.
+    for _ in 0..2 {
+        assert!(rtree.set_visible("rect1", false));
+        let pixmap = render_tree(&rtree, 100, 100);
+        assert!(pixmap.pixels().iter().all(|p| p.alpha() == 0));
+        assert_eq!(rtree.content_area, None);
+
+        assert!(rtree.set_visible("rect1", true));
+        assert_eq!(render_tree(&rtree, 100, 100).data(), full.data());
+        assert_eq!(
+            rtree.content_area,
+            tiny_skia::Rect::from_xywh(15.0, 15.0, 70.0, 70.0)
+        );
+    }
+}
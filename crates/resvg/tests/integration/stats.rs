@@ -0,0 +1,69 @@
+use usvg::TreeParsing;
+
+#[test]
+fn filter_and_mask() {
+    let svg = "
+    <svg viewBox='0 0 100 100' xmlns='http://www.w3.org/2000/svg'>
+        <filter id='filter1'>
+            <feGaussianBlur stdDeviation='2'/>
+        </filter>
+        <mask id='mask1'>
+            <rect x='20' y='60' width='60' height='20' fill='white'/>
+        </mask>
+        <rect x='20' y='20' width='60' height='20' fill='green' filter='url(#filter1)'/>
+        <rect x='10' y='50' width='80' height='40' fill='blue' mask='url(#mask1)'/>
+    </svg>
+    ";
+
+    let tree = usvg::Tree::from_str(svg, &usvg::Options::default()).unwrap();
+    let rtree = resvg::Tree::from_usvg(&tree);
+    let mut pixmap = tiny_skia::Pixmap::new(100, 100).unwrap();
+
+    let mut stats = resvg::RenderStats::default();
+    rtree.render_with_stats(
+        tiny_skia::Transform::default(),
+        &mut pixmap.as_mut(),
+        &mut stats,
+    );
+
+    // Two rects and a mask content.
+    assert_eq!(stats.nodes_painted, 3);
+    // A filter group layer, a mask group layer and a mask itself.
+    assert_eq!(stats.layers, 3);
+    assert_eq!(stats.filters, 1);
+    assert!(stats.filtered_pixels > 0);
+
+    // Statistics are accumulated.
+    rtree.render_with_stats(
+        tiny_skia::Transform::default(),
+        &mut pixmap.as_mut(),
+        &mut stats,
+    );
+    assert_eq!(stats.nodes_painted, 6);
+    assert_eq!(stats.layers, 6);
+    assert_eq!(stats.filters, 2);
+}
+
+#[test]
+fn paint_times() {
+    let svg = "
+    <svg viewBox='0 0 100 100' xmlns='http://www.w3.org/2000/svg'>
+        <rect x='20' y='20' width='60' height='60' fill='none' stroke='green' stroke-width='5'/>
+    </svg>
+    ";
+
+    let tree = usvg::Tree::from_str(svg, &usvg::Options::default()).unwrap();
+    let rtree = resvg::Tree::from_usvg(&tree);
+    let mut pixmap = tiny_skia::Pixmap::new(100, 100).unwrap();
+
+    let mut stats = resvg::RenderStats::default();
+    rtree.render_with_stats(
+        tiny_skia::Transform::default(),
+        &mut pixmap.as_mut(),
+        &mut stats,
+    );
+
+    assert!(stats.stroke_time > std::time::Duration::ZERO);
+    assert_eq!(stats.fill_time, std::time::Duration::ZERO);
+    assert_eq!(stats.image_time, std::time::Duration::ZERO);
+}
@@ -0,0 +1,46 @@
+use crate::{alpha, render_svg};
+
+/// Renders a sharp corner with a miter join and returns the alpha of a pixel
+/// that is covered only by a miter.
+fn miter_tip_alpha(miterlimit: &str) -> u8 {
+    let svg = format!(
+        "
+    <svg viewBox='0 0 100 100' xmlns='http://www.w3.org/2000/svg'>
+        <path d='M 20 30 L 80 50 L 20 70' fill='none' stroke='black' stroke-width='10'
+              stroke-linejoin='miter' {}/>
+    </svg>
+    ",
+        miterlimit
+    );
+
+    let pixmap = render_svg(&svg, 100, 100);
+    // The miter ratio of this corner is ~3.16, so its tip is at x=95.8.
+    // A bevel ends at x=81.6.
+    alpha(&pixmap, 90, 50)
+}
+
+#[test]
+fn miterlimit_one_always_bevels() {
+    assert_eq!(miter_tip_alpha("stroke-miterlimit='1'"), 0);
+}
+
+#[test]
+fn miterlimit_above_ratio_keeps_miter() {
+    assert_eq!(miter_tip_alpha("stroke-miterlimit='10'"), 255);
+}
+
+#[test]
+fn miterlimit_default() {
+    assert_eq!(miter_tip_alpha(""), 255);
+}
+
+#[test]
+fn miterlimit_below_ratio_bevels() {
+    assert_eq!(miter_tip_alpha("stroke-miterlimit='3'"), 0);
+}
+
+#[test]
+fn invalid_miterlimit_is_ignored() {
+    // Falls back to the default value of 4 instead of being clamped to 1.
+    assert_eq!(miter_tip_alpha("stroke-miterlimit='0.5'"), 255);
+}
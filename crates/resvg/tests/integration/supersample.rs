@@ -0,0 +1,106 @@
+use usvg::TreeParsing;
+
+fn render(svg: &str, factor: u32) -> tiny_skia::Pixmap {
+    let tree = usvg::Tree::from_str(svg, &usvg::Options::default()).unwrap();
+    let tree = resvg::Tree::from_usvg(&tree);
+    let mut pixmap = tiny_skia::Pixmap::new(100, 40).unwrap();
+    tree.render_supersampled(
+        factor,
+        tiny_skia::Transform::default(),
+        &mut pixmap.as_mut(),
+    );
+    pixmap
+}
+
+/// Returns the mean coverage error of a stroked line in `10..90` columns.
+///
+/// The exact coverage is estimated using 32x32 samples per pixel.
+fn coverage_error(pixmap: &tiny_skia::Pixmap, from: (f32, f32), to: (f32, f32), width: f32) -> f32 {
+    const SAMPLES: u32 = 32;
+
+    let len = ((to.0 - from.0).powi(2) + (to.1 - from.1).powi(2)).sqrt();
+    let dir = ((to.0 - from.0) / len, (to.1 - from.1) / len);
+    let is_inside =
+        |x: f32, y: f32| ((x - from.0) * dir.1 - (y - from.1) * dir.0).abs() <= width / 2.0;
+
+    let mut error = 0.0;
+    let mut count = 0;
+    for y in 0..pixmap.height() {
+        for x in 10..90 {
+            let mut inside = 0;
+            for sy in 0..SAMPLES {
+                for sx in 0..SAMPLES {
+                    let px = x as f32 + (sx as f32 + 0.5) / SAMPLES as f32;
+                    let py = y as f32 + (sy as f32 + 0.5) / SAMPLES as f32;
+                    if is_inside(px, py) {
+                        inside += 1;
+                    }
+                }
+            }
+
+            let exact = inside as f32 / (SAMPLES * SAMPLES) as f32;
+            let actual = pixmap.pixel(x, y).unwrap().alpha() as f32 / 255.0;
+            if exact > 0.0 || actual > 0.0 {
+                error += (exact - actual).abs();
+                count += 1;
+            }
+        }
+    }
+
+    error / count as f32
+}
+
+#[test]
+fn thin_diagonal_line() {
+    let svg = "
+    <svg viewBox='0 0 100 40' xmlns='http://www.w3.org/2000/svg'>
+        <path d='M 0 10 L 100 27' stroke='black' stroke-width='0.5'/>
+    </svg>
+    ";
+
+    let line = ((0.0, 10.0), (100.0, 27.0), 0.5);
+    let error = |factor| coverage_error(&render(svg, factor), line.0, line.1, line.2);
+    let e1 = error(1);
+    let e4 = error(4);
+    assert!(e4 < e1 / 4.0, "{} {}", e1, e4);
+}
+
+#[test]
+fn pixel_aligned_content() {
+    // Content is blended with the existing pixmap content, like in `render`.
+    let svg = "
+    <svg viewBox='0 0 100 40' xmlns='http://www.w3.org/2000/svg'>
+        <rect x='10' y='10' width='50' height='20' fill='green' opacity='0.5'/>
+    </svg>
+    ";
+
+    let tree = usvg::Tree::from_str(svg, &usvg::Options::default()).unwrap();
+    let tree = resvg::Tree::from_usvg(&tree);
+
+    let mut expected = tiny_skia::Pixmap::new(100, 40).unwrap();
+    expected.fill(tiny_skia::Color::WHITE);
+    let mut pixmap = expected.clone();
+
+    tree.render(tiny_skia::Transform::default(), &mut expected.as_mut());
+    tree.render_supersampled(3, tiny_skia::Transform::default(), &mut pixmap.as_mut());
+    let max_diff = pixmap
+        .data()
+        .iter()
+        .zip(expected.data())
+        .map(|(a, b)| (*a as i32 - *b as i32).abs())
+        .max();
+    // Allow rounding errors, since the content is blended twice.
+    assert!(max_diff <= Some(1));
+}
+
+#[test]
+fn huge_factor() {
+    let svg = "
+    <svg viewBox='0 0 100 40' xmlns='http://www.w3.org/2000/svg'>
+        <path d='M 0 10 L 100 27' stroke='black' stroke-width='0.5'/>
+    </svg>
+    ";
+
+    let max = render(svg, resvg::MAX_SUPERSAMPLING_FACTOR);
+    assert!(render(svg, u32::MAX).data() == max.data());
+}
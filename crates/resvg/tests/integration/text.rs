@@ -0,0 +1,301 @@
+use usvg::{NodeExt, TreeParsing, TreeTextToPath};
+
+use crate::GLOBAL_FONTDB;
+
+fn paths_data(tree: &usvg::Tree) -> Vec<String> {
+    let mut data = Vec::new();
+    for node in tree.root.descendants() {
+        if let usvg::NodeKind::Path(ref path) = *node.borrow() {
+            data.push(format!("{:?} {:?}", node.abs_transform(), path.data));
+        }
+    }
+
+    data
+}
+
+#[test]
+fn shaping_cache() {
+    let svg = "
+    <svg viewBox='0 0 200 200' xmlns='http://www.w3.org/2000/svg'
+         font-family='Noto Sans' font-size='24'>
+        <text x='20' y='50'>Label</text>
+        <text x='20' y='100' font-size='48'>Label</text>
+    </svg>
+    ";
+
+    let fontdb = GLOBAL_FONTDB.lock().unwrap();
+    let opt = usvg::Options::default();
+
+    let mut expected = usvg::Tree::from_str(svg, &opt).unwrap();
+    expected.convert_text(&fontdb);
+    let expected = paths_data(&expected);
+
+    let mut cache = usvg::ShapingCache::new();
+
+    let mut tree = usvg::Tree::from_str(svg, &opt).unwrap();
+    assert!(tree.convert_text_with_cache(&fontdb, &mut cache));
+    assert_eq!(paths_data(&tree), expected);
+    // Shaping doesn't depend on the font size, therefore the second text is already cached.
+    assert_eq!(cache.len(), 1);
+    assert_eq!(cache.misses(), 1);
+    assert_eq!(cache.hits(), 1);
+
+    let mut tree = usvg::Tree::from_str(svg, &opt).unwrap();
+    assert!(tree.convert_text_with_cache(&fontdb, &mut cache));
+    assert_eq!(paths_data(&tree), expected);
+    assert_eq!(cache.len(), 1);
+    assert_eq!(cache.misses(), 1);
+    assert_eq!(cache.hits(), 3);
+}
+
+#[test]
+fn shaping_cache_with_embedded_fonts() {
+    let svg = std::fs::read_to_string("tests/tests/text/font-face/woff-data-url.svg").unwrap();
+
+    let fontdb = GLOBAL_FONTDB.lock().unwrap();
+    let opt = usvg::Options::default();
+
+    let mut expected = usvg::Tree::from_str(&svg, &opt).unwrap();
+    expected.convert_text(&fontdb);
+    let expected = paths_data(&expected);
+    assert!(!expected.is_empty());
+
+    // Embedded fonts are loaded into a temporary database, so the cache is not used.
+    let mut cache = usvg::ShapingCache::new();
+    let mut tree = usvg::Tree::from_str(&svg, &opt).unwrap();
+    assert!(!tree.convert_text_with_cache(&fontdb, &mut cache));
+    assert_eq!(paths_data(&tree), expected);
+    assert!(cache.is_empty());
+    assert_eq!(cache.misses(), 0);
+}
+
+fn text_width(text: &str, style: &str) -> f32 {
+    let svg = format!(
+        "
+    <svg viewBox='0 0 200 200' xmlns='http://www.w3.org/2000/svg'
+         font-family='Noto Sans' font-size='48'>
+        <text x='20' y='100' style=\"{}\">{}</text>
+    </svg>
+    ",
+        style, text
+    );
+
+    let fontdb = GLOBAL_FONTDB.lock().unwrap();
+    let mut tree = usvg::Tree::from_str(&svg, &usvg::Options::default()).unwrap();
+    tree.convert_text(&fontdb);
+    tree.root.calculate_bbox().unwrap().width()
+}
+
+#[test]
+fn disable_kerning() {
+    let kerned = text_width("AV", "");
+    let not_kerned = text_width("AV", "font-kerning: none");
+    assert!(not_kerned > kerned + 1.0);
+
+    assert_eq!(
+        text_width("AV", "font-feature-settings: 'kern' off"),
+        not_kerned
+    );
+    // Low-level settings override `font-kerning`.
+    assert_eq!(
+        text_width("AV", "font-kerning: none; font-feature-settings: 'kern' 1"),
+        kerned
+    );
+    // Invalid settings are ignored.
+    assert_eq!(
+        text_width("AV", "font-feature-settings: 'kern' off, 'toolong'"),
+        kerned
+    );
+}
+
+#[test]
+fn replace_text() {
+    let svg = |label: &str| {
+        format!(
+            "
+    <svg viewBox='0 0 200 200' xmlns='http://www.w3.org/2000/svg'
+         font-family='Noto Sans' font-size='24'>
+        <text id='label' x='20' y='50' fill='green'>{}</text>
+        <text id='other' x='20' y='100'>Other</text>
+    </svg>
+    ",
+            label
+        )
+    };
+
+    let fontdb = GLOBAL_FONTDB.lock().unwrap();
+    let opt = usvg::Options::default();
+
+    let source = usvg::Tree::from_str(&svg("Label"), &opt).unwrap();
+    let mut tree = source.clone();
+    tree.root = source.root.make_deep_copy();
+    tree.convert_text(&fontdb);
+    let other = tree.node_by_id("other").unwrap();
+
+    assert!(tree.replace_text(&source, "label", "New text", &fontdb));
+
+    let mut expected = usvg::Tree::from_str(&svg("New text"), &opt).unwrap();
+    expected.convert_text(&fontdb);
+    assert_eq!(paths_data(&tree), paths_data(&expected));
+
+    // Other nodes are not converted again.
+    assert!(tree.node_by_id("other").unwrap() == other);
+    // The source tree can be reused.
+    assert!(matches!(
+        *source.node_by_id("label").unwrap().borrow(),
+        usvg::NodeKind::Text(_)
+    ));
+
+    assert!(!tree.replace_text(&source, "missing", "New text", &fontdb));
+}
+
+#[test]
+fn font_substitution() {
+    let svg = |family: &str| {
+        format!(
+            "
+    <svg viewBox='0 0 200 200' xmlns='http://www.w3.org/2000/svg'>
+        <text x='20' y='100' font-family=\"{}\" font-size='48' font-weight='bold'>Text</text>
+    </svg>
+    ",
+            family
+        )
+    };
+
+    let fontdb = GLOBAL_FONTDB.lock().unwrap();
+    let convert = |svg: &str, opt: &usvg::Options| {
+        let mut tree = usvg::Tree::from_str(svg, opt).unwrap();
+        tree.convert_text(&fontdb);
+        paths_data(&tree)
+    };
+
+    let default_opt = usvg::Options::default();
+    let expected = convert(&svg("Noto Sans"), &default_opt);
+    assert_ne!(convert(&svg("Helvetica"), &default_opt), expected);
+
+    let mut opt = usvg::Options::default();
+    opt.font_substitutions
+        .insert("helvetica".to_string(), "Noto Sans".to_string());
+    assert_eq!(convert(&svg("Helvetica"), &opt), expected);
+    assert_eq!(convert(&svg("'Helvetica', serif"), &opt), expected);
+}
+
+/// Returns the bottom of the text bbox, i.e. a baseline for text without descenders.
+fn text_bottom(content: &str) -> f32 {
+    let svg = format!(
+        "
+    <svg viewBox='0 0 200 200' xmlns='http://www.w3.org/2000/svg'
+         font-family='Noto Sans' font-size='48'>
+        <text x='20' y='100'>{}</text>
+    </svg>
+    ",
+        content
+    );
+
+    let fontdb = GLOBAL_FONTDB.lock().unwrap();
+    let mut tree = usvg::Tree::from_str(&svg, &usvg::Options::default()).unwrap();
+    tree.convert_text(&fontdb);
+    tree.root.calculate_bbox().unwrap().bottom()
+}
+
+#[test]
+fn nested_baseline_shift() {
+    let baseline = text_bottom("x");
+
+    let shift = baseline - text_bottom("<tspan baseline-shift='super'>x</tspan>");
+    assert!(shift > 1.0);
+
+    // Nested shifts are cumulative.
+    let nested_shift = baseline
+        - text_bottom(
+            "<tspan baseline-shift='super'><tspan baseline-shift='super'>x</tspan></tspan>",
+        );
+    assert!((nested_shift - shift * 2.0).abs() < 0.01);
+
+    let sub_shift = baseline - text_bottom("<tspan baseline-shift='sub'>x</tspan>");
+    assert!(sub_shift < -1.0);
+
+    // Percentages are resolved against the font size.
+    let percent_shift = baseline
+        - text_bottom("<tspan baseline-shift='50%'><tspan baseline-shift='25%'>x</tspan></tspan>");
+    assert!((percent_shift - 36.0).abs() < 0.01);
+
+    // `dy` is applied in addition to the shift.
+    let dy_shift = baseline - text_bottom("<tspan baseline-shift='super' dy='-10'>x</tspan>");
+    assert!((dy_shift - shift - 10.0).abs() < 0.01);
+}
+
+#[test]
+fn inline_size_wrapping() {
+    let word_width = text_width("xxxx", "");
+
+    let svg = format!(
+        "
+    <svg viewBox='0 0 200 200' xmlns='http://www.w3.org/2000/svg'
+         font-family='Noto Sans' font-size='48'>
+        <text x='20' y='100' inline-size='{}'>xxxx xxxx</text>
+    </svg>
+    ",
+        word_width * 1.5
+    );
+
+    let fontdb = GLOBAL_FONTDB.lock().unwrap();
+    let mut tree = usvg::Tree::from_str(&svg, &usvg::Options::default()).unwrap();
+    tree.convert_text(&fontdb);
+
+    // Each line is a separate path.
+    let lines: Vec<_> = tree
+        .root
+        .descendants()
+        .filter(|n| matches!(*n.borrow(), usvg::NodeKind::Path(_)))
+        .map(|n| n.calculate_bbox().unwrap())
+        .collect();
+    assert_eq!(lines.len(), 2);
+
+    // Both lines start at `x`.
+    assert!((lines[0].left() - lines[1].left()).abs() < 0.01);
+    assert!((lines[0].width() - word_width).abs() < 0.01);
+    assert!((lines[1].width() - word_width).abs() < 0.01);
+
+    // The first baseline is at `y` and the next one is a line height below.
+    // Noto Sans has an ascender of 1069, a descender of -293 and no line gap.
+    let line_height = (1069.0 + 293.0) / 1000.0 * 48.0;
+    assert!((lines[0].bottom() - 100.0).abs() < 0.5);
+    assert!((lines[1].bottom() - lines[0].bottom() - line_height).abs() < 0.01);
+}
+
+#[test]
+fn write_quoted_font_families() {
+    use usvg::TreeWriting;
+
+    let svg = "
+    <svg viewBox='0 0 200 200' xmlns='http://www.w3.org/2000/svg'>
+        <text x='20' y='50' font-family=\"&quot;Bob's Font&quot;, 'Noto &quot;Sans&quot;', serif\">
+            Text
+        </text>
+    </svg>
+    ";
+
+    let families = |tree: &usvg::Tree| {
+        let mut families = Vec::new();
+        for node in tree.root.descendants() {
+            if let usvg::NodeKind::Text(ref text) = *node.borrow() {
+                for chunk in &text.chunks {
+                    for span in &chunk.spans {
+                        families.extend(span.font.families.iter().cloned());
+                    }
+                }
+            }
+        }
+
+        families
+    };
+
+    let opt = usvg::Options::default();
+    let tree = usvg::Tree::from_str(svg, &opt).unwrap();
+    assert_eq!(families(&tree), ["Bob's Font", "Noto \"Sans\"", "serif"]);
+
+    let output = tree.to_string(&usvg::XmlOptions::default());
+    let tree = usvg::Tree::from_str(&output, &opt).unwrap();
+    assert_eq!(families(&tree), ["Bob's Font", "Noto \"Sans\"", "serif"]);
+}
@@ -0,0 +1,77 @@
+use crate::render_svg;
+
+// Expected values were cross-checked against a direct port of the reference
+// implementation from the `feTurbulence` section of the SVG 1.1 specification.
+
+fn render(kind: &str, seed: i32) -> tiny_skia::Pixmap {
+    let svg = format!(
+        "
+        <svg viewBox='0 0 8 8' xmlns='http://www.w3.org/2000/svg'>
+            <filter id='filter1' filterUnits='userSpaceOnUse' x='0' y='0' width='8' height='8'
+                    color-interpolation-filters='sRGB'>
+                <feTurbulence type='{}' baseFrequency='0.05' numOctaves='2' seed='{}'/>
+            </filter>
+            <rect width='8' height='8' filter='url(#filter1)'/>
+        </svg>
+        ",
+        kind, seed
+    );
+
+    render_svg(&svg, 8, 8)
+}
+
+/// Returns premultiplied RGBA pixels of a row.
+fn row(pixmap: &tiny_skia::Pixmap, y: u32) -> Vec<[u8; 4]> {
+    (0..pixmap.width())
+        .map(|x| {
+            let c = pixmap.pixel(x, y).unwrap();
+            [c.red(), c.green(), c.blue(), c.alpha()]
+        })
+        .collect()
+}
+
+#[test]
+fn turbulence_with_zero_seed() {
+    let pixmap = render("turbulence", 0);
+
+    #[rustfmt::skip]
+    assert_eq!(row(&pixmap, 0), [
+        [0, 0, 0, 0], [0, 1, 0, 10], [1, 3, 1, 19], [1, 5, 2, 25],
+        [1, 8, 4, 29], [2, 11, 5, 31], [1, 12, 6, 31], [1, 11, 6, 29],
+    ]);
+
+    #[rustfmt::skip]
+    assert_eq!(row(&pixmap, 7), [
+        [12, 4, 5, 41], [11, 3, 5, 43], [12, 6, 5, 53], [14, 12, 5, 62],
+        [15, 19, 4, 67], [14, 24, 3, 67], [12, 26, 2, 61], [13, 33, 5, 69],
+    ]);
+}
+
+#[test]
+fn fractal_noise_with_zero_seed() {
+    let pixmap = render("fractalNoise", 0);
+
+    #[rustfmt::skip]
+    assert_eq!(row(&pixmap, 0), [
+        [64, 64, 64, 128], [68, 63, 67, 133], [71, 59, 71, 137], [73, 54, 75, 140],
+        [75, 50, 80, 142], [75, 47, 83, 143], [75, 45, 86, 143], [74, 44, 86, 142],
+    ]);
+
+    #[rustfmt::skip]
+    assert_eq!(row(&pixmap, 7), [
+        [95, 73, 72, 148], [89, 67, 68, 142], [81, 59, 64, 134], [74, 50, 60, 124],
+        [67, 41, 56, 114], [61, 34, 53, 105], [57, 28, 51, 98], [54, 24, 50, 93],
+    ]);
+}
+
+#[test]
+fn non_positive_seed() {
+    // The reference implementation maps seeds below 1 onto `1 - seed`.
+    let seed_1 = render("turbulence", 1);
+    assert_eq!(render("turbulence", 0).data(), seed_1.data());
+    assert_ne!(render("turbulence", 2).data(), seed_1.data());
+    assert_eq!(
+        render("turbulence", -1).data(),
+        render("turbulence", 2).data()
+    );
+}
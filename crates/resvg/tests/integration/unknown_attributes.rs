@@ -0,0 +1,117 @@
+use usvg::{TreeParsing, TreeWriting};
+
+fn unknown_attributes(tree: &usvg::Tree, id: &str) -> Vec<usvg::UnknownAttribute> {
+    match *tree.node_by_id(id).unwrap().borrow() {
+        usvg::NodeKind::Group(ref g) => g.unknown_attributes.clone(),
+        _ => Vec::new(),
+    }
+}
+
+#[test]
+fn preserve_unknown_attributes() {
+    let svg = "
+    <svg viewBox='0 0 100 100' xmlns='http://www.w3.org/2000/svg'
+         xmlns:foo='http://example.com/foo'>
+        <g id='g1' data-id='a &amp; &lt;b&gt; &quot;c&quot;' foo:bar='y'>
+            <rect width='10' height='10'/>
+        </g>
+        <rect id='rect1' width='10' height='10' data-id='z'/>
+    </svg>
+    ";
+
+    let expected = vec![
+        usvg::UnknownAttribute {
+            namespace: String::new(),
+            prefix: String::new(),
+            name: "data-id".to_string(),
+            value: "a & <b> \"c\"".to_string(),
+        },
+        usvg::UnknownAttribute {
+            namespace: "http://example.com/foo".to_string(),
+            prefix: "foo".to_string(),
+            name: "bar".to_string(),
+            value: "y".to_string(),
+        },
+    ];
+
+    let opt = usvg::Options {
+        preserve_unknown_attributes: true,
+        ..usvg::Options::default()
+    };
+    let tree = usvg::Tree::from_str(svg, &opt).unwrap();
+    assert_eq!(unknown_attributes(&tree, "g1"), expected);
+    // Shapes with unknown attributes are wrapped into a group.
+    let rect = tree.node_by_id("rect1").unwrap();
+    match *rect.parent().unwrap().borrow() {
+        usvg::NodeKind::Group(ref g) => assert_eq!(g.unknown_attributes[0].value, "z"),
+        _ => panic!("not a group"),
+    }
+
+    let svg = tree.to_string(&usvg::XmlOptions::default());
+    let tree = usvg::Tree::from_str(&svg, &opt).unwrap();
+    assert_eq!(unknown_attributes(&tree, "g1"), expected);
+}
+
+#[test]
+fn unknown_attributes_are_dropped_by_default() {
+    let svg = "
+    <svg viewBox='0 0 100 100' xmlns='http://www.w3.org/2000/svg'
+         xmlns:foo='http://example.com/foo'>
+        <g id='g1' data-id='x' foo:bar='y'>
+            <rect width='10' height='10'/>
+        </g>
+    </svg>
+    ";
+
+    let tree = usvg::Tree::from_str(svg, &usvg::Options::default()).unwrap();
+    assert!(unknown_attributes(&tree, "g1").is_empty());
+    let svg = tree.to_string(&usvg::XmlOptions::default());
+    assert!(!svg.contains("data-id"));
+    assert!(!svg.contains("example.com"));
+}
+
+#[test]
+fn unknown_attributes_prefix_collisions() {
+    let svg = "
+    <svg viewBox='0 0 100 100' xmlns='http://www.w3.org/2000/svg'>
+        <g id='g1'>
+            <rect width='10' height='10'/>
+        </g>
+    </svg>
+    ";
+
+    let attr = |namespace: &str, prefix: &str, name: &str| usvg::UnknownAttribute {
+        namespace: namespace.to_string(),
+        prefix: prefix.to_string(),
+        name: name.to_string(),
+        value: "v".to_string(),
+    };
+
+    let opt = usvg::Options {
+        preserve_unknown_attributes: true,
+        ..usvg::Options::default()
+    };
+    let tree = usvg::Tree::from_str(svg, &opt).unwrap();
+    if let usvg::NodeKind::Group(ref mut g) = *tree.node_by_id("g1").unwrap().borrow_mut() {
+        g.unknown_attributes = vec![
+            attr("http://example.com/a", "", "a"),
+            attr("http://example.com/b", "ns1", "b"),
+            attr("http://example.com/c", "ns1", "c"),
+        ];
+    }
+
+    let svg = tree.to_string(&usvg::XmlOptions::default());
+    let tree = usvg::Tree::from_str(&svg, &opt).unwrap();
+    let namespaces: Vec<_> = unknown_attributes(&tree, "g1")
+        .into_iter()
+        .map(|a| (a.namespace, a.name))
+        .collect();
+    assert_eq!(
+        namespaces,
+        [
+            ("http://example.com/a".to_string(), "a".to_string()),
+            ("http://example.com/b".to_string(), "b".to_string()),
+            ("http://example.com/c".to_string(), "c".to_string()),
+        ]
+    );
+}
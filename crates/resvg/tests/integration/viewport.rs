@@ -0,0 +1,113 @@
+use usvg::TreeParsing;
+
+use crate::alpha;
+
+fn tree(svg: &str) -> resvg::Tree {
+    let tree = usvg::Tree::from_str(svg, &usvg::Options::default()).unwrap();
+    resvg::Tree::from_usvg(&tree)
+}
+
+/// Renders a tree in the center of a twice larger pixmap.
+fn render(tree: &resvg::Tree) -> tiny_skia::Pixmap {
+    let mut pixmap = tiny_skia::Pixmap::new(80, 80).unwrap();
+    let ts = tiny_skia::Transform::from_translate(20.0, 20.0);
+    tree.render(ts, &mut pixmap.as_mut());
+    pixmap
+}
+
+fn svg(overflow: &str) -> String {
+    format!(
+        "
+        <svg width='40' height='40' overflow='{}' xmlns='http://www.w3.org/2000/svg'>
+            <rect x='-10' y='10' width='60' height='20'/>
+        </svg>
+        ",
+        overflow
+    )
+}
+
+#[test]
+fn clipped_by_default() {
+    let tree = tree(&svg("hidden"));
+    assert!(tree.clip_to_viewport);
+
+    let pixmap = render(&tree);
+    assert_eq!(alpha(&pixmap, 25, 40), 255);
+    // Pixels right at the viewport edges.
+    assert_eq!(alpha(&pixmap, 20, 40), 255);
+    assert_eq!(alpha(&pixmap, 59, 40), 255);
+    // Pixels right outside of the viewport edges.
+    assert_eq!(alpha(&pixmap, 19, 40), 0);
+    assert_eq!(alpha(&pixmap, 60, 40), 0);
+    assert_eq!(alpha(&pixmap, 15, 40), 0);
+    assert_eq!(alpha(&pixmap, 65, 40), 0);
+
+    let bbox = tree.content_bbox().unwrap();
+    assert_eq!(
+        bbox,
+        tiny_skia::Rect::from_xywh(0.0, 10.0, 40.0, 20.0).unwrap()
+    );
+}
+
+#[test]
+fn overflow_visible() {
+    for overflow in ["visible", "auto"] {
+        let tree = tree(&svg(overflow));
+        assert!(!tree.clip_to_viewport);
+
+        let pixmap = render(&tree);
+        assert_eq!(alpha(&pixmap, 15, 40), 255);
+        assert_eq!(alpha(&pixmap, 65, 40), 255);
+        assert_eq!(alpha(&pixmap, 5, 40), 0);
+
+        // Can be used to allocate a pixmap for the whole content.
+        let bbox = tree.content_bbox().unwrap();
+        assert_eq!(
+            bbox,
+            tiny_skia::Rect::from_xywh(-10.0, 10.0, 60.0, 20.0).unwrap()
+        );
+    }
+}
+
+#[test]
+fn clipped_with_skew() {
+    let tree = tree(&svg("hidden"));
+    let mut pixmap = tiny_skia::Pixmap::new(80, 80).unwrap();
+    let ts = tiny_skia::Transform::from_row(1.0, 0.0, 0.5, 1.0, 10.0, 20.0);
+    tree.render(ts, &mut pixmap.as_mut());
+
+    // The left edge of the viewport is at `x = 20` in this row.
+    assert_eq!(alpha(&pixmap, 12, 40), 0);
+    assert_eq!(alpha(&pixmap, 32, 40), 255);
+}
+
+#[test]
+fn clipped_partially_outside_of_pixmap() {
+    let tree = tree(&svg("hidden"));
+    let mut pixmap = tiny_skia::Pixmap::new(80, 80).unwrap();
+    tree.render_onto(
+        &mut pixmap.as_mut(),
+        (60, 20),
+        tiny_skia::Transform::default(),
+    );
+
+    assert_eq!(alpha(&pixmap, 59, 40), 0);
+    assert_eq!(alpha(&pixmap, 60, 40), 255);
+    assert_eq!(alpha(&pixmap, 79, 40), 255);
+    assert_eq!(alpha(&pixmap, 70, 29), 0);
+    assert_eq!(alpha(&pixmap, 70, 30), 255);
+}
+
+#[test]
+fn clipped_outside_of_pixmap() {
+    let tree = tree(&svg("hidden"));
+    let mut pixmap = tiny_skia::Pixmap::new(80, 80).unwrap();
+    // The content is still inside the pixmap, but the viewport is not.
+    tree.render_onto(
+        &mut pixmap.as_mut(),
+        (-45, 20),
+        tiny_skia::Transform::default(),
+    );
+
+    assert!(pixmap.pixels().iter().all(|p| p.alpha() == 0));
+}
@@ -0,0 +1,40 @@
+//! Measures parsing of large path data.
+//!
+//! Run with `cargo bench -p usvg-parser --bench path`.
+
+use std::time::Instant;
+
+use usvg_parser::TreeParsing;
+
+fn main() {
+    let mut data = String::from("M 10 10");
+    for i in 0..200_000 {
+        let n = (i % 100) as f32;
+        data.push_str(&format!(
+            " L {} {} c 1.5,2.5 -3.25,4 {},-{} q 2 3 4.5 5 Z m 1 1",
+            n,
+            n * 0.5,
+            n * 0.25,
+            n * 0.125
+        ));
+    }
+
+    let svg = format!(
+        "<svg xmlns='http://www.w3.org/2000/svg' viewBox='0 0 100 100'><path d='{}'/></svg>",
+        data
+    );
+
+    let opt = usvg_parser::Options::default();
+    let iterations = 10;
+    let start = Instant::now();
+    for _ in 0..iterations {
+        let tree = usvg_tree::Tree::from_str(&svg, &opt).unwrap();
+        assert!(tree.root.has_children());
+    }
+
+    println!(
+        "{} KiB of path data: {:.2?} per iteration",
+        data.len() / 1024,
+        start.elapsed() / iterations
+    );
+}
@@ -30,7 +30,7 @@ pub(crate) fn convert(
 
     // Resolve linked clip path.
     let mut clip_path = None;
-    if let Some(link) = node.attribute::<SvgNode>(AId::ClipPath) {
+    if let Some(link) = node.node_attribute(AId::ClipPath) {
         clip_path = convert(link, state, cache);
 
         // Linked `clipPath` must be valid.
@@ -0,0 +1,145 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use usvg_tree::{Fill, Node, NodeKind, Paint, Stroke, Tree};
+
+use crate::svgtree::{AId, SvgNode};
+use crate::ColorTransform;
+
+/// Applies a color transform to all fills, strokes and gradient stops.
+///
+/// Runs on an already converted tree, so `currentColor`, inheritance
+/// and paint servers are already resolved.
+/// Masks and clip paths are not affected, since they do not produce visible colors.
+pub(crate) fn apply(svg: SvgNode, transform: &ColorTransform, tree: &mut Tree) {
+    if !allows_forced_colors(svg) {
+        return;
+    }
+
+    let mut ctx = Context {
+        transform,
+        gradients: HashMap::new(),
+        patterns: Vec::new(),
+    };
+    ctx.process_node(&tree.root);
+}
+
+/// Checks that the document did not opt out of forced colors via `color-scheme: only light`.
+fn allows_forced_colors(svg: SvgNode) -> bool {
+    let value: &str = match svg.attribute(AId::ColorScheme) {
+        Some(v) => v,
+        None => return true,
+    };
+
+    !value.split_ascii_whitespace().any(|s| s == "only")
+}
+
+struct Context<'a> {
+    transform: &'a ColorTransform,
+    /// Already transformed gradients. Preserves gradients sharing.
+    gradients: HashMap<*const (), Paint>,
+    /// Already processed patterns. Pattern content is updated in place.
+    patterns: Vec<*const usvg_tree::Pattern>,
+}
+
+impl Context<'_> {
+    fn process_node(&mut self, parent: &Node) {
+        for node in parent.descendants() {
+            match *node.borrow_mut() {
+                NodeKind::Path(ref mut path) => {
+                    self.process_fill(&mut path.fill);
+                    self.process_stroke(&mut path.stroke);
+                }
+                NodeKind::Text(ref mut text) => {
+                    for span in text.chunks.iter_mut().flat_map(|c| c.spans.iter_mut()) {
+                        self.process_fill(&mut span.fill);
+                        self.process_stroke(&mut span.stroke);
+
+                        let decoration = &mut span.decoration;
+                        for style in decoration
+                            .underline
+                            .iter_mut()
+                            .chain(decoration.overline.iter_mut())
+                            .chain(decoration.line_through.iter_mut())
+                        {
+                            self.process_fill(&mut style.fill);
+                            self.process_stroke(&mut style.stroke);
+                        }
+                    }
+                }
+                NodeKind::Group(_) | NodeKind::Image(_) => {}
+            }
+        }
+    }
+
+    fn process_fill(&mut self, fill: &mut Option<Fill>) {
+        if let Some(ref mut fill) = fill {
+            self.process_paint(&mut fill.paint);
+        }
+    }
+
+    fn process_stroke(&mut self, stroke: &mut Option<Stroke>) {
+        if let Some(ref mut stroke) = stroke {
+            self.process_paint(&mut stroke.paint);
+        }
+    }
+
+    fn process_paint(&mut self, paint: &mut Paint) {
+        let key = match paint {
+            Paint::Color(ref mut color) => {
+                *color = self.transform.apply(*color);
+                return;
+            }
+            Paint::LinearGradient(ref lg) => Rc::as_ptr(lg) as *const (),
+            Paint::RadialGradient(ref rg) => Rc::as_ptr(rg) as *const (),
+            Paint::ConicGradient(ref cg) => Rc::as_ptr(cg) as *const (),
+            Paint::Pattern(ref patt) => {
+                let ptr = Rc::as_ptr(patt);
+                if !self.patterns.contains(&ptr) {
+                    self.patterns.push(ptr);
+                    let root = patt.root.clone();
+                    self.process_node(&root);
+                }
+
+                return;
+            }
+        };
+
+        if let Some(transformed) = self.gradients.get(&key) {
+            *paint = transformed.clone();
+            return;
+        }
+
+        let transformed = match paint {
+            Paint::LinearGradient(ref lg) => {
+                let mut lg = (**lg).clone();
+                self.process_stops(&mut lg.base.stops);
+                Paint::LinearGradient(Rc::new(lg))
+            }
+            Paint::RadialGradient(ref rg) => {
+                let mut rg = (**rg).clone();
+                self.process_stops(&mut rg.base.stops);
+                Paint::RadialGradient(Rc::new(rg))
+            }
+            Paint::ConicGradient(ref cg) => {
+                let mut cg = (**cg).clone();
+                self.process_stops(&mut cg.base.stops);
+                Paint::ConicGradient(Rc::new(cg))
+            }
+            Paint::Color(_) | Paint::Pattern(_) => return,
+        };
+
+        self.gradients.insert(key, transformed.clone());
+        *paint = transformed;
+    }
+
+    fn process_stops(&self, stops: &mut [usvg_tree::Stop]) {
+        for stop in stops {
+            stop.color = self.transform.apply(stop.color);
+        }
+    }
+}
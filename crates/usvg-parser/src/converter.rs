@@ -33,6 +33,8 @@ pub struct Cache {
     pub masks: HashMap<String, Rc<Mask>>,
     pub filters: HashMap<String, Rc<usvg_tree::filter::Filter>>,
     pub paint: HashMap<String, Paint>,
+    /// Used to generate IDs for inline conic gradients.
+    pub conic_gradients: usize,
 }
 
 impl<'a, 'input: 'a> SvgNode<'a, 'input> {
@@ -121,6 +123,63 @@ impl<'a, 'input: 'a> SvgNode<'a, 'input> {
             && self.has_valid_transform(AId::Transform)
             && crate::switch::is_condition_passed(*self, opt)
     }
+
+    /// Resolves a color attribute, like `stop-color` or `flood-color`.
+    ///
+    /// `currentColor` is resolved via [`resolve_current_color`](Self::resolve_current_color).
+    pub(crate) fn resolve_color(
+        &self,
+        aid: AId,
+        context: SvgNode,
+        def: svgtypes::Color,
+    ) -> svgtypes::Color {
+        match self.attribute(aid) {
+            Some("currentColor") => self.resolve_current_color(context),
+            Some(value) => {
+                if let Ok(c) = svgtypes::Color::from_str(value) {
+                    c
+                } else {
+                    log::warn!("Failed to parse {} value: '{}'.", aid, value);
+                    def
+                }
+            }
+            None => def,
+        }
+    }
+
+    /// Resolves the `currentColor` value.
+    ///
+    /// The `color` property is inherited from the current element ancestors first.
+    /// If none of them has it, the `color` of the `context` element is used.
+    /// `context` is an element that references the current one at use time.
+    /// Like a shape referencing a gradient or a filter.
+    pub(crate) fn resolve_current_color(&self, context: SvgNode) -> svgtypes::Color {
+        self.find_attribute(AId::Color)
+            .or_else(|| context.find_attribute(AId::Color))
+            .unwrap_or_else(svgtypes::Color::black)
+    }
+
+    /// Checks that a color attribute is set to `currentColor` and is not resolvable
+    /// without a `context` element.
+    pub(crate) fn depends_on_context_color(&self, aid: AId) -> bool {
+        self.attribute(aid) == Some("currentColor") && !self.has_inherited_color()
+    }
+
+    fn has_inherited_color(&self) -> bool {
+        self.ancestors().any(|n| n.has_attribute(AId::Color))
+    }
+}
+
+/// Creates a cache key for an element that can be resolved differently
+/// depending on the context `color`.
+pub(crate) fn context_color_key(id: &str, color: Option<svgtypes::Color>) -> String {
+    match color {
+        Some(c) => format!(
+            "{}-{:02x}{:02x}{:02x}{:02x}",
+            id, c.red, c.green, c.blue, c.alpha
+        ),
+        None => id.to_string(),
+    }
 }
 
 pub trait SvgColorExt {
@@ -157,6 +216,8 @@ pub(crate) fn convert_doc(svg_doc: &svgtree::Document, opt: &Options) -> Result<
         size,
         view_box,
         root: Node::new(NodeKind::Group(Group::default())),
+        clip_to_viewport: !matches!(svg.attribute(AId::Overflow), Some("visible") | Some("auto")),
+        font_faces: Vec::new(),
     };
 
     if !svg.is_visible_element(opt) {
@@ -173,7 +234,34 @@ pub(crate) fn convert_doc(svg_doc: &svgtree::Document, opt: &Options) -> Result<
     };
 
     let mut cache = Cache::default();
-    convert_children(svg_doc.root(), &state, &mut cache, &mut tree.root);
+    // Just like with nested `svg` and `symbol` elements, `clip` is ignored
+    // when `overflow` is `visible` or `auto`.
+    let root_clip_path = if tree.clip_to_viewport {
+        root_clip_path(&svg, &tree, &state)
+    } else {
+        None
+    };
+
+    let mut parent = match root_clip_path {
+        Some(clip_path) => tree.root.append_kind(NodeKind::Group(Group {
+            clip_path: Some(clip_path),
+            ..Group::default()
+        })),
+        None => tree.root.clone(),
+    };
+    convert_children(svg_doc.root(), &state, &mut cache, &mut parent);
+
+    if let Some(ref transform) = opt.color_transform {
+        crate::color_transform::apply(svg, transform, &mut tree);
+    }
+
+    if let Some(ref snap) = opt.grid_snap {
+        crate::grid_snap::apply(snap, &tree);
+    }
+
+    if opt.normalize_winding {
+        normalize_winding(&tree);
+    }
 
     remove_empty_groups(&mut tree);
 
@@ -184,6 +272,20 @@ pub(crate) fn convert_doc(svg_doc: &svgtree::Document, opt: &Options) -> Result<
     Ok(tree)
 }
 
+/// Converts the deprecated `clip` property on the root `svg` element into a clip path.
+///
+/// `clip` is defined in viewport coordinates, so the resolved rectangle
+/// is mapped into the `viewBox` coordinates.
+fn root_clip_path(svg: &SvgNode, tree: &Tree, state: &State) -> Option<Rc<ClipPath>> {
+    let viewport = tree.size.to_non_zero_rect(0.0, 0.0);
+    let rect = crate::use_node::resolve_clip_rect(*svg, viewport, state)?;
+    let ts = utils::view_box_to_transform(tree.view_box.rect, tree.view_box.aspect, tree.size)
+        .invert()?;
+    let rect = rect.transform(ts)?;
+
+    Some(crate::use_node::rect_clip_path(rect))
+}
+
 fn resolve_svg_size(svg: &SvgNode, opt: &Options) -> (Result<Size, Error>, bool) {
     let mut state = State {
         parent_clip_path: None,
@@ -314,11 +416,12 @@ pub(crate) fn convert_element(
         return None;
     }
 
-    let parent = &mut match convert_group(node, state, false, cache, parent) {
-        GroupKind::Create(g) => g,
-        GroupKind::Skip => parent.clone(),
+    let (mut parent, is_new_group) = match convert_group(node, state, false, cache, parent) {
+        GroupKind::Create(g) => (g, true),
+        GroupKind::Skip => (parent.clone(), false),
         GroupKind::Ignore => return None,
     };
+    let parent = &mut parent;
 
     match tag_name {
         EId::Rect
@@ -352,6 +455,11 @@ pub(crate) fn convert_element(
         _ => {}
     }
 
+    // Nested `svg` elements handle their transform separately.
+    if is_new_group && tag_name != EId::Svg {
+        apply_transform_box(node, parent, state);
+    }
+
     Some(parent.clone())
 }
 
@@ -520,6 +628,7 @@ pub(crate) fn convert_group(
 
     // TODO: ignore just transform
     let is_g_or_use = matches!(node.tag_name(), Some(EId::G) | Some(EId::Use));
+    let unknown_attributes = node.unknown_attributes();
     let required = opacity.get().approx_ne_ulps(&1.0, 4)
         || clip_path.is_some()
         || mask.is_some()
@@ -528,6 +637,7 @@ pub(crate) fn convert_group(
         || blend_mode != BlendMode::Normal
         || isolate
         || is_g_or_use
+        || !unknown_attributes.is_empty()
         || force;
 
     if required {
@@ -538,6 +648,15 @@ pub(crate) fn convert_group(
             String::new()
         };
 
+        let use_source = if state.opt.preserve_use_sources && node.tag_name() == Some(EId::Use) {
+            node.node_attribute(AId::Href).map(|link| UseSource {
+                use_id: node.element_id().to_string(),
+                def_id: link.element_id().to_string(),
+            })
+        } else {
+            None
+        };
+
         let g = parent.append_kind(NodeKind::Group(Group {
             id,
             transform,
@@ -547,6 +666,8 @@ pub(crate) fn convert_group(
             clip_path,
             mask,
             filters,
+            use_source,
+            unknown_attributes: unknown_attributes.to_vec(),
         }));
 
         GroupKind::Create(g)
@@ -555,6 +676,23 @@ pub(crate) fn convert_group(
     }
 }
 
+/// Winds outer contours counter-clockwise on the canvas.
+fn normalize_winding(tree: &Tree) {
+    for node in tree.root.descendants() {
+        // A mirroring transform flips the orientation.
+        let ts = node.abs_transform();
+        let outer = if ts.sx * ts.sy - ts.kx * ts.ky < 0.0 {
+            Orientation::Clockwise
+        } else {
+            Orientation::CounterClockwise
+        };
+
+        if let NodeKind::Path(ref mut path) = *node.borrow_mut() {
+            path.normalize_winding(outer);
+        }
+    }
+}
+
 fn remove_empty_groups(tree: &mut Tree) {
     fn rm(parent: Node) -> bool {
         let mut changed = false;
@@ -738,3 +876,133 @@ impl SvgNode<'_, '_> {
         transform
     }
 }
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum TransformBox {
+    Fill,
+    Stroke,
+    View,
+}
+
+impl<'a, 'input: 'a> FromValue<'a, 'input> for TransformBox {
+    fn parse(_: SvgNode, _: AId, value: &str) -> Option<Self> {
+        match value {
+            // SVG elements do not have CSS layout boxes,
+            // so `content-box` and `border-box` are mapped to the SVG ones.
+            "fill-box" | "content-box" => Some(TransformBox::Fill),
+            "stroke-box" | "border-box" => Some(TransformBox::Stroke),
+            "view-box" => Some(TransformBox::View),
+            _ => None,
+        }
+    }
+}
+
+/// Resolves `transform-origin` against the element's bounding box
+/// when `transform-box` is set to `fill-box` or `stroke-box`.
+///
+/// Must be called after the element content was converted, since the bounding box
+/// is calculated using the converted content.
+/// When the bounding box cannot be calculated, like in case of text,
+/// the `view-box` origin set by `resolve_transform` is preserved.
+fn apply_transform_box(node: SvgNode, g: &Node, state: &State) {
+    // Unlike CSS, SVG elements default to `view-box`.
+    let transform_box = node
+        .attribute(AId::TransformBox)
+        .unwrap_or(TransformBox::View);
+    if transform_box == TransformBox::View {
+        return;
+    }
+
+    let transform_origin: TransformOrigin = match node.attribute(AId::TransformOrigin) {
+        Some(v) => v,
+        None => return,
+    };
+
+    let with_stroke = transform_box == TransformBox::Stroke;
+    let mut bbox = BBox::default();
+    for child in g.children() {
+        if let Some(c_bbox) = calc_content_bbox(&child, child.transform(), with_stroke) {
+            bbox = bbox.expand(c_bbox);
+        }
+    }
+
+    let bbox = match bbox.to_rect() {
+        Some(v) => v,
+        None => return,
+    };
+
+    let resolve = |length: Length, aid: AId, size: f32| {
+        if length.unit == Unit::Percent {
+            size * length.number as f32 / 100.0
+        } else {
+            convert_length(length, node, aid, Units::UserSpaceOnUse, state)
+        }
+    };
+
+    let dx = bbox.x() + resolve(transform_origin.x_offset, AId::Width, bbox.width());
+    let dy = bbox.y() + resolve(transform_origin.y_offset, AId::Height, bbox.height());
+
+    if let NodeKind::Group(ref mut g) = *g.borrow_mut() {
+        let transform: Transform = node.attribute(AId::Transform).unwrap_or_default();
+        g.transform = Transform::default()
+            .pre_translate(dx, dy)
+            .pre_concat(transform)
+            .pre_translate(-dx, -dy);
+    }
+}
+
+fn calc_content_bbox(node: &Node, ts: Transform, with_stroke: bool) -> Option<BBox> {
+    match *node.borrow() {
+        NodeKind::Path(ref path) => {
+            let stroked_path = match path.stroke {
+                Some(ref stroke) if with_stroke => stroke_path(&path.data, stroke),
+                _ => None,
+            };
+
+            let bounds = stroked_path
+                .as_ref()
+                .map(|p| p.bounds())
+                .unwrap_or_else(|| path.data.bounds());
+            bounds.transform(ts).map(BBox::from)
+        }
+        NodeKind::Image(ref img) => img.view_box.rect.transform(ts).map(BBox::from),
+        NodeKind::Group(_) => {
+            let mut bbox = BBox::default();
+            for child in node.children() {
+                let child_ts = ts.pre_concat(child.transform());
+                if let Some(c_bbox) = calc_content_bbox(&child, child_ts, with_stroke) {
+                    bbox = bbox.expand(c_bbox);
+                }
+            }
+
+            if bbox.is_default() {
+                return None;
+            }
+
+            Some(bbox)
+        }
+        // Text is not laid out yet.
+        NodeKind::Text(_) => None,
+    }
+}
+
+fn stroke_path(path: &tiny_skia_path::Path, stroke: &Stroke) -> Option<tiny_skia_path::Path> {
+    let stroke = tiny_skia_path::Stroke {
+        width: stroke.width.get(),
+        miter_limit: stroke.miterlimit.get(),
+        line_cap: match stroke.linecap {
+            LineCap::Butt => tiny_skia_path::LineCap::Butt,
+            LineCap::Round => tiny_skia_path::LineCap::Round,
+            LineCap::Square => tiny_skia_path::LineCap::Square,
+        },
+        line_join: match stroke.linejoin {
+            LineJoin::Miter => tiny_skia_path::LineJoin::Miter,
+            LineJoin::MiterClip => tiny_skia_path::LineJoin::MiterClip,
+            LineJoin::Round => tiny_skia_path::LineJoin::Round,
+            LineJoin::Bevel => tiny_skia_path::LineJoin::Bevel,
+        },
+        dash: None,
+    };
+
+    path.stroke(&stroke, 1.0)
+}
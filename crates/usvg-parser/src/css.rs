@@ -0,0 +1,102 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! A minimal CSS tokenizer for preprocessing text before `simplecss`.
+
+use std::ops::Range;
+
+/// A CSS token.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub(crate) enum Token {
+    /// A quoted string, including quotes. Can be unterminated.
+    String,
+    /// A comment, including delimiters. Can be unterminated.
+    Comment,
+    /// A backslash followed by any character.
+    Escape,
+    /// Any other character.
+    Char(char),
+}
+
+/// Splits CSS into tokens, so characters inside strings and comments
+/// are not confused with the syntax.
+///
+/// Yields each token together with its byte range.
+pub(crate) struct Tokenizer<'a> {
+    text: &'a str,
+    pos: usize,
+}
+
+impl<'a> Tokenizer<'a> {
+    pub(crate) fn new(text: &'a str) -> Self {
+        Tokenizer { text, pos: 0 }
+    }
+
+    fn char_end(&self, pos: usize) -> usize {
+        match self.text[pos..].chars().next() {
+            Some(c) => pos + c.len_utf8(),
+            None => pos,
+        }
+    }
+}
+
+impl Iterator for Tokenizer<'_> {
+    type Item = (Range<usize>, Token);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let start = self.pos;
+        let tail = &self.text[start..];
+        let c = tail.chars().next()?;
+
+        let (end, token) = if let Some(comment) = tail.strip_prefix("/*") {
+            let end = match comment.find("*/") {
+                Some(end) => start + 2 + end + 2,
+                None => self.text.len(),
+            };
+
+            (end, Token::Comment)
+        } else if c == '\'' || c == '"' {
+            let mut end = self.text.len();
+            let mut chars = tail.char_indices().skip(1);
+            while let Some((i, c2)) = chars.next() {
+                if c2 == '\\' {
+                    chars.next();
+                } else if c2 == c {
+                    end = start + i + 1;
+                    break;
+                }
+            }
+
+            (end, Token::String)
+        } else if c == '\\' {
+            (self.char_end(start + 1), Token::Escape)
+        } else {
+            (start + c.len_utf8(), Token::Char(c))
+        };
+
+        self.pos = end;
+        Some((start..end, token))
+    }
+}
+
+/// Splits text by a separator, ignoring separators inside functions, strings and comments.
+pub(crate) fn split(text: &str, separator: char) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0;
+    let mut start = 0;
+    for (range, token) in Tokenizer::new(text) {
+        match token {
+            Token::Char('(') => depth += 1,
+            Token::Char(')') => depth -= 1,
+            Token::Char(c) if c == separator && depth == 0 => {
+                parts.push(&text[start..range.start]);
+                start = range.end;
+            }
+            _ => {}
+        }
+    }
+    parts.push(&text[start..]);
+
+    parts
+}
@@ -0,0 +1,115 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use std::borrow::Cow;
+
+use crate::Error;
+
+/// A text encoding of an SVG data.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Encoding {
+    /// UTF-8. The default.
+    Utf8,
+    /// UTF-16, little-endian.
+    Utf16Le,
+    /// UTF-16, big-endian.
+    Utf16Be,
+    /// ISO-8859-1, aka Latin-1.
+    Latin1,
+}
+
+impl Encoding {
+    /// Detects an encoding of an SVG data.
+    ///
+    /// Checks for a byte order mark first and then for the `encoding`
+    /// in the XML declaration. Falls back to UTF-8.
+    pub fn detect(data: &[u8]) -> Self {
+        if data.starts_with(&[0xEF, 0xBB, 0xBF]) {
+            return Encoding::Utf8;
+        } else if data.starts_with(&[0xFF, 0xFE]) || data.starts_with(b"<\0?\0") {
+            return Encoding::Utf16Le;
+        } else if data.starts_with(&[0xFE, 0xFF]) || data.starts_with(b"\0<\0?") {
+            return Encoding::Utf16Be;
+        }
+
+        match parse_declared_encoding(data) {
+            Some(name) => Self::from_name(name).unwrap_or_else(|| {
+                log::warn!("Unsupported encoding: '{}'. Fallback to UTF-8.", name);
+                Encoding::Utf8
+            }),
+            None => Encoding::Utf8,
+        }
+    }
+
+    /// Returns an encoding by its name, like `ISO-8859-1`.
+    ///
+    /// Names are case-insensitive.
+    pub fn from_name(name: &str) -> Option<Self> {
+        let name = name.to_ascii_lowercase();
+        match name.as_str() {
+            "utf-8" | "utf8" | "us-ascii" | "ascii" => Some(Encoding::Utf8),
+            // Without a BOM, UTF-16 is big-endian.
+            "utf-16" | "utf-16be" => Some(Encoding::Utf16Be),
+            "utf-16le" => Some(Encoding::Utf16Le),
+            "iso-8859-1" | "iso8859-1" | "iso_8859-1" | "latin1" | "latin-1" | "l1" => {
+                Some(Encoding::Latin1)
+            }
+            _ => None,
+        }
+    }
+
+    /// Decodes data into a UTF-8 string.
+    ///
+    /// A byte order mark is removed.
+    pub fn decode(self, data: &[u8]) -> Result<Cow<'_, str>, Error> {
+        match self {
+            Encoding::Utf8 => {
+                let data = data.strip_prefix(&[0xEF, 0xBB, 0xBF]).unwrap_or(data);
+                let text = std::str::from_utf8(data).map_err(|_| Error::NotAnUtf8Str)?;
+                Ok(Cow::Borrowed(text))
+            }
+            Encoding::Utf16Le => decode_utf16(data, u16::from_le_bytes).map(Cow::Owned),
+            Encoding::Utf16Be => decode_utf16(data, u16::from_be_bytes).map(Cow::Owned),
+            Encoding::Latin1 => {
+                // Latin-1 code points are the same as the first 256 Unicode code points.
+                Ok(Cow::Owned(data.iter().map(|b| *b as char).collect()))
+            }
+        }
+    }
+}
+
+fn decode_utf16(data: &[u8], f: fn([u8; 2]) -> u16) -> Result<String, Error> {
+    let chunks = data.chunks_exact(2);
+    if !chunks.remainder().is_empty() {
+        return Err(Error::NotAnUtf8Str);
+    }
+
+    let units = chunks.map(|c| f([c[0], c[1]]));
+    let mut text: String = std::char::decode_utf16(units)
+        .collect::<Result<_, _>>()
+        .map_err(|_| Error::NotAnUtf8Str)?;
+
+    if text.starts_with('\u{FEFF}') {
+        text.remove(0);
+    }
+
+    Ok(text)
+}
+
+/// Returns an `encoding` value from the XML declaration.
+fn parse_declared_encoding(data: &[u8]) -> Option<&str> {
+    if !data.starts_with(b"<?xml") {
+        return None;
+    }
+
+    // The declaration is always ASCII.
+    let end = data.windows(2).take(1024).position(|w| w == b"?>")?;
+    let decl = std::str::from_utf8(&data[..end]).ok()?;
+
+    let value = decl.split("encoding").nth(1)?.trim_start();
+    let value = value.strip_prefix('=')?.trim_start();
+    let quote = value.chars().next().filter(|c| *c == '"' || *c == '\'')?;
+    let value = &value[1..];
+    value.split(quote).next()
+}
@@ -6,7 +6,6 @@
 
 use std::collections::HashSet;
 use std::rc::Rc;
-use std::str::FromStr;
 
 use strict_num::PositiveF32;
 use svgtypes::{Length, LengthUnit as Unit};
@@ -123,7 +122,7 @@ pub(crate) fn convert(
             }
             svgtypes::FilterValue::Url(url) => {
                 if let Some(link) = node.document().element_by_id(url) {
-                    if let Ok(res) = convert_url(link, state, cache) {
+                    if let Ok(res) = convert_url(link, node, state, cache) {
                         if let Some(f) = res {
                             filters.push(f);
                         }
@@ -150,10 +149,19 @@ pub(crate) fn convert(
 
 fn convert_url(
     node: SvgNode,
+    context: SvgNode,
     state: &converter::State,
     cache: &mut converter::Cache,
 ) -> Result<Option<Rc<Filter>>, ()> {
-    if let Some(filter) = cache.filters.get(node.element_id()) {
+    // Filters with a `currentColor` primitive must be resolved for each context color separately.
+    let context_color = if uses_context_color(node) {
+        Some(node.resolve_current_color(context))
+    } else {
+        None
+    };
+    let id = converter::context_color_key(node.element_id(), context_color);
+
+    if let Some(filter) = cache.filters.get(&id) {
         return Ok(Some(filter.clone()));
     }
 
@@ -203,26 +211,40 @@ fn convert_url(
         Some(v) => v,
         None => return Err(()),
     };
-    let primitives = collect_children(&node_with_primitives, primitive_units, state, cache);
+    let primitives = collect_children(
+        &node_with_primitives,
+        context,
+        primitive_units,
+        state,
+        cache,
+    );
     if primitives.is_empty() {
         return Err(());
     }
 
     let filter = Rc::new(Filter {
-        id: node.element_id().to_string(),
+        id: id.clone(),
         units,
         primitive_units,
         rect,
         primitives,
     });
 
-    cache
-        .filters
-        .insert(node.element_id().to_string(), filter.clone());
+    cache.filters.insert(id, filter.clone());
 
     Ok(Some(filter))
 }
 
+fn uses_context_color(node: SvgNode) -> bool {
+    match find_filter_with_primitives(node) {
+        Some(filter) => filter.children().any(|fe| {
+            fe.depends_on_context_color(AId::FloodColor)
+                || fe.depends_on_context_color(AId::LightingColor)
+        }),
+        None => false,
+    }
+}
+
 fn find_filter_with_primitives<'a>(node: SvgNode<'a, 'a>) -> Option<SvgNode<'a, 'a>> {
     for link in node.href_iter() {
         if link.tag_name() != Some(EId::Filter) {
@@ -249,6 +271,7 @@ struct FilterResults {
 
 fn collect_children(
     filter: &SvgNode,
+    context: SvgNode,
     units: Units,
     state: &converter::State,
     cache: &mut converter::Cache,
@@ -266,33 +289,33 @@ fn collect_children(
             None => continue,
         };
 
-        let kind =
-            match tag_name {
-                EId::FeDropShadow => convert_drop_shadow(child, &primitives),
-                EId::FeGaussianBlur => convert_gaussian_blur(child, &primitives),
-                EId::FeOffset => convert_offset(child, &primitives),
-                EId::FeBlend => convert_blend(child, &primitives),
-                EId::FeFlood => convert_flood(child),
-                EId::FeComposite => convert_composite(child, &primitives),
-                EId::FeMerge => convert_merge(child, &primitives),
-                EId::FeTile => convert_tile(child, &primitives),
-                EId::FeImage => convert_image(child, state, cache),
-                EId::FeComponentTransfer => convert_component_transfer(child, &primitives),
-                EId::FeColorMatrix => convert_color_matrix(child, &primitives),
-                EId::FeConvolveMatrix => convert_convolve_matrix(child, &primitives)
-                    .unwrap_or_else(create_dummy_primitive),
-                EId::FeMorphology => convert_morphology(child, &primitives),
-                EId::FeDisplacementMap => convert_displacement_map(child, &primitives),
-                EId::FeTurbulence => convert_turbulence(child),
-                EId::FeDiffuseLighting => convert_diffuse_lighting(child, &primitives)
-                    .unwrap_or_else(create_dummy_primitive),
-                EId::FeSpecularLighting => convert_specular_lighting(child, &primitives)
-                    .unwrap_or_else(create_dummy_primitive),
-                tag_name => {
-                    log::warn!("'{}' is not a valid filter primitive. Skipped.", tag_name);
-                    continue;
-                }
-            };
+        let kind = match tag_name {
+            EId::FeDropShadow => convert_drop_shadow(child, context, &primitives),
+            EId::FeGaussianBlur => convert_gaussian_blur(child, &primitives),
+            EId::FeOffset => convert_offset(child, &primitives),
+            EId::FeBlend => convert_blend(child, &primitives),
+            EId::FeFlood => convert_flood(child, context),
+            EId::FeComposite => convert_composite(child, &primitives),
+            EId::FeMerge => convert_merge(child, &primitives),
+            EId::FeTile => convert_tile(child, &primitives),
+            EId::FeImage => convert_image(child, state, cache),
+            EId::FeComponentTransfer => convert_component_transfer(child, &primitives),
+            EId::FeColorMatrix => convert_color_matrix(child, &primitives),
+            EId::FeConvolveMatrix => {
+                convert_convolve_matrix(child, &primitives).unwrap_or_else(create_dummy_primitive)
+            }
+            EId::FeMorphology => convert_morphology(child, &primitives),
+            EId::FeDisplacementMap => convert_displacement_map(child, &primitives),
+            EId::FeTurbulence => convert_turbulence(child),
+            EId::FeDiffuseLighting => convert_diffuse_lighting(child, context, &primitives)
+                .unwrap_or_else(create_dummy_primitive),
+            EId::FeSpecularLighting => convert_specular_lighting(child, context, &primitives)
+                .unwrap_or_else(create_dummy_primitive),
+            tag_name => {
+                log::warn!("'{}' is not a valid filter primitive. Skipped.", tag_name);
+                continue;
+            }
+        };
 
         let fe = convert_primitive(child, kind, units, state, &mut results);
         primitives.push(fe);
@@ -617,12 +640,11 @@ fn convert_displacement_map(fe: SvgNode, primitives: &[Primitive]) -> Kind {
     })
 }
 
-fn convert_drop_shadow(fe: SvgNode, primitives: &[Primitive]) -> Kind {
+fn convert_drop_shadow(fe: SvgNode, context: SvgNode, primitives: &[Primitive]) -> Kind {
     let (std_dev_x, std_dev_y) = convert_std_dev_attr(fe, "2 2");
 
     let (color, opacity) = fe
-        .attribute(AId::FloodColor)
-        .unwrap_or_else(svgtypes::Color::black)
+        .resolve_color(AId::FloodColor, context, svgtypes::Color::black())
         .split_alpha();
 
     let flood_opacity = fe
@@ -640,10 +662,9 @@ fn convert_drop_shadow(fe: SvgNode, primitives: &[Primitive]) -> Kind {
     })
 }
 
-fn convert_flood(fe: SvgNode) -> Kind {
+fn convert_flood(fe: SvgNode, context: SvgNode) -> Kind {
     let (color, opacity) = fe
-        .attribute(AId::FloodColor)
-        .unwrap_or_else(svgtypes::Color::black)
+        .resolve_color(AId::FloodColor, context, svgtypes::Color::black())
         .split_alpha();
 
     let flood_opacity = fe
@@ -747,18 +768,26 @@ fn convert_image(fe: SvgNode, state: &converter::State, cache: &mut converter::C
     })
 }
 
-fn convert_diffuse_lighting(fe: SvgNode, primitives: &[Primitive]) -> Option<Kind> {
+fn convert_diffuse_lighting(
+    fe: SvgNode,
+    context: SvgNode,
+    primitives: &[Primitive],
+) -> Option<Kind> {
     let light_source = convert_light_source(fe)?;
     Some(Kind::DiffuseLighting(DiffuseLighting {
         input: resolve_input(fe, AId::In, primitives),
         surface_scale: fe.attribute(AId::SurfaceScale).unwrap_or(1.0),
         diffuse_constant: fe.attribute(AId::DiffuseConstant).unwrap_or(1.0),
-        lighting_color: convert_lighting_color(fe),
+        lighting_color: convert_lighting_color(fe, context),
         light_source,
     }))
 }
 
-fn convert_specular_lighting(fe: SvgNode, primitives: &[Primitive]) -> Option<Kind> {
+fn convert_specular_lighting(
+    fe: SvgNode,
+    context: SvgNode,
+    primitives: &[Primitive],
+) -> Option<Kind> {
     let light_source = convert_light_source(fe)?;
 
     let specular_exponent = fe.attribute(AId::SpecularExponent).unwrap_or(1.0);
@@ -774,32 +803,19 @@ fn convert_specular_lighting(fe: SvgNode, primitives: &[Primitive]) -> Option<Ki
         surface_scale: fe.attribute(AId::SurfaceScale).unwrap_or(1.0),
         specular_constant: fe.attribute(AId::SpecularConstant).unwrap_or(1.0),
         specular_exponent,
-        lighting_color: convert_lighting_color(fe),
+        lighting_color: convert_lighting_color(fe, context),
         light_source,
     }))
 }
 
 #[inline(never)]
-fn convert_lighting_color(node: SvgNode) -> Color {
+fn convert_lighting_color(node: SvgNode, context: SvgNode) -> Color {
     // Color's alpha doesn't affect lighting-color. Simply skip it.
-    match node.attribute(AId::LightingColor) {
-        Some("currentColor") => {
-            node.find_attribute(AId::Color)
-                // Yes, a missing `currentColor` resolves to black and not white.
-                .unwrap_or(svgtypes::Color::black())
-                .split_alpha()
-                .0
-        }
-        Some(value) => {
-            if let Ok(c) = svgtypes::Color::from_str(value) {
-                c.split_alpha().0
-            } else {
-                log::warn!("Failed to parse lighting-color value: '{}'.", value);
-                Color::white()
-            }
-        }
-        _ => Color::white(),
-    }
+    // A missing `lighting-color` is white, but an unresolvable `currentColor`
+    // falls back to black, like any other `currentColor`.
+    node.resolve_color(AId::LightingColor, context, svgtypes::Color::white())
+        .split_alpha()
+        .0
 }
 
 #[inline(never)]
@@ -0,0 +1,275 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use std::sync::Arc;
+
+use usvg_tree::{FontFace, FontStyle};
+
+use crate::css::{self, Token};
+
+/// Collects fonts embedded via CSS `@font-face` rules.
+///
+/// Only `data:` URLs are supported. `local()` sources and external files are skipped,
+/// so the next source in the list will be used.
+pub(crate) fn collect(xml: &roxmltree::Document) -> Vec<FontFace> {
+    let mut faces = Vec::new();
+    for node in xml.descendants().filter(|n| n.has_tag_name("style")) {
+        match node.attribute("type") {
+            Some("text/css") => {}
+            Some(_) => continue,
+            None => {}
+        }
+
+        let text = match node.text() {
+            Some(v) => v,
+            None => continue,
+        };
+
+        if !text.contains("@font-face") {
+            continue;
+        }
+
+        let text = replace_font_face_rules(text);
+        let sheet = simplecss::StyleSheet::parse(&text);
+        for rule in &sheet.rules {
+            if rule.selector.to_string() == FONT_FACE_SELECTOR {
+                if let Some(face) = parse_font_face(&rule.declarations) {
+                    faces.push(face);
+                }
+            }
+        }
+    }
+
+    faces
+}
+
+/// `simplecss` skips at-rules, so `@font-face` is replaced with a type selector
+/// that cannot match any element.
+const FONT_FACE_SELECTOR: &str = "-usvg-font-face";
+
+/// Replaces `@font-face` rules with `FONT_FACE_SELECTOR` rules.
+///
+/// Strings, comments and nested blocks are left as is.
+fn replace_font_face_rules(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut depth = 0;
+    let mut start = 0;
+    for (range, token) in css::Tokenizer::new(text) {
+        match token {
+            Token::Char('{') => depth += 1,
+            Token::Char('}') => depth -= 1,
+            Token::Char('@') if depth == 0 && text[range.start..].starts_with("@font-face") => {
+                result.push_str(&text[start..range.start]);
+                result.push_str(FONT_FACE_SELECTOR);
+                start = range.start + "@font-face".len();
+            }
+            _ => {}
+        }
+    }
+    result.push_str(&text[start..]);
+
+    result
+}
+
+fn parse_font_face(declarations: &[simplecss::Declaration]) -> Option<FontFace> {
+    let mut family = None;
+    let mut src = None;
+    let mut style = None;
+    let mut weight = None;
+    for declaration in declarations {
+        match declaration.name {
+            "font-family" => {
+                family = Some(declaration.value.trim_matches(|c| c == '\'' || c == '"'));
+            }
+            "src" => src = Some(declaration.value),
+            "font-style" => {
+                style = match declaration.value {
+                    "normal" => Some(FontStyle::Normal),
+                    "italic" => Some(FontStyle::Italic),
+                    "oblique" => Some(FontStyle::Oblique),
+                    _ => None,
+                };
+            }
+            "font-weight" => {
+                weight = match declaration.value {
+                    "normal" => Some(400),
+                    "bold" => Some(700),
+                    value => value.parse::<u16>().ok(),
+                };
+            }
+            _ => {}
+        }
+    }
+
+    let family = family?;
+    if family.is_empty() {
+        return None;
+    }
+
+    let data = match src.and_then(load_src) {
+        Some(v) => v,
+        None => {
+            log::warn!("@font-face '{}' has no supported sources. Skipped.", family);
+            return None;
+        }
+    };
+
+    Some(FontFace {
+        family: family.to_string(),
+        style,
+        weight,
+        data: Arc::new(data),
+    })
+}
+
+/// Loads the first supported source from a `src` descriptor.
+fn load_src(value: &str) -> Option<Vec<u8>> {
+    let mut s = value;
+    loop {
+        s = s.trim_start_matches(|c: char| c == ',' || c.is_ascii_whitespace());
+        if s.is_empty() {
+            return None;
+        }
+
+        let end = s.find(')').map(|i| i + 1).unwrap_or(s.len());
+        let (func, rest) = s.split_at(end);
+        s = rest;
+
+        if let Some(url) = func.strip_prefix("url(") {
+            let url = url
+                .trim_end_matches(')')
+                .trim()
+                .trim_matches(|c| c == '\'' || c == '"');
+
+            if let Some(data) = load_url(url) {
+                return Some(data);
+            }
+        }
+
+        // Skip an optional `format()` and `tech()` hints.
+        s = s.trim_start();
+        while s.starts_with("format(") || s.starts_with("tech(") {
+            let end = s.find(')').map(|i| i + 1).unwrap_or(s.len());
+            s = s[end..].trim_start();
+        }
+    }
+}
+
+fn load_url(url: &str) -> Option<Vec<u8>> {
+    let url = match data_url::DataUrl::process(url) {
+        Ok(v) => v,
+        Err(_) => {
+            log::warn!("Only data URLs are supported in @font-face.");
+            return None;
+        }
+    };
+
+    let (data, _) = url.decode_to_vec().ok()?;
+    match data.get(0..4)? {
+        b"wOFF" => decode_woff(&data),
+        b"wOF2" => {
+            log::warn!("WOFF2 fonts are not supported.");
+            None
+        }
+        [0x00, 0x01, 0x00, 0x00] | b"OTTO" | b"true" | b"ttcf" => Some(data),
+        _ => {
+            log::warn!("Unknown font format in @font-face.");
+            None
+        }
+    }
+}
+
+/// Converts a WOFF font into a TrueType/OpenType one.
+///
+/// https://www.w3.org/TR/WOFF/
+fn decode_woff(data: &[u8]) -> Option<Vec<u8>> {
+    use std::io::Read;
+
+    fn read_u16(data: &[u8], offset: usize) -> Option<u16> {
+        let b = data.get(offset..offset + 2)?;
+        Some(u16::from_be_bytes([b[0], b[1]]))
+    }
+
+    fn read_u32(data: &[u8], offset: usize) -> Option<u32> {
+        let b = data.get(offset..offset + 4)?;
+        Some(u32::from_be_bytes([b[0], b[1], b[2], b[3]]))
+    }
+
+    const HEADER_SIZE: usize = 44;
+    const WOFF_ENTRY_SIZE: usize = 20;
+    const SFNT_ENTRY_SIZE: usize = 16;
+    const MAX_COMPRESSION_RATIO: usize = 1032;
+    const MAX_FONT_SIZE: usize = 64 * 1024 * 1024;
+
+    let flavor = read_u32(data, 4)?;
+    let num_tables = read_u16(data, 12)?;
+
+    let mut entry_selector = 0u32;
+    while 1u32 << (entry_selector + 1) <= num_tables as u32 {
+        entry_selector += 1;
+    }
+    let search_range = (1u32 << entry_selector) * 16;
+    let range_shift = (num_tables as u32 * 16).saturating_sub(search_range);
+
+    let mut font = Vec::new();
+    font.extend_from_slice(&flavor.to_be_bytes());
+    font.extend_from_slice(&num_tables.to_be_bytes());
+    font.extend_from_slice(&(search_range as u16).to_be_bytes());
+    font.extend_from_slice(&(entry_selector as u16).to_be_bytes());
+    font.extend_from_slice(&(range_shift as u16).to_be_bytes());
+
+    let mut tables = Vec::with_capacity(num_tables as usize);
+    let mut offset = 12 + SFNT_ENTRY_SIZE * num_tables as usize;
+    let mut total_length = 0usize;
+    for i in 0..num_tables as usize {
+        let entry = HEADER_SIZE + WOFF_ENTRY_SIZE * i;
+        let tag = data.get(entry..entry + 4)?;
+        let table_offset = read_u32(data, entry + 4)? as usize;
+        let comp_length = read_u32(data, entry + 8)? as usize;
+        let orig_length = read_u32(data, entry + 12)? as usize;
+        let checksum = read_u32(data, entry + 16)?;
+
+        // Do not trust lengths from the file. zlib cannot compress better than ~1:1032.
+        total_length = total_length.checked_add(orig_length)?;
+        if comp_length > orig_length
+            || orig_length > comp_length.saturating_mul(MAX_COMPRESSION_RATIO)
+            || total_length > MAX_FONT_SIZE
+        {
+            log::warn!("Invalid WOFF table lengths.");
+            return None;
+        }
+
+        let table = data.get(table_offset..table_offset.checked_add(comp_length)?)?;
+        let table = if comp_length < orig_length {
+            let mut decoded = Vec::with_capacity(orig_length);
+            flate2::read::ZlibDecoder::new(table)
+                .take(orig_length as u64)
+                .read_to_end(&mut decoded)
+                .ok()?;
+            if decoded.len() != orig_length {
+                return None;
+            }
+
+            decoded
+        } else {
+            table.to_vec()
+        };
+
+        font.extend_from_slice(tag);
+        font.extend_from_slice(&checksum.to_be_bytes());
+        font.extend_from_slice(&(offset as u32).to_be_bytes());
+        font.extend_from_slice(&(orig_length as u32).to_be_bytes());
+
+        // Tables are 4-byte aligned.
+        offset += (orig_length + 3) & !3;
+        tables.push(table);
+    }
+
+    for mut table in tables {
+        table.resize((table.len() + 3) & !3, 0);
+        font.extend_from_slice(&table);
+    }
+
+    Some(font)
+}
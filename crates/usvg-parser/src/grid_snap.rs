@@ -0,0 +1,147 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use std::rc::Rc;
+
+use usvg_tree::tiny_skia_path::{self, PathBuilder, PathSegment, Point, Rect};
+use usvg_tree::{utils, ApproxZeroUlps, NodeExt, NodeKind, StrokeWidth, Transform, Tree};
+
+use crate::GridSnap;
+
+/// Snaps path coordinates and, optionally, stroke widths to a grid in canvas pixels.
+///
+/// Runs on an already converted tree.
+pub(crate) fn apply(snap: &GridSnap, tree: &Tree) {
+    if !snap.step.is_finite() || snap.step <= 0.0 {
+        return;
+    }
+
+    let canvas_ts =
+        utils::view_box_to_transform(tree.view_box.rect, tree.view_box.aspect, tree.size);
+
+    for node in tree.root.descendants() {
+        let ts = canvas_ts.pre_concat(node.abs_transform());
+        if let NodeKind::Path(ref mut path) = *node.borrow_mut() {
+            if let Some(data) = snap_path(&path.data, ts, snap) {
+                path.data = Rc::new(data);
+            }
+
+            if snap.stroke_width {
+                if let Some(ref mut stroke) = path.stroke {
+                    if let Some(width) = snap_stroke_width(stroke.width, ts, snap.step) {
+                        stroke.width = width;
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn snap_path(
+    path: &tiny_skia_path::Path,
+    ts: Transform,
+    snap: &GridSnap,
+) -> Option<tiny_skia_path::Path> {
+    let inv_ts = ts.invert()?;
+
+    // Each subpath is processed separately, so small ones can be left as is.
+    let mut builder = PathBuilder::new();
+    let mut subpath = Vec::new();
+    for segment in path.segments() {
+        if let PathSegment::MoveTo(_) = segment {
+            snap_subpath(&subpath, ts, inv_ts, snap, &mut builder);
+            subpath.clear();
+        }
+
+        subpath.push(segment);
+    }
+    snap_subpath(&subpath, ts, inv_ts, snap, &mut builder);
+
+    builder.finish()
+}
+
+fn snap_subpath(
+    subpath: &[PathSegment],
+    ts: Transform,
+    inv_ts: Transform,
+    snap: &GridSnap,
+    builder: &mut PathBuilder,
+) {
+    let to_canvas = |mut p: Point| {
+        ts.map_point(&mut p);
+        p
+    };
+
+    let snap_point = |p: Point| {
+        let mut p = to_canvas(p);
+        p.x = (p.x / snap.step).round() * snap.step;
+        p.y = (p.y / snap.step).round() * snap.step;
+        inv_ts.map_point(&mut p);
+        p
+    };
+
+    let bounds = points_bounds(subpath, to_canvas);
+    let snapped_bounds = points_bounds(subpath, |p| to_canvas(snap_point(p)));
+    let keep = match (bounds, snapped_bounds) {
+        (Some(r1), Some(r2)) => {
+            let is_small = |n: f32| n > 0.0 && n < snap.min_size;
+            // Collapsing into a line or a point would change the shape completely.
+            let collapses = |n1: f32, n2: f32| n1 > 0.0 && n2.approx_zero_ulps(4);
+            is_small(r1.width())
+                || is_small(r1.height())
+                || collapses(r1.width(), r2.width())
+                || collapses(r1.height(), r2.height())
+        }
+        _ => true,
+    };
+
+    for segment in subpath {
+        let f = |p: Point| if keep { p } else { snap_point(p) };
+        match *segment {
+            PathSegment::MoveTo(p) => {
+                let p = f(p);
+                builder.move_to(p.x, p.y);
+            }
+            PathSegment::LineTo(p) => {
+                let p = f(p);
+                builder.line_to(p.x, p.y);
+            }
+            PathSegment::QuadTo(p1, p) => {
+                let (p1, p) = (f(p1), f(p));
+                builder.quad_to(p1.x, p1.y, p.x, p.y);
+            }
+            PathSegment::CubicTo(p1, p2, p) => {
+                let (p1, p2, p) = (f(p1), f(p2), f(p));
+                builder.cubic_to(p1.x, p1.y, p2.x, p2.y, p.x, p.y);
+            }
+            PathSegment::Close => builder.close(),
+        }
+    }
+}
+
+fn points_bounds(subpath: &[PathSegment], f: impl Fn(Point) -> Point) -> Option<Rect> {
+    let mut points = Vec::new();
+    for segment in subpath {
+        match *segment {
+            PathSegment::MoveTo(p) | PathSegment::LineTo(p) => points.push(f(p)),
+            PathSegment::QuadTo(p1, p) => points.extend_from_slice(&[f(p1), f(p)]),
+            PathSegment::CubicTo(p1, p2, p) => points.extend_from_slice(&[f(p1), f(p2), f(p)]),
+            PathSegment::Close => {}
+        }
+    }
+
+    Rect::from_points(&points)
+}
+
+fn snap_stroke_width(width: StrokeWidth, ts: Transform, step: f32) -> Option<StrokeWidth> {
+    let (sx, sy) = ts.get_scale();
+    let scale = (sx * sy).sqrt();
+    if !scale.is_finite() || scale <= 0.0 {
+        return None;
+    }
+
+    // Never round down to zero.
+    let canvas_width = ((width.get() * scale / step).round() * step).max(step);
+    StrokeWidth::new(canvas_width / scale)
+}
@@ -5,7 +5,9 @@
 use std::sync::Arc;
 
 use svgtypes::Length;
-use usvg_tree::{Image, ImageKind, Node, NodeExt, NodeKind, NonZeroRect, Size, Tree, ViewBox};
+use usvg_tree::{
+    Image, ImageKind, Node, NodeExt, NodeKind, NonZeroRect, Size, Transform, Tree, ViewBox,
+};
 
 use crate::svgtree::{AId, SvgNode};
 use crate::{converter, OptionLog, Options, TreeParsing};
@@ -171,6 +173,21 @@ pub(crate) fn convert(node: SvgNode, state: &converter::State, parent: &mut Node
         String::new()
     };
 
+    // The deprecated `clip` property clips an image to a rectangle inside its viewport.
+    let parent = match crate::use_node::resolve_clip_rect(node, rect, state) {
+        Some(clip_rect) => {
+            let g =
+                crate::use_node::clip_element(node, clip_rect, Transform::default(), state, parent);
+            // The ID stays on the image itself.
+            if let NodeKind::Group(ref mut g) = *g.borrow_mut() {
+                g.id = String::new();
+            }
+
+            g
+        }
+        None => parent.clone(),
+    };
+
     parent.append_kind(NodeKind::Image(Image {
         id,
         transform: Default::default(),
@@ -184,6 +201,15 @@ pub(crate) fn convert(node: SvgNode, state: &converter::State, parent: &mut Node
 }
 
 pub(crate) fn get_href_data(href: &str, opt: &Options) -> Option<ImageKind> {
+    let kind = get_href_data_impl(href, opt)?;
+    if let ImageKind::JPEG(ref data) | ImageKind::PNG(ref data) | ImageKind::GIF(ref data) = kind {
+        check_image_limits(data, opt)?;
+    }
+
+    Some(kind)
+}
+
+fn get_href_data_impl(href: &str, opt: &Options) -> Option<ImageKind> {
     if let Ok(url) = data_url::DataUrl::process(href) {
         let (data, _) = url.decode_to_vec().ok()?;
 
@@ -199,6 +225,38 @@ pub(crate) fn get_href_data(href: &str, opt: &Options) -> Option<ImageKind> {
     }
 }
 
+/// Checks `Options::max_image_bytes` and `Options::max_image_pixels`.
+fn check_image_limits(data: &[u8], opt: &Options) -> Option<()> {
+    if let Some(max) = opt.max_image_bytes {
+        if data.len() > max {
+            log::warn!(
+                "Image data is too big: {} > {} bytes. Skipped.",
+                data.len(),
+                max
+            );
+            return None;
+        }
+    }
+
+    if let Some(max) = opt.max_image_pixels {
+        // An invalid size will be handled later.
+        if let Ok(size) = imagesize::blob_size(data) {
+            let pixels = size.width as u64 * size.height as u64;
+            if pixels > max {
+                log::warn!(
+                    "Image is too big: {}x{} > {} pixels. Skipped.",
+                    size.width,
+                    size.height,
+                    max
+                );
+                return None;
+            }
+        }
+    }
+
+    Some(())
+}
+
 /// Checks that file has a PNG, a GIF or a JPEG magic bytes.
 /// Or an SVG(Z) extension.
 fn get_image_file_format(path: &std::path::Path, data: &[u8]) -> Option<ImageFormat> {
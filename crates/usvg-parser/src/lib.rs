@@ -22,8 +22,13 @@
 #![allow(clippy::upper_case_acronyms)]
 
 mod clippath;
+mod color_transform;
 mod converter;
+mod css;
+mod encoding;
 mod filter;
+mod font_face;
+mod grid_snap;
 mod image;
 mod marker;
 mod mask;
@@ -37,6 +42,7 @@ mod text;
 mod units;
 mod use_node;
 
+pub use crate::encoding::Encoding;
 pub use crate::options::*;
 pub use image::ImageHrefResolver;
 pub use roxmltree;
@@ -45,7 +51,9 @@ pub use svgtree::{AId, EId};
 /// List of all errors.
 #[derive(Debug)]
 pub enum Error {
-    /// Only UTF-8 content are supported.
+    /// Provided data is not a valid text in the detected encoding.
+    ///
+    /// Only UTF-8, UTF-16 and Latin-1 are supported.
     NotAnUtf8Str,
 
     /// Compressed SVG must use the GZip algorithm.
@@ -114,8 +122,31 @@ pub trait TreeParsing: Sized {
     /// Parses `Tree` from an SVG data.
     ///
     /// Can contain an SVG string or a gzip compressed data.
+    /// The text encoding is detected automatically.
     fn from_data(data: &[u8], opt: &Options) -> Result<Self, Error>;
 
+    /// Parses `Tree` from an SVG data using the specified text encoding.
+    ///
+    /// Can contain an SVG string or a gzip compressed data.
+    /// The encoding is detected automatically when `None`, see [`Encoding::detect`].
+    fn from_data_with_encoding(
+        data: &[u8],
+        encoding: Option<Encoding>,
+        opt: &Options,
+    ) -> Result<Self, Error> {
+        let decode = |data: &[u8]| -> Result<Self, Error> {
+            let encoding = encoding.unwrap_or_else(|| Encoding::detect(data));
+            let text = encoding.decode(data)?;
+            Self::from_str(&text, opt)
+        };
+
+        if data.starts_with(&[0x1f, 0x8b]) {
+            decode(&decompress_svgz(data)?)
+        } else {
+            decode(data)
+        }
+    }
+
     /// Parses `Tree` from an SVG string.
     fn from_str(text: &str, opt: &Options) -> Result<Self, Error>;
 
@@ -127,15 +158,9 @@ impl TreeParsing for usvg_tree::Tree {
     /// Parses `Tree` from an SVG data.
     ///
     /// Can contain an SVG string or a gzip compressed data.
+    /// The text encoding is detected automatically.
     fn from_data(data: &[u8], opt: &Options) -> Result<Self, Error> {
-        if data.starts_with(&[0x1f, 0x8b]) {
-            let data = decompress_svgz(data)?;
-            let text = std::str::from_utf8(&data).map_err(|_| Error::NotAnUtf8Str)?;
-            Self::from_str(text, opt)
-        } else {
-            let text = std::str::from_utf8(data).map_err(|_| Error::NotAnUtf8Str)?;
-            Self::from_str(text, opt)
-        }
+        Self::from_data_with_encoding(data, None, opt)
     }
 
     /// Parses `Tree` from an SVG string.
@@ -153,8 +178,14 @@ impl TreeParsing for usvg_tree::Tree {
 
     /// Parses `Tree` from `roxmltree::Document`.
     fn from_xmltree(doc: &roxmltree::Document, opt: &Options) -> Result<Self, Error> {
-        let doc = svgtree::Document::parse_tree(doc)?;
-        crate::converter::convert_doc(&doc, opt)
+        let svg_doc = svgtree::Document::parse_tree(
+            doc,
+            opt.href_rewrite.clone(),
+            opt.preserve_unknown_attributes,
+        )?;
+        let mut tree = crate::converter::convert_doc(&svg_doc, opt)?;
+        tree.font_faces = crate::font_face::collect(doc);
+        Ok(tree)
     }
 }
 
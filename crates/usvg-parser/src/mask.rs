@@ -44,7 +44,7 @@ pub(crate) fn convert(
 
     // Resolve linked mask.
     let mut mask = None;
-    if let Some(link) = node.attribute::<SvgNode>(AId::Mask) {
+    if let Some(link) = node.node_attribute(AId::Mask) {
         mask = convert(link, state, cache);
 
         // Linked `mask` must be valid.
@@ -65,6 +65,7 @@ pub(crate) fn convert(
         content_units,
         rect,
         kind,
+        color_interpolation: super::paint_server::convert_color_interpolation(node),
         mask,
         root: Node::new(NodeKind::Group(Group::default())),
     };
@@ -2,12 +2,94 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
-use usvg_tree::{ImageRendering, ShapeRendering, Size, TextRendering};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use usvg_tree::{Color, ImageRendering, ShapeRendering, Size, TextRendering};
 
 use crate::ImageHrefResolver;
 
+/// A shorthand for [Options::href_rewrite]'s function.
+pub type HrefRewriteFn = Arc<dyn Fn(&str) -> String + Send + Sync>;
+
+/// A shorthand for [ColorTransform::Custom]'s function.
+pub type ColorTransformFn = Arc<dyn Fn(Color) -> Color + Send + Sync>;
+
+/// A color transform applied to resolved paint colors.
+///
+/// Can be used to implement a simple dark mode.
+#[derive(Clone)]
+pub enum ColorTransform {
+    /// Inverts color lightness while preserving hue and saturation.
+    ///
+    /// White becomes black and vice versa, while fully saturated colors,
+    /// like pure red, stay the same.
+    InvertLightness,
+    /// A user-defined transform.
+    Custom(ColorTransformFn),
+}
+
+impl ColorTransform {
+    /// Applies the transform to a color.
+    pub fn apply(&self, color: Color) -> Color {
+        match self {
+            ColorTransform::InvertLightness => {
+                // Inverting HSL lightness preserves chroma, which means that
+                // all channels are shifted by the same amount.
+                let max = color.red.max(color.green).max(color.blue) as i32;
+                let min = color.red.min(color.green).min(color.blue) as i32;
+                let shift = 255 - max - min;
+                let f = |c: u8| (c as i32 + shift).clamp(0, 255) as u8;
+                Color::new_rgb(f(color.red), f(color.green), f(color.blue))
+            }
+            ColorTransform::Custom(ref f) => f(color),
+        }
+    }
+}
+
+impl std::fmt::Debug for ColorTransform {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ColorTransform::InvertLightness => f.write_str("InvertLightness"),
+            ColorTransform::Custom(_) => f.write_str("Custom(..)"),
+        }
+    }
+}
+
+/// Grid snapping options.
+///
+/// See [`Options::grid_snap`].
+#[derive(Clone, Copy, Debug)]
+pub struct GridSnap {
+    /// Grid step in pixels.
+    ///
+    /// Default: 1.0
+    pub step: f32,
+
+    /// Subpaths with a width or height smaller than this value in pixels are not snapped.
+    ///
+    /// Subpaths that would collapse into a line or a point are never snapped.
+    ///
+    /// Default: 0.0
+    pub min_size: f32,
+
+    /// Rounds stroke widths to the grid step as well.
+    ///
+    /// Default: false
+    pub stroke_width: bool,
+}
+
+impl Default for GridSnap {
+    fn default() -> Self {
+        GridSnap {
+            step: 1.0,
+            min_size: 0.0,
+            stroke_width: false,
+        }
+    }
+}
+
 /// Processing options.
-#[derive(Debug)]
 pub struct Options {
     /// Directory that will be used during relative paths resolving.
     ///
@@ -19,7 +101,11 @@ pub struct Options {
 
     /// Target DPI.
     ///
-    /// Impacts units conversion.
+    /// Impacts units conversion. All physical units (`in`, `cm`, `mm`, `pt` and `pc`)
+    /// are converted into pixels using this value. For example, `1in` is `dpi` pixels
+    /// and `1mm` is `dpi / 25.4` pixels.
+    ///
+    /// Also used to convert the default `font_size` from points into pixels.
     ///
     /// Default: 96.0
     pub dpi: f32,
@@ -38,6 +124,15 @@ pub struct Options {
     /// Default: 12
     pub font_size: f32,
 
+    /// Font family substitutions.
+    ///
+    /// Maps a family name requested by the SVG to a family that should be used instead.
+    /// Names are matched case-insensitively. Generic families like `serif` are not affected.
+    /// Font weight, style and stretch are preserved.
+    ///
+    /// Default: empty
+    pub font_substitutions: HashMap<String, String>,
+
     /// A list of languages.
     ///
     /// Will be used to resolve a `systemLanguage` conditional attribute.
@@ -78,6 +173,83 @@ pub struct Options {
     ///
     /// Default: see type's documentation for details
     pub image_href_resolver: ImageHrefResolver,
+
+    /// Rewrites `xlink:href` values of `image`, `use` and `feImage` elements.
+    ///
+    /// Called with the raw attribute value before any other processing.
+    /// This means that a rewritten relative path would still be resolved using
+    /// `resources_dir` and any rewritten value would still be passed to `image_href_resolver`.
+    /// Can be used to remap paths or URLs without replacing the whole resolver.
+    ///
+    /// Default: `None`
+    pub href_rewrite: Option<HrefRewriteFn>,
+
+    /// A color transform applied to all fills, strokes and gradient stops.
+    ///
+    /// Applied after `currentColor`, inheritance and paint servers resolving.
+    /// Masks and clip paths are not affected.
+    ///
+    /// Documents with `color-scheme: only light` on the root element
+    /// opt out of the transform.
+    ///
+    /// Default: `None`
+    pub color_transform: Option<ColorTransform>,
+
+    /// Snaps path coordinates to a grid.
+    ///
+    /// Unlike `shape-rendering="crispEdges"`, modifies the tree geometry itself.
+    /// The grid is defined in canvas pixels, i.e. after all transforms and the `viewBox`
+    /// are applied. Paths inside clip paths, masks and patterns are not affected.
+    ///
+    /// Default: `None`
+    pub grid_snap: Option<GridSnap>,
+
+    /// Annotates groups created for `use` elements with their source.
+    ///
+    /// When enabled, each expanded `use` element is stored as a group with
+    /// [`Group::use_source`](usvg_tree::Group::use_source) set to the `use` element ID
+    /// and the referenced element ID. Can be used to find shapes that came from the same
+    /// `symbol`.
+    ///
+    /// Default: false
+    pub preserve_use_sources: bool,
+
+    /// Preserves attributes that are not supported by `usvg`.
+    ///
+    /// When enabled, `data-*` attributes, attributes in a foreign namespace and other unknown
+    /// attributes are stored in [`Group::unknown_attributes`](usvg_tree::Group::unknown_attributes)
+    /// and written back by the `usvg` serializer. Elements with such attributes are always
+    /// converted into a group, i.e. shapes will be wrapped.
+    /// Attributes of the root `svg` element are not preserved.
+    ///
+    /// Default: false
+    pub preserve_unknown_attributes: bool,
+
+    /// Normalizes paths winding.
+    ///
+    /// When enabled, outer contours of each path are wound counter-clockwise
+    /// and holes clockwise, as seen on the canvas. See
+    /// [`normalize_winding`](usvg_tree::normalize_winding) for details.
+    /// Paths inside clip paths, masks and patterns are not affected,
+    /// just like dashed paths and text that is not converted to paths yet.
+    ///
+    /// Default: false
+    pub normalize_winding: bool,
+
+    /// The maximum number of pixels in a raster image.
+    ///
+    /// Larger images are skipped, since decoding them can exhaust memory.
+    /// The size is read from the image header, before decoding.
+    ///
+    /// Default: `None`
+    pub max_image_pixels: Option<u64>,
+
+    /// The maximum size of an encoded raster image in bytes.
+    ///
+    /// Larger images are skipped.
+    ///
+    /// Default: `None`
+    pub max_image_bytes: Option<usize>,
 }
 
 impl Default for Options {
@@ -88,16 +260,54 @@ impl Default for Options {
             // Default font is user-agent dependent so we can use whichever we like.
             font_family: "Times New Roman".to_owned(),
             font_size: 12.0,
+            font_substitutions: HashMap::new(),
             languages: vec!["en".to_string()],
             shape_rendering: ShapeRendering::default(),
             text_rendering: TextRendering::default(),
             image_rendering: ImageRendering::default(),
             default_size: Size::from_wh(100.0, 100.0).unwrap(),
             image_href_resolver: ImageHrefResolver::default(),
+            href_rewrite: None,
+            color_transform: None,
+            grid_snap: None,
+            preserve_use_sources: false,
+            preserve_unknown_attributes: false,
+            normalize_winding: false,
+            max_image_pixels: None,
+            max_image_bytes: None,
         }
     }
 }
 
+impl std::fmt::Debug for Options {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Options")
+            .field("resources_dir", &self.resources_dir)
+            .field("dpi", &self.dpi)
+            .field("font_family", &self.font_family)
+            .field("font_size", &self.font_size)
+            .field("font_substitutions", &self.font_substitutions)
+            .field("languages", &self.languages)
+            .field("shape_rendering", &self.shape_rendering)
+            .field("text_rendering", &self.text_rendering)
+            .field("image_rendering", &self.image_rendering)
+            .field("default_size", &self.default_size)
+            .field("image_href_resolver", &self.image_href_resolver)
+            .field("href_rewrite", &self.href_rewrite.as_ref().map(|_| ".."))
+            .field("color_transform", &self.color_transform)
+            .field("grid_snap", &self.grid_snap)
+            .field("preserve_use_sources", &self.preserve_use_sources)
+            .field(
+                "preserve_unknown_attributes",
+                &self.preserve_unknown_attributes,
+            )
+            .field("normalize_winding", &self.normalize_winding)
+            .field("max_image_pixels", &self.max_image_pixels)
+            .field("max_image_bytes", &self.max_image_bytes)
+            .finish()
+    }
+}
+
 impl Options {
     /// Converts a relative path into absolute relative to the SVG file itself.
     ///
@@ -3,7 +3,6 @@
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
 use std::rc::Rc;
-use std::str::FromStr;
 
 use strict_num::PositiveF32;
 use svgtypes::{Length, LengthUnit as Unit};
@@ -18,36 +17,65 @@ pub(crate) enum ServerOrColor {
     Color { color: Color, opacity: Opacity },
 }
 
+/// Converts a paint server.
+///
+/// `context` is an element that references the paint server.
+/// It's used to resolve `currentColor` in gradient stops.
 pub(crate) fn convert(
     node: SvgNode,
+    context: SvgNode,
     state: &converter::State,
     cache: &mut converter::Cache,
 ) -> Option<ServerOrColor> {
+    // Gradients with a `currentColor` stop must be resolved for each context color separately.
+    let context_color = if uses_context_color(node) {
+        Some(node.resolve_current_color(context))
+    } else {
+        None
+    };
+    let id = converter::context_color_key(node.element_id(), context_color);
+
     // Check for existing.
-    if let Some(paint) = cache.paint.get(node.element_id()) {
+    if let Some(paint) = cache.paint.get(&id) {
         return Some(ServerOrColor::Server(paint.clone()));
     }
 
     // Unwrap is safe, because we already checked for is_paint_server().
     let paint = match node.tag_name().unwrap() {
-        EId::LinearGradient => convert_linear(node, state),
-        EId::RadialGradient => convert_radial(node, state),
+        EId::LinearGradient => convert_linear(node, &id, context, state),
+        EId::RadialGradient => convert_radial(node, &id, context, state),
         EId::Pattern => convert_pattern(node, state, cache),
         _ => unreachable!(),
     };
 
     if let Some(ServerOrColor::Server(ref paint)) = paint {
-        cache
-            .paint
-            .insert(node.element_id().to_string(), paint.clone());
+        cache.paint.insert(id, paint.clone());
     }
 
     paint
 }
 
+fn uses_context_color(node: SvgNode) -> bool {
+    if !node.tag_name().unwrap().is_gradient() {
+        return false;
+    }
+
+    match find_gradient_with_stops(node) {
+        Some(grad) => grad
+            .children()
+            .any(|stop| stop.depends_on_context_color(AId::StopColor)),
+        None => false,
+    }
+}
+
 #[inline(never)]
-fn convert_linear(node: SvgNode, state: &converter::State) -> Option<ServerOrColor> {
-    let stops = convert_stops(find_gradient_with_stops(node)?);
+fn convert_linear(
+    node: SvgNode,
+    id: &str,
+    context: SvgNode,
+    state: &converter::State,
+) -> Option<ServerOrColor> {
+    let stops = convert_stops(find_gradient_with_stops(node)?, context);
     if stops.len() < 2 {
         return stops_to_color(&stops);
     }
@@ -56,7 +84,7 @@ fn convert_linear(node: SvgNode, state: &converter::State) -> Option<ServerOrCol
     let transform = node.resolve_transform(AId::GradientTransform, state);
 
     let gradient = LinearGradient {
-        id: node.element_id().to_string(),
+        id: id.to_string(),
         x1: resolve_number(node, AId::X1, units, state, Length::zero()),
         y1: resolve_number(node, AId::Y1, units, state, Length::zero()),
         x2: resolve_number(
@@ -72,6 +100,7 @@ fn convert_linear(node: SvgNode, state: &converter::State) -> Option<ServerOrCol
             transform,
             spread_method: convert_spread_method(node),
             stops,
+            color_interpolation: convert_color_interpolation(node),
         },
     };
 
@@ -81,8 +110,13 @@ fn convert_linear(node: SvgNode, state: &converter::State) -> Option<ServerOrCol
 }
 
 #[inline(never)]
-fn convert_radial(node: SvgNode, state: &converter::State) -> Option<ServerOrColor> {
-    let stops = convert_stops(find_gradient_with_stops(node)?);
+fn convert_radial(
+    node: SvgNode,
+    id: &str,
+    context: SvgNode,
+    state: &converter::State,
+) -> Option<ServerOrColor> {
+    let stops = convert_stops(find_gradient_with_stops(node)?, context);
     if stops.len() < 2 {
         return stops_to_color(&stops);
     }
@@ -122,7 +156,7 @@ fn convert_radial(node: SvgNode, state: &converter::State) -> Option<ServerOrCol
     let transform = node.resolve_transform(AId::GradientTransform, state);
 
     let gradient = RadialGradient {
-        id: node.element_id().to_string(),
+        id: id.to_string(),
         cx,
         cy,
         r: PositiveF32::new(r).unwrap(),
@@ -133,6 +167,7 @@ fn convert_radial(node: SvgNode, state: &converter::State) -> Option<ServerOrCol
             transform,
             spread_method,
             stops,
+            color_interpolation: convert_color_interpolation(node),
         },
     };
 
@@ -176,7 +211,7 @@ fn convert_pattern(
         )
     })?;
 
-    let mut patt = Pattern {
+    let patt = Pattern {
         id: node.element_id().to_string(),
         units,
         content_units,
@@ -186,20 +221,61 @@ fn convert_pattern(
         root: Node::new(NodeKind::Group(Group::default())),
     };
 
-    converter::convert_children(node_with_children, state, cache, &mut patt.root);
+    let mut parent = match pattern_clip_path(node, &patt, state) {
+        Some(clip_path) => patt.root.append_kind(NodeKind::Group(Group {
+            clip_path: Some(clip_path),
+            ..Group::default()
+        })),
+        None => patt.root.clone(),
+    };
+    converter::convert_children(node_with_children, state, cache, &mut parent);
 
-    if !patt.root.has_children() {
+    if !parent.has_children() {
         return None;
     }
 
     Some(ServerOrColor::Server(Paint::Pattern(Rc::new(patt))))
 }
 
+/// Converts the deprecated `clip` property on a `pattern` into a clip path.
+///
+/// `clip` is resolved relative to the pattern tile and then mapped into
+/// the pattern content coordinates.
+fn pattern_clip_path(
+    node: SvgNode,
+    patt: &Pattern,
+    state: &converter::State,
+) -> Option<Rc<ClipPath>> {
+    if !node.has_attribute(AId::Clip) {
+        return None;
+    }
+
+    if patt.content_units == Units::ObjectBoundingBox && patt.view_box.is_none() {
+        log::warn!("'clip' on a pattern with objectBoundingBox content units is not supported.");
+        return None;
+    }
+
+    let tile = patt.rect.size().to_non_zero_rect(0.0, 0.0);
+    let mut rect = crate::use_node::resolve_clip_rect(node, tile, state)?;
+    if let Some(vbox) = patt.view_box {
+        let ts = usvg_tree::utils::view_box_to_transform(vbox.rect, vbox.aspect, patt.rect.size());
+        rect = rect.transform(ts.invert()?)?;
+    }
+
+    Some(crate::use_node::rect_clip_path(rect))
+}
+
 fn convert_spread_method(node: SvgNode) -> SpreadMethod {
     let node = resolve_attr(node, AId::SpreadMethod);
     node.attribute(AId::SpreadMethod).unwrap_or_default()
 }
 
+pub(crate) fn convert_color_interpolation(node: SvgNode) -> filter::ColorInterpolation {
+    // Unlike `color-interpolation-filters`, the default value is `sRGB`.
+    node.find_attribute(AId::ColorInterpolation)
+        .unwrap_or(filter::ColorInterpolation::SRGB)
+}
+
 pub(crate) fn convert_units(node: SvgNode, name: AId, def: Units) -> Units {
     let node = resolve_attr(node, name);
     node.attribute(name).unwrap_or(def)
@@ -247,7 +323,7 @@ fn find_pattern_with_children<'a, 'input: 'a>(
     None
 }
 
-fn convert_stops(grad: SvgNode) -> Vec<Stop> {
+fn convert_stops(grad: SvgNode, context: SvgNode) -> Vec<Stop> {
     let mut stops = Vec::new();
 
     {
@@ -268,21 +344,9 @@ fn convert_stops(grad: SvgNode) -> Vec<Stop> {
             prev_offset = Length::new_number(offset);
             let offset = crate::f32_bound(0.0, offset as f32, 1.0);
 
-            let (color, opacity) = match stop.attribute(AId::StopColor) {
-                Some("currentColor") => stop
-                    .find_attribute(AId::Color)
-                    .unwrap_or_else(svgtypes::Color::black),
-                Some(value) => {
-                    if let Ok(c) = svgtypes::Color::from_str(value) {
-                        c
-                    } else {
-                        log::warn!("Failed to parse stop-color value: '{}'.", value);
-                        svgtypes::Color::black()
-                    }
-                }
-                _ => svgtypes::Color::black(),
-            }
-            .split_alpha();
+            let (color, opacity) = stop
+                .resolve_color(AId::StopColor, context, svgtypes::Color::black())
+                .split_alpha();
 
             let stop_opacity = stop
                 .attribute::<Opacity>(AId::StopOpacity)
@@ -509,6 +573,231 @@ fn resolve_filter_attr<'a, 'input: 'a>(node: SvgNode<'a, 'input>, aid: AId) -> S
     node
 }
 
+/// Converts a CSS `conic-gradient()` function.
+///
+/// Supports optional `from <angle>` and `at <position>` parameters and color stops
+/// with up to two positions in percentages or angles. Color hints are not supported.
+///
+/// A center in percentages or keywords results in `objectBoundingBox` units
+/// and a center in pixels in `userSpaceOnUse` units. Mixing both is not supported.
+pub(crate) fn convert_conic_gradient(
+    value: &str,
+    context: SvgNode,
+    cache: &mut converter::Cache,
+) -> Option<Paint> {
+    let args = value
+        .trim()
+        .strip_prefix("conic-gradient(")?
+        .strip_suffix(')')?;
+    let mut args = crate::css::split(args, ',');
+
+    let mut angle = 0.0;
+    let mut center = (CenterCoord::Fraction(0.5), CenterCoord::Fraction(0.5));
+    let first = args.first()?.trim();
+    if first.starts_with("from ") || first.starts_with("at ") {
+        let (from, at) = match first.split_once("at ") {
+            Some((from, at)) => (from.trim(), Some(at)),
+            None => (first, None),
+        };
+
+        if let Some(from) = from.strip_prefix("from ") {
+            angle = crate::svgtree::parse_css_angle(from.trim())?;
+        } else if !from.is_empty() {
+            return None;
+        }
+
+        if let Some(at) = at {
+            center = parse_conic_center(at)?;
+        }
+
+        args.remove(0);
+    }
+
+    let units = match center {
+        (CenterCoord::Fraction(_), CenterCoord::Fraction(_)) => Units::ObjectBoundingBox,
+        (CenterCoord::Length(_), CenterCoord::Length(_)) => Units::UserSpaceOnUse,
+        _ => return None,
+    };
+
+    let mut stops = Vec::new();
+    for arg in args {
+        let mut text = arg.trim();
+
+        // Up to two positions can follow a color.
+        let mut positions = Vec::new();
+        while positions.len() < 2 {
+            let (head, tail) = match text.rsplit_once(char::is_whitespace) {
+                Some(v) => v,
+                None => break,
+            };
+
+            match parse_conic_stop_position(tail) {
+                Some(pos) => {
+                    positions.insert(0, pos);
+                    text = head.trim_end();
+                }
+                None => break,
+            }
+        }
+
+        let color = if text == "currentColor" {
+            context.resolve_current_color(context)
+        } else {
+            text.parse::<svgtypes::Color>().ok()?
+        };
+
+        if positions.is_empty() {
+            stops.push((color, None));
+        }
+        for pos in positions {
+            stops.push((color, Some(pos)));
+        }
+    }
+
+    if stops.len() < 2 {
+        return None;
+    }
+
+    let offsets = resolve_css_stop_positions(&stops.iter().map(|s| s.1).collect::<Vec<_>>());
+    let stops = stops
+        .iter()
+        .zip(offsets)
+        .map(|((color, _), offset)| {
+            let (color, opacity) = color.split_alpha();
+            Stop {
+                offset: StopOffset::new_clamped(offset),
+                color,
+                opacity,
+            }
+        })
+        .collect();
+
+    let (cx, cy) = (center.0.get(), center.1.get());
+    Some(Paint::ConicGradient(Rc::new(ConicGradient {
+        id: gen_conic_gradient_id(context, cache),
+        cx,
+        cy,
+        angle,
+        base: BaseGradient {
+            units,
+            transform: Transform::default(),
+            spread_method: SpreadMethod::Pad,
+            stops,
+            color_interpolation: filter::ColorInterpolation::SRGB,
+        },
+    })))
+}
+
+/// Generates an ID that is not used by the document.
+fn gen_conic_gradient_id(node: SvgNode, cache: &mut converter::Cache) -> String {
+    loop {
+        cache.conic_gradients += 1;
+        let id = format!("conicGradient{}", cache.conic_gradients);
+        if node.document().element_by_id(&id).is_none() {
+            return id;
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum CenterCoord {
+    Fraction(f32),
+    Length(f32),
+}
+
+impl CenterCoord {
+    fn get(self) -> f32 {
+        match self {
+            CenterCoord::Fraction(n) | CenterCoord::Length(n) => n,
+        }
+    }
+}
+
+fn parse_conic_center(text: &str) -> Option<(CenterCoord, CenterCoord)> {
+    let parse = |s: &str| match s {
+        "left" | "top" => Some(CenterCoord::Fraction(0.0)),
+        "center" => Some(CenterCoord::Fraction(0.5)),
+        "right" | "bottom" => Some(CenterCoord::Fraction(1.0)),
+        _ => {
+            if let Some(n) = s.strip_suffix('%') {
+                let n: f32 = n.parse().ok()?;
+                Some(CenterCoord::Fraction(n / 100.0))
+            } else {
+                crate::svgtree::parse_css_length(s).map(CenterCoord::Length)
+            }
+        }
+    };
+
+    let mut parts = text.split_whitespace();
+    let first = parts.next()?;
+    let second = parts.next().unwrap_or("center");
+    if parts.next().is_some() {
+        return None;
+    }
+
+    // Vertical keywords can be set first, like `top left`.
+    if matches!(first, "top" | "bottom") || matches!(second, "left" | "right") {
+        Some((parse(second)?, parse(first)?))
+    } else {
+        Some((parse(first)?, parse(second)?))
+    }
+}
+
+/// Parses a stop position as a fraction of a full turn.
+fn parse_conic_stop_position(text: &str) -> Option<f32> {
+    if let Some(n) = text.strip_suffix('%') {
+        let n: f32 = n.parse().ok()?;
+        Some(n / 100.0)
+    } else {
+        crate::svgtree::parse_css_angle(text).map(|a| a / 360.0)
+    }
+}
+
+/// Resolves omitted and decreasing stop positions as per CSS Images 3.
+fn resolve_css_stop_positions(positions: &[Option<f32>]) -> Vec<f32> {
+    let mut resolved: Vec<Option<f32>> = positions.to_vec();
+    if let Some(first) = resolved.first_mut() {
+        first.get_or_insert(0.0);
+    }
+    if let Some(last) = resolved.last_mut() {
+        last.get_or_insert(1.0);
+    }
+
+    // A position cannot be smaller than any position before it.
+    let mut max = f32::MIN;
+    for pos in resolved.iter_mut().flatten() {
+        max = max.max(*pos);
+        *pos = max;
+    }
+
+    // Omitted positions are spread evenly between the closest known ones.
+    let mut result = Vec::with_capacity(resolved.len());
+    let mut i = 0;
+    while i < resolved.len() {
+        match resolved[i] {
+            Some(pos) => {
+                result.push(pos);
+                i += 1;
+            }
+            None => {
+                // The first and the last positions are always set.
+                let start = result[i - 1];
+                let end_idx = (i..resolved.len())
+                    .find(|n| resolved[*n].is_some())
+                    .unwrap();
+                let end = resolved[end_idx].unwrap();
+                let count = (end_idx - i + 1) as f32;
+                for n in i..end_idx {
+                    result.push(start + (end - start) * (n - i + 1) as f32 / count);
+                }
+                i = end_idx;
+            }
+        }
+    }
+
+    result
+}
+
 fn stops_to_color(stops: &[Stop]) -> Option<ServerOrColor> {
     if stops.is_empty() {
         None
@@ -25,14 +25,26 @@ pub(crate) fn convert(node: SvgNode, state: &converter::State) -> Option<Rc<Path
 }
 
 pub(crate) fn convert_path(node: SvgNode) -> Option<Rc<Path>> {
-    let value: &str = node.attribute(AId::D)?;
-    let mut builder = tiny_skia_path::PathBuilder::new();
+    let value = unwrap_css_path(node.attribute(AId::D)?)?;
+
+    // Path data can be megabytes long, so reserve the buffers upfront
+    // to avoid repeated reallocations. `svgtypes` parses numbers in place,
+    // so these are the only allocations that depend on the data length.
+    // Estimated as a coordinates pair per 16 bytes and a segment per two pairs,
+    // which is close to the lower bound for typical data.
+    let mut builder =
+        tiny_skia_path::PathBuilder::with_capacity(value.len() / 32, value.len() / 16);
     for segment in svgtypes::SimplifyingPathParser::from(value) {
         let segment = match segment {
             Ok(v) => v,
             Err(_) => break,
         };
 
+        // Coordinates that overflow `f32` are treated just like a parsing error.
+        if !is_finite_segment(&segment) {
+            break;
+        }
+
         match segment {
             svgtypes::SimplePathSegment::MoveTo { x, y } => {
                 builder.move_to(x as f32, y as f32);
@@ -64,6 +76,49 @@ pub(crate) fn convert_path(node: SvgNode) -> Option<Rc<Path>> {
     builder.finish().map(Rc::new)
 }
 
+/// Extracts path data from the CSS `d` property syntax.
+///
+/// Accepts `path("...")`, `none` and plain path data, which is used by the `d` attribute.
+fn unwrap_css_path(value: &str) -> Option<&str> {
+    let value = value.trim();
+    if value == "none" {
+        return None;
+    }
+
+    let inner = match value
+        .strip_prefix("path(")
+        .and_then(|v| v.strip_suffix(')'))
+    {
+        Some(v) => v.trim(),
+        None => return Some(value),
+    };
+
+    inner
+        .strip_prefix('"')
+        .and_then(|v| v.strip_suffix('"'))
+        .or_else(|| inner.strip_prefix('\'').and_then(|v| v.strip_suffix('\'')))
+}
+
+fn is_finite_segment(segment: &svgtypes::SimplePathSegment) -> bool {
+    let finite = |n: &f64| (*n as f32).is_finite();
+    match *segment {
+        svgtypes::SimplePathSegment::MoveTo { x, y }
+        | svgtypes::SimplePathSegment::LineTo { x, y } => [x, y].iter().all(finite),
+        svgtypes::SimplePathSegment::Quadratic { x1, y1, x, y } => {
+            [x1, y1, x, y].iter().all(finite)
+        }
+        svgtypes::SimplePathSegment::CurveTo {
+            x1,
+            y1,
+            x2,
+            y2,
+            x,
+            y,
+        } => [x1, y1, x2, y2, x, y].iter().all(finite),
+        svgtypes::SimplePathSegment::ClosePath => true,
+    }
+}
+
 fn convert_rect(node: SvgNode, state: &converter::State) -> Option<Rc<Path>> {
     // 'width' and 'height' attributes must be positive and non-zero.
     let width = node.convert_user_length(AId::Width, state, Length::zero());
@@ -190,6 +245,11 @@ fn points_to_path(node: SvgNode, eid: &str) -> Option<tiny_skia_path::PathBuilde
     match node.attribute::<&str>(AId::Points) {
         Some(text) => {
             for (x, y) in PointsParser::from(text) {
+                // Just like with a parsing error, stop at the first invalid point.
+                if !(x as f32).is_finite() || !(y as f32).is_finite() {
+                    break;
+                }
+
                 if builder.is_empty() {
                     builder.move_to(x as f32, y as f32);
                 } else {
@@ -58,7 +58,7 @@ pub(crate) fn resolve_fill(
 
     let mut sub_opacity = Opacity::ONE;
     let paint = if let Some(n) = node.ancestors().find(|n| n.has_attribute(AId::Fill)) {
-        convert_paint(n, AId::Fill, has_bbox, state, &mut sub_opacity, cache)?
+        convert_paint(n, node, AId::Fill, has_bbox, state, &mut sub_opacity, cache)?
     } else {
         Paint::Color(Color::black())
     };
@@ -87,26 +87,41 @@ pub(crate) fn resolve_stroke(
 
     let mut sub_opacity = Opacity::ONE;
     let paint = if let Some(n) = node.ancestors().find(|n| n.has_attribute(AId::Stroke)) {
-        convert_paint(n, AId::Stroke, has_bbox, state, &mut sub_opacity, cache)?
+        convert_paint(
+            n,
+            node,
+            AId::Stroke,
+            has_bbox,
+            state,
+            &mut sub_opacity,
+            cache,
+        )?
     } else {
         return None;
     };
 
     let width = node.resolve_valid_length(AId::StrokeWidth, state, 1.0)?;
 
-    // Must be bigger than 1.
-    let miterlimit = node.find_attribute(AId::StrokeMiterlimit).unwrap_or(4.0);
-    let miterlimit = if miterlimit < 1.0 { 1.0 } else { miterlimit };
+    // Values smaller than 1 are invalid and must be ignored, like any other invalid value.
+    // In which case an inherited value will be used.
+    let miterlimit = node
+        .ancestors()
+        .filter_map(|n| n.attribute::<f32>(AId::StrokeMiterlimit))
+        .find(|n| n.is_finite() && *n >= 1.0)
+        .unwrap_or(4.0);
     let miterlimit = StrokeMiterlimit::new(miterlimit);
 
     let stroke_opacity = node
         .find_attribute::<Opacity>(AId::StrokeOpacity)
         .unwrap_or(Opacity::ONE);
 
+    let dasharray = conv_dasharray(node, state);
+    let dashoffset = conv_dashoffset(node, dasharray.as_deref(), state);
+
     let stroke = Stroke {
         paint,
-        dasharray: conv_dasharray(node, state),
-        dashoffset: node.resolve_length(AId::StrokeDashoffset, state, 0.0),
+        dasharray,
+        dashoffset,
         miterlimit,
         opacity: sub_opacity * stroke_opacity,
         width,
@@ -117,8 +132,13 @@ pub(crate) fn resolve_stroke(
     Some(stroke)
 }
 
+/// Converts a paint attribute set on `node`.
+///
+/// `context` is the element being painted. It may differ from `node` when the paint is inherited
+/// and is used to resolve `currentColor`.
 fn convert_paint(
     node: SvgNode,
+    context: SvgNode,
     aid: AId,
     has_bbox: bool,
     state: &converter::State,
@@ -126,6 +146,17 @@ fn convert_paint(
     cache: &mut converter::Cache,
 ) -> Option<Paint> {
     let value: &str = node.attribute(aid)?;
+    // An invalid conic gradient is handled just like any other invalid paint below.
+    if value.starts_with("conic-gradient(") {
+        if let Some(paint) = paint_server::convert_conic_gradient(value, context, cache) {
+            if !has_bbox && paint.units() == Some(Units::ObjectBoundingBox) {
+                return None;
+            }
+
+            return Some(paint);
+        }
+    }
+
     let paint = match svgtypes::Paint::from_str(value) {
         Ok(v) => v,
         Err(_) => {
@@ -145,10 +176,7 @@ fn convert_paint(
         svgtypes::Paint::None => None,
         svgtypes::Paint::Inherit => None, // already resolved by svgtree
         svgtypes::Paint::CurrentColor => {
-            let svg_color: svgtypes::Color = node
-                .find_attribute(AId::Color)
-                .unwrap_or_else(svgtypes::Color::black);
-            let (color, alpha) = svg_color.split_alpha();
+            let (color, alpha) = context.resolve_current_color(context).split_alpha();
             *opacity = alpha;
             Some(Paint::Color(color))
         }
@@ -161,14 +189,14 @@ fn convert_paint(
             if let Some(link) = node.document().element_by_id(func_iri) {
                 let tag_name = link.tag_name().unwrap();
                 if tag_name.is_paint_server() {
-                    match paint_server::convert(link, state, cache) {
+                    match paint_server::convert(link, context, state, cache) {
                         Some(paint_server::ServerOrColor::Server(paint)) => {
                             // We can use a paint server node with ObjectBoundingBox units
                             // for painting only when the shape itself has a bbox.
                             //
                             // See SVG spec 7.11 for details.
                             if !has_bbox && paint.units() == Some(Units::ObjectBoundingBox) {
-                                from_fallback(node, fallback, opacity)
+                                from_fallback(context, fallback, opacity)
                             } else {
                                 Some(paint)
                             }
@@ -177,14 +205,14 @@ fn convert_paint(
                             *opacity = so;
                             Some(Paint::Color(color))
                         }
-                        None => from_fallback(node, fallback, opacity),
+                        None => from_fallback(context, fallback, opacity),
                     }
                 } else {
                     log::warn!("'{}' cannot be used to {} a shape.", tag_name, aid);
-                    None
+                    from_fallback(context, fallback, opacity)
                 }
             } else {
-                from_fallback(node, fallback, opacity)
+                from_fallback(context, fallback, opacity)
             }
         }
         // Ignore `context-fill` and `context-stroke for now
@@ -200,10 +228,7 @@ fn from_fallback(
     match fallback? {
         svgtypes::PaintFallback::None => None,
         svgtypes::PaintFallback::CurrentColor => {
-            let svg_color: svgtypes::Color = node
-                .find_attribute(AId::Color)
-                .unwrap_or_else(svgtypes::Color::black);
-            let (color, alpha) = svg_color.split_alpha();
+            let (color, alpha) = node.resolve_current_color(node).split_alpha();
             *opacity = alpha;
             Some(Paint::Color(color))
         }
@@ -215,6 +240,31 @@ fn from_fallback(
     }
 }
 
+// Resolves `stroke-dashoffset`, which can be negative and in percentages.
+//
+// The offset is normalized into the `[0, period)` range of the dash array,
+// so a negative offset shifts the dash pattern forward along the path.
+fn conv_dashoffset(node: SvgNode, dasharray: Option<&[f32]>, state: &converter::State) -> f32 {
+    let offset = node.resolve_length(AId::StrokeDashoffset, state, 0.0);
+    let dasharray = match dasharray {
+        Some(v) => v,
+        None => return 0.0,
+    };
+
+    let period: f32 = dasharray.iter().sum();
+    if !offset.is_finite() || !period.is_finite() || period <= 0.0 {
+        return 0.0;
+    }
+
+    let offset = offset.rem_euclid(period);
+    // Due to finite precision, the result can be equal to the period.
+    if offset >= period {
+        0.0
+    } else {
+        offset
+    }
+}
+
 // Prepare the 'stroke-dasharray' according to:
 // https://www.w3.org/TR/SVG11/painting.html#StrokeDasharrayProperty
 fn conv_dasharray(node: SvgNode, state: &converter::State) -> Option<Vec<f32>> {
@@ -11,6 +11,7 @@ mod parse;
 mod text;
 
 pub use names::{AId, EId};
+pub(crate) use parse::{parse_css_angle, parse_css_length};
 
 /// An SVG tree container.
 ///
@@ -20,6 +21,9 @@ pub struct Document<'input> {
     nodes: Vec<NodeData>,
     attrs: Vec<Attribute<'input>>,
     links: HashMap<String, NodeId>,
+    href_rewrite: Option<crate::HrefRewriteFn>,
+    /// Collected only when `preserve_unknown_attributes` is set.
+    unknown_attrs: Option<HashMap<NodeId, Vec<usvg_tree::UnknownAttribute>>>,
 }
 
 impl<'input> Document<'input> {
@@ -144,7 +148,7 @@ impl ShortRange {
     }
 }
 
-#[derive(Clone, Copy, PartialEq, Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
 pub(crate) struct NodeId(NonZeroU32);
 
 impl NodeId {
@@ -286,7 +290,7 @@ impl<'a, 'input: 'a> SvgNode<'a, 'input> {
     }
 
     #[inline]
-    fn node_attribute(&self, aid: AId) -> Option<SvgNode<'a, 'input>> {
+    pub(crate) fn node_attribute(&self, aid: AId) -> Option<SvgNode<'a, 'input>> {
         let value = self.attribute(aid)?;
         let id = if aid == AId::Href {
             svgtypes::IRI::from_str(value).ok().map(|v| v.0)
@@ -297,6 +301,18 @@ impl<'a, 'input: 'a> SvgNode<'a, 'input> {
         self.document().element_by_id(id)
     }
 
+    /// Returns attributes that are not supported by `usvg`.
+    ///
+    /// Always empty unless `preserve_unknown_attributes` is set.
+    pub fn unknown_attributes(&self) -> &'a [usvg_tree::UnknownAttribute] {
+        self.doc
+            .unknown_attrs
+            .as_ref()
+            .and_then(|attrs| attrs.get(&self.id))
+            .map(|attrs| attrs.as_slice())
+            .unwrap_or_default()
+    }
+
     /// Checks if an attribute is present.
     #[inline]
     pub fn has_attribute(&self, aid: AId) -> bool {
@@ -656,12 +672,15 @@ impl AId {
             self,
             AId::AlignmentBaseline
                 | AId::BaselineShift
+                | AId::Clip
                 | AId::ClipPath
                 | AId::ClipRule
                 | AId::Color
                 | AId::ColorInterpolation
                 | AId::ColorInterpolationFilters
                 | AId::ColorRendering
+                | AId::ColorScheme // technically not presentation
+                | AId::D // SVG 2
                 | AId::Direction
                 | AId::Display
                 | AId::DominantBaseline
@@ -672,6 +691,7 @@ impl AId {
                 | AId::FloodColor
                 | AId::FloodOpacity
                 | AId::FontFamily
+                | AId::FontFeatureSettings // technically not presentation
                 | AId::FontKerning // technically not presentation
                 | AId::FontSize
                 | AId::FontSizeAdjust
@@ -682,6 +702,7 @@ impl AId {
                 | AId::GlyphOrientationHorizontal
                 | AId::GlyphOrientationVertical
                 | AId::ImageRendering
+                | AId::InlineSize // SVG 2
                 | AId::Isolation // technically not presentation
                 | AId::LetterSpacing
                 | AId::LightingColor
@@ -709,6 +730,7 @@ impl AId {
                 | AId::TextOverflow
                 | AId::TextRendering
                 | AId::Transform
+                | AId::TransformBox
                 | AId::TransformOrigin
                 | AId::UnicodeBidi
                 | AId::VectorEffect
@@ -733,9 +755,11 @@ impl AId {
             self,
             AId::AlignmentBaseline
                 | AId::BaselineShift
+                | AId::Clip
                 | AId::ClipPath
                 | AId::ClipRule
                 | AId::Color
+                | AId::ColorInterpolation
                 | AId::ColorInterpolationFilters
                 | AId::Direction
                 | AId::Display
@@ -747,6 +771,7 @@ impl AId {
                 | AId::FloodColor
                 | AId::FloodOpacity
                 | AId::FontFamily
+                | AId::FontFeatureSettings
                 | AId::FontKerning
                 | AId::FontSize
                 | AId::FontStretch
@@ -788,12 +813,15 @@ fn is_non_inheritable(id: AId) -> bool {
         id,
         AId::AlignmentBaseline
             | AId::BaselineShift
+            | AId::Clip
             | AId::ClipPath
+            | AId::D
             | AId::Display
             | AId::DominantBaseline
             | AId::Filter
             | AId::FloodColor
             | AId::FloodOpacity
+            | AId::InlineSize
             | AId::Mask
             | AId::Opacity
             | AId::Overflow
@@ -802,6 +830,7 @@ fn is_non_inheritable(id: AId) -> bool {
             | AId::StopOpacity
             | AId::TextDecoration
             | AId::Transform
+            | AId::TransformBox
             | AId::TransformOrigin
     )
 }
@@ -821,15 +850,25 @@ impl<'a, 'input: 'a> FromValue<'a, 'input> for &'a str {
     }
 }
 
+/// Checks that a parsed number is still finite after conversion to `f32`.
+///
+/// Numbers like `1e39` are valid in SVG, but overflow `f32`
+/// and must be treated as invalid values.
+fn is_finite_f32(n: f64) -> bool {
+    (n as f32).is_finite()
+}
+
 impl<'a, 'input: 'a> FromValue<'a, 'input> for f32 {
     fn parse(_: SvgNode, _: AId, value: &str) -> Option<Self> {
-        svgtypes::Number::from_str(value).ok().map(|v| v.0 as f32)
+        let n = svgtypes::Number::from_str(value).ok()?.0;
+        is_finite_f32(n).then_some(n as f32)
     }
 }
 
 impl<'a, 'input: 'a> FromValue<'a, 'input> for svgtypes::Length {
     fn parse(_: SvgNode, _: AId, value: &str) -> Option<Self> {
-        svgtypes::Length::from_str(value).ok()
+        let length = svgtypes::Length::from_str(value).ok()?;
+        is_finite_f32(length.number).then_some(length)
     }
 }
 
@@ -881,7 +920,11 @@ impl<'a, 'input: 'a> FromValue<'a, 'input> for svgtypes::TransformOrigin {
 
 impl<'a, 'input: 'a> FromValue<'a, 'input> for svgtypes::ViewBox {
     fn parse(_: SvgNode, _: AId, value: &str) -> Option<Self> {
-        Self::from_str(value).ok()
+        let vb = Self::from_str(value).ok()?;
+        [vb.x, vb.y, vb.w, vb.h]
+            .iter()
+            .all(|n| is_finite_f32(*n))
+            .then_some(vb)
     }
 }
 
@@ -921,7 +964,8 @@ impl<'a, 'input: 'a> FromValue<'a, 'input> for svgtypes::Color {
 
 impl<'a, 'input: 'a> FromValue<'a, 'input> for svgtypes::Angle {
     fn parse(_: SvgNode, _: AId, value: &str) -> Option<Self> {
-        Self::from_str(value).ok()
+        let angle = Self::from_str(value).ok()?;
+        is_finite_f32(angle.to_degrees()).then_some(angle)
     }
 }
 
@@ -941,7 +985,12 @@ impl<'a, 'input: 'a> FromValue<'a, 'input> for Vec<f32> {
     fn parse(_: SvgNode, _: AId, value: &str) -> Option<Self> {
         let mut list = Vec::new();
         for n in svgtypes::NumberListParser::from(value) {
-            list.push(n.ok()? as f32);
+            let n = n.ok()?;
+            if !is_finite_f32(n) {
+                return None;
+            }
+
+            list.push(n as f32);
         }
 
         Some(list)
@@ -952,7 +1001,12 @@ impl<'a, 'input: 'a> FromValue<'a, 'input> for Vec<svgtypes::Length> {
     fn parse(_: SvgNode, _: AId, value: &str) -> Option<Self> {
         let mut list = Vec::new();
         for n in svgtypes::LengthListParser::from(value) {
-            list.push(n.ok()?);
+            let n = n.ok()?;
+            if !is_finite_f32(n.number) {
+                return None;
+            }
+
+            list.push(n);
         }
 
         Some(list)
@@ -176,6 +176,7 @@ pub enum AId {
     ColorInterpolationFilters,
     ColorProfile,
     ColorRendering,
+    ColorScheme,
     Cx,
     Cy,
     D,
@@ -371,266 +372,261 @@ pub enum AId {
 }
 
 static ATTRIBUTES: Map<AId> = Map {
-    key: 732231254413039614,
+    key: 17799468072909011701,
     disps: &[
-        (63, 17),
-        (0, 1),
-        (0, 113),
-        (0, 21),
+        (1, 95),
+        (1, 2),
+        (0, 4),
         (0, 8),
-        (0, 23),
-        (0, 15),
-        (0, 3),
-        (40, 203),
+        (0, 25),
+        (1, 1),
+        (0, 11),
+        (0, 50),
+        (1, 177),
+        (0, 139),
+        (12, 155),
+        (0, 13),
+        (0, 16),
+        (3, 91),
+        (2, 70),
+        (0, 13),
+        (0, 120),
+        (0, 80),
         (0, 3),
-        (0, 6),
-        (1, 31),
-        (0, 68),
-        (11, 161),
-        (0, 41),
-        (2, 58),
-        (7, 10),
-        (0, 0),
-        (0, 39),
-        (7, 73),
-        (0, 5),
-        (65, 30),
-        (0, 71),
-        (0, 28),
-        (0, 7),
-        (2, 99),
-        (0, 168),
-        (1, 79),
-        (0, 0),
         (0, 3),
-        (0, 21),
-        (1, 9),
-        (0, 0),
-        (5, 171),
-        (0, 45),
-        (0, 18),
-        (99, 110),
-        (31, 206),
-        (5, 125),
-        (0, 14),
         (0, 4),
+        (1, 11),
+        (4, 11),
+        (0, 116),
+        (1, 114),
+        (2, 16),
+        (48, 190),
         (0, 83),
+        (1, 162),
+        (11, 70),
+        (1, 85),
+        (0, 0),
+        (1, 144),
+        (0, 0),
+        (0, 56),
+        (1, 72),
+        (7, 2),
+        (0, 158),
+        (0, 89),
+        (22, 174),
+        (1, 2),
+        (14, 64),
     ],
     entries: &[
-        ("mask-type", AId::MaskType),
-        ("markerWidth", AId::MarkerWidth),
-        ("textLength", AId::TextLength),
-        ("alignment-baseline", AId::AlignmentBaseline),
-        ("unicode-bidi", AId::UnicodeBidi),
-        ("dominant-baseline", AId::DominantBaseline),
+        ("offset", AId::Offset),
+        ("vector-effect", AId::VectorEffect),
+        ("image-rendering", AId::ImageRendering),
+        ("diffuseConstant", AId::DiffuseConstant),
+        ("transform-origin", AId::TransformOrigin),
+        ("font-variant-caps", AId::FontVariantCaps),
+        ("shape-rendering", AId::ShapeRendering),
+        ("kerning", AId::Kerning),
+        ("marker-start", AId::MarkerStart),
+        ("color-rendering", AId::ColorRendering),
+        ("limitingConeAngle", AId::LimitingConeAngle),
+        ("gradientTransform", AId::GradientTransform),
+        ("order", AId::Order),
+        ("text-orientation", AId::TextOrientation),
+        ("stroke-dasharray", AId::StrokeDasharray),
+        ("stroke-width", AId::StrokeWidth),
+        ("targetY", AId::TargetY),
+        ("operator", AId::Operator),
+        ("width", AId::Width),
+        ("divisor", AId::Divisor),
+        ("mask-border-slice", AId::MaskBorderSlice),
+        ("fx", AId::Fx),
+        ("in2", AId::In2),
+        ("pointsAtY", AId::PointsAtY),
+        ("mask-border-width", AId::MaskBorderWidth),
+        ("color", AId::Color),
+        ("values", AId::Values),
+        ("patternUnits", AId::PatternUnits),
+        ("r", AId::R),
+        ("marker-end", AId::MarkerEnd),
         ("orient", AId::Orient),
-        ("x", AId::X),
+        ("font-stretch", AId::FontStretch),
+        ("rx", AId::Rx),
+        ("exponent", AId::Exponent),
+        ("preserveAlpha", AId::PreserveAlpha),
+        ("flood-color", AId::FloodColor),
+        ("path", AId::Path),
+        ("font-feature-settings", AId::FontFeatureSettings),
+        ("shape-margin", AId::ShapeMargin),
+        ("font-variant-ligatures", AId::FontVariantLigatures),
+        ("y", AId::Y),
+        ("side", AId::Side),
+        ("baseline-shift", AId::BaselineShift),
+        ("isolation", AId::Isolation),
+        ("markerWidth", AId::MarkerWidth),
+        ("filterUnits", AId::FilterUnits),
+        ("refX", AId::RefX),
+        ("cx", AId::Cx),
+        ("markerHeight", AId::MarkerHeight),
+        ("text-overflow", AId::TextOverflow),
+        ("text-decoration-fill", AId::TextDecorationFill),
+        ("transform", AId::Transform),
         ("font-style", AId::FontStyle),
-        ("color-interpolation", AId::ColorInterpolation),
-        ("style", AId::Style),
-        ("clip-rule", AId::ClipRule),
-        ("bias", AId::Bias),
-        ("font-weight", AId::FontWeight),
-        ("fill", AId::Fill),
-        ("mask-border-outset", AId::MaskBorderOutset),
+        ("color-profile", AId::ColorProfile),
+        ("patternTransform", AId::PatternTransform),
+        ("z", AId::Z),
+        ("requiredExtensions", AId::RequiredExtensions),
+        ("text-indent", AId::TextIndent),
+        ("stop-color", AId::StopColor),
+        ("font-variant-numeric", AId::FontVariantNumeric),
+        ("line-height", AId::LineHeight),
+        ("rotate", AId::Rotate),
         ("x1", AId::X1),
+        ("fill-rule", AId::FillRule),
+        ("mask-border-repeat", AId::MaskBorderRepeat),
         ("mask", AId::Mask),
-        ("height", AId::Height),
-        ("stroke", AId::Stroke),
-        ("transform", AId::Transform),
-        ("isolation", AId::Isolation),
-        ("fy", AId::Fy),
-        ("text-orientation", AId::TextOrientation),
-        ("stop-opacity", AId::StopOpacity),
-        ("fx", AId::Fx),
-        ("font-kerning", AId::FontKerning),
-        ("cx", AId::Cx),
-        ("maskContentUnits", AId::MaskContentUnits),
-        ("opacity", AId::Opacity),
-        ("filterUnits", AId::FilterUnits),
-        ("lengthAdjust", AId::LengthAdjust),
-        ("text-anchor", AId::TextAnchor),
-        ("stroke-miterlimit", AId::StrokeMiterlimit),
+        ("overflow", AId::Overflow),
+        ("numOctaves", AId::NumOctaves),
+        ("stroke-linejoin", AId::StrokeLinejoin),
+        ("tableValues", AId::TableValues),
         ("viewBox", AId::ViewBox),
-        ("visibility", AId::Visibility),
-        ("ry", AId::Ry),
-        (
-            "glyph-orientation-horizontal",
-            AId::GlyphOrientationHorizontal,
-        ),
-        ("gradientTransform", AId::GradientTransform),
+        ("text-anchor", AId::TextAnchor),
+        ("writing-mode", AId::WritingMode),
+        ("x", AId::X),
+        ("edgeMode", AId::EdgeMode),
+        ("opacity", AId::Opacity),
         ("markerUnits", AId::MarkerUnits),
-        ("shape-inside", AId::ShapeInside),
-        ("font-size", AId::FontSize),
-        ("yChannelSelector", AId::YChannelSelector),
-        ("clip-path", AId::ClipPath),
-        ("flood-color", AId::FloodColor),
-        ("marker-mid", AId::MarkerMid),
-        ("surfaceScale", AId::SurfaceScale),
-        ("color-profile", AId::ColorProfile),
-        ("requiredFeatures", AId::RequiredFeatures),
-        ("class", AId::Class),
-        ("startOffset", AId::StartOffset),
+        ("k2", AId::K2),
+        ("enable-background", AId::EnableBackground),
+        ("space", AId::Space),
+        ("patternContentUnits", AId::PatternContentUnits),
+        ("display", AId::Display),
+        ("text-decoration-color", AId::TextDecorationColor),
+        ("filter", AId::Filter),
+        ("mask-border-outset", AId::MaskBorderOutset),
+        ("mask-mode", AId::MaskMode),
+        ("font", AId::Font),
+        ("y1", AId::Y1),
+        ("stroke-linecap", AId::StrokeLinecap),
+        ("mask-composite", AId::MaskComposite),
+        ("clip-rule", AId::ClipRule),
+        ("clip", AId::Clip),
+        ("unicode-range", AId::UnicodeRange),
+        ("text-decoration-stroke", AId::TextDecorationStroke),
+        ("preserveAspectRatio", AId::PreserveAspectRatio),
+        ("azimuth", AId::Azimuth),
+        ("points", AId::Points),
+        ("d", AId::D),
+        ("unicode-bidi", AId::UnicodeBidi),
+        ("lengthAdjust", AId::LengthAdjust),
         ("mask-origin", AId::MaskOrigin),
-        ("stdDeviation", AId::StdDeviation),
-        ("mode", AId::Mode),
-        ("overflow", AId::Overflow),
-        ("text-decoration-fill", AId::TextDecorationFill),
+        ("seed", AId::Seed),
+        ("specularConstant", AId::SpecularConstant),
+        ("color-interpolation", AId::ColorInterpolation),
+        ("shape-subtract", AId::ShapeSubtract),
+        ("href", AId::Href),
+        ("font-variant-position", AId::FontVariantPosition),
+        ("clipPathUnits", AId::ClipPathUnits),
+        ("text-decoration-style", AId::TextDecorationStyle),
+        ("text-rendering", AId::TextRendering),
         ("mask-border-mode", AId::MaskBorderMode),
-        ("divisor", AId::Divisor),
-        ("marker-start", AId::MarkerStart),
-        ("text-decoration-line", AId::TextDecorationLine),
-        ("preserveAlpha", AId::PreserveAlpha),
+        ("mask-position", AId::MaskPosition),
+        ("result", AId::Result),
+        ("glyph-orientation-vertical", AId::GlyphOrientationVertical),
+        ("elevation", AId::Elevation),
+        ("font-weight", AId::FontWeight),
+        ("requiredFeatures", AId::RequiredFeatures),
+        ("pointsAtX", AId::PointsAtX),
+        ("text-underline-position", AId::TextUnderlinePosition),
+        ("shape-image-threshold", AId::ShapeImageThreshold),
+        ("id", AId::Id),
+        ("class", AId::Class),
         ("primitiveUnits", AId::PrimitiveUnits),
+        ("maskContentUnits", AId::MaskContentUnits),
+        ("bias", AId::Bias),
+        ("slope", AId::Slope),
+        ("mask-size", AId::MaskSize),
+        ("text-decoration-line", AId::TextDecorationLine),
+        ("baseFrequency", AId::BaseFrequency),
+        ("stroke", AId::Stroke),
+        ("clip-path", AId::ClipPath),
+        ("mask-type", AId::MaskType),
+        ("mix-blend-mode", AId::MixBlendMode),
+        ("k1", AId::K1),
+        ("lighting-color", AId::LightingColor),
         ("in", AId::In),
-        ("points", AId::Points),
-        ("pointsAtY", AId::PointsAtY),
-        ("specularExponent", AId::SpecularExponent),
-        ("shape-rendering", AId::ShapeRendering),
-        ("refY", AId::RefY),
-        ("clip", AId::Clip),
-        ("white-space", AId::WhiteSpace),
-        ("vector-effect", AId::VectorEffect),
-        ("image-rendering", AId::ImageRendering),
         ("stitchTiles", AId::StitchTiles),
+        ("mode", AId::Mode),
+        ("alignment-baseline", AId::AlignmentBaseline),
+        ("shape-inside", AId::ShapeInside),
+        ("letter-spacing", AId::LetterSpacing),
+        ("gradientUnits", AId::GradientUnits),
+        ("dx", AId::Dx),
+        ("dominant-baseline", AId::DominantBaseline),
+        ("surfaceScale", AId::SurfaceScale),
+        ("visibility", AId::Visibility),
         ("stroke-opacity", AId::StrokeOpacity),
-        ("shape-margin", AId::ShapeMargin),
-        ("y2", AId::Y2),
-        ("operator", AId::Operator),
+        ("text-align-last", AId::TextAlignLast),
+        ("style", AId::Style),
         ("pathLength", AId::PathLength),
-        ("order", AId::Order),
-        ("text-rendering", AId::TextRendering),
-        ("mask-border", AId::MaskBorder),
-        ("exponent", AId::Exponent),
-        (
-            "color-interpolation-filters",
-            AId::ColorInterpolationFilters,
-        ),
-        ("diffuseConstant", AId::DiffuseConstant),
-        ("space", AId::Space),
-        ("font-synthesis", AId::FontSynthesis),
-        ("direction", AId::Direction),
-        ("font-size-adjust", AId::FontSizeAdjust),
-        ("kerning", AId::Kerning),
-        ("flood-opacity", AId::FloodOpacity),
-        ("mask-border-source", AId::MaskBorderSource),
-        ("line-height", AId::LineHeight),
-        ("mix-blend-mode", AId::MixBlendMode),
-        ("scale", AId::Scale),
-        ("mask-image", AId::MaskImage),
+        ("yChannelSelector", AId::YChannelSelector),
+        ("font-size", AId::FontSize),
         ("dy", AId::Dy),
+        ("kernelMatrix", AId::KernelMatrix),
+        ("font-variant-east-asian", AId::FontVariantEastAsian),
+        ("glyph-orientation-horizontal", AId::GlyphOrientationHorizontal),
+        ("color-scheme", AId::ColorScheme),
+        ("marker-mid", AId::MarkerMid),
+        ("cy", AId::Cy),
         ("xChannelSelector", AId::XChannelSelector),
-        ("unicode-range", AId::UnicodeRange),
-        ("y", AId::Y),
+        ("textLength", AId::TextLength),
+        ("maskUnits", AId::MaskUnits),
+        ("ry", AId::Ry),
+        ("fy", AId::Fy),
+        ("white-space", AId::WhiteSpace),
+        ("y2", AId::Y2),
         ("fill-opacity", AId::FillOpacity),
-        ("mask-composite", AId::MaskComposite),
-        ("targetY", AId::TargetY),
-        ("writing-mode", AId::WritingMode),
-        ("transform-origin", AId::TransformOrigin),
-        ("font-variant-caps", AId::FontVariantCaps),
-        ("k2", AId::K2),
-        ("transform-box", AId::TransformBox),
-        ("text-decoration", AId::TextDecoration),
-        ("filter", AId::Filter),
-        ("enable-background", AId::EnableBackground),
+        ("startOffset", AId::StartOffset),
+        ("paint-order", AId::PaintOrder),
+        ("font-variant", AId::FontVariant),
+        ("stroke-miterlimit", AId::StrokeMiterlimit),
+        ("font-synthesis", AId::FontSynthesis),
+        ("font-family", AId::FontFamily),
         ("word-spacing", AId::WordSpacing),
-        ("gradientUnits", AId::GradientUnits),
-        ("font-feature-settings", AId::FontFeatureSettings),
-        ("stroke-linecap", AId::StrokeLinecap),
-        ("rx", AId::Rx),
-        ("kernelUnitLength", AId::KernelUnitLength),
-        ("shape-image-threshold", AId::ShapeImageThreshold),
-        ("inline-size", AId::InlineSize),
-        ("edgeMode", AId::EdgeMode),
-        ("text-decoration-style", AId::TextDecorationStyle),
-        ("pointsAtZ", AId::PointsAtZ),
-        ("elevation", AId::Elevation),
-        ("tableValues", AId::TableValues),
-        ("kernelMatrix", AId::KernelMatrix),
-        ("patternTransform", AId::PatternTransform),
-        ("fill-rule", AId::FillRule),
-        ("color", AId::Color),
-        ("lighting-color", AId::LightingColor),
-        ("cy", AId::Cy),
-        ("fr", AId::Fr),
-        ("systemLanguage", AId::SystemLanguage),
-        ("text-indent", AId::TextIndent),
-        ("slope", AId::Slope),
-        ("font", AId::Font),
-        ("requiredExtensions", AId::RequiredExtensions),
-        ("href", AId::Href),
-        ("baseFrequency", AId::BaseFrequency),
-        ("stroke-dashoffset", AId::StrokeDashoffset),
-        ("text-decoration-stroke", AId::TextDecorationStroke),
-        ("display", AId::Display),
+        ("spreadMethod", AId::SpreadMethod),
         ("amplitude", AId::Amplitude),
-        ("mask-size", AId::MaskSize),
-        ("font-variant-ligatures", AId::FontVariantLigatures),
-        ("in2", AId::In2),
-        ("maskUnits", AId::MaskUnits),
-        ("k4", AId::K4),
         ("shape-padding", AId::ShapePadding),
-        ("r", AId::R),
-        ("text-underline-position", AId::TextUnderlinePosition),
-        ("font-variant-east-asian", AId::FontVariantEastAsian),
-        ("z", AId::Z),
-        ("text-decoration-color", AId::TextDecorationColor),
-        ("intercept", AId::Intercept),
-        ("side", AId::Side),
-        ("x2", AId::X2),
-        ("mask-position", AId::MaskPosition),
-        ("pointsAtX", AId::PointsAtX),
-        ("patternUnits", AId::PatternUnits),
-        ("result", AId::Result),
-        ("baseline-shift", AId::BaselineShift),
-        ("stop-color", AId::StopColor),
-        ("k3", AId::K3),
-        ("stroke-dasharray", AId::StrokeDasharray),
-        ("y1", AId::Y1),
         ("targetX", AId::TargetX),
-        ("stroke-width", AId::StrokeWidth),
-        ("limitingConeAngle", AId::LimitingConeAngle),
-        ("id", AId::Id),
-        ("mask-border-width", AId::MaskBorderWidth),
-        ("shape-subtract", AId::ShapeSubtract),
-        ("font-variant", AId::FontVariant),
-        ("glyph-orientation-vertical", AId::GlyphOrientationVertical),
-        ("offset", AId::Offset),
-        ("type", AId::Type),
-        ("numOctaves", AId::NumOctaves),
-        ("font-stretch", AId::FontStretch),
+        ("font-kerning", AId::FontKerning),
         ("mask-clip", AId::MaskClip),
-        ("clipPathUnits", AId::ClipPathUnits),
-        ("path", AId::Path),
-        ("seed", AId::Seed),
-        ("font-variant-position", AId::FontVariantPosition),
-        ("rotate", AId::Rotate),
-        ("text-overflow", AId::TextOverflow),
-        ("mask-border-repeat", AId::MaskBorderRepeat),
-        ("stroke-linejoin", AId::StrokeLinejoin),
-        ("mask-mode", AId::MaskMode),
-        ("specularConstant", AId::SpecularConstant),
-        ("spreadMethod", AId::SpreadMethod),
-        ("letter-spacing", AId::LetterSpacing),
-        ("font-family", AId::FontFamily),
-        ("azimuth", AId::Azimuth),
-        ("refX", AId::RefX),
-        ("color-rendering", AId::ColorRendering),
-        ("values", AId::Values),
-        ("marker-end", AId::MarkerEnd),
-        ("font-variant-numeric", AId::FontVariantNumeric),
-        ("markerHeight", AId::MarkerHeight),
+        ("font-size-adjust", AId::FontSizeAdjust),
+        ("stop-opacity", AId::StopOpacity),
+        ("k3", AId::K3),
+        ("systemLanguage", AId::SystemLanguage),
         ("radius", AId::Radius),
-        ("width", AId::Width),
-        ("text-align-last", AId::TextAlignLast),
-        ("preserveAspectRatio", AId::PreserveAspectRatio),
-        ("patternContentUnits", AId::PatternContentUnits),
-        ("k1", AId::K1),
-        ("paint-order", AId::PaintOrder),
-        ("d", AId::D),
-        ("mask-border-slice", AId::MaskBorderSlice),
-        ("dx", AId::Dx),
+        ("transform-box", AId::TransformBox),
+        ("color-interpolation-filters", AId::ColorInterpolationFilters),
+        ("text-decoration", AId::TextDecoration),
+        ("type", AId::Type),
+        ("direction", AId::Direction),
+        ("k4", AId::K4),
+        ("refY", AId::RefY),
         ("text-align", AId::TextAlign),
+        ("pointsAtZ", AId::PointsAtZ),
+        ("mask-border", AId::MaskBorder),
+        ("stdDeviation", AId::StdDeviation),
+        ("inline-size", AId::InlineSize),
+        ("fr", AId::Fr),
+        ("flood-opacity", AId::FloodOpacity),
+        ("fill", AId::Fill),
+        ("x2", AId::X2),
+        ("stroke-dashoffset", AId::StrokeDashoffset),
+        ("mask-image", AId::MaskImage),
+        ("specularExponent", AId::SpecularExponent),
+        ("scale", AId::Scale),
+        ("kernelUnitLength", AId::KernelUnitLength),
+        ("height", AId::Height),
+        ("intercept", AId::Intercept),
+        ("mask-border-source", AId::MaskBorderSource),
     ],
 };
 
@@ -6,16 +6,32 @@ use std::collections::HashMap;
 
 use roxmltree::Error;
 
-use super::{AId, Attribute, Document, EId, NodeData, NodeId, NodeKind, ShortRange};
+use super::{AId, Attribute, Document, EId, NodeData, NodeId, NodeKind, ShortRange, SvgNode};
+use crate::css::{self, Token};
 
 const SVG_NS: &str = "http://www.w3.org/2000/svg";
 const XLINK_NS: &str = "http://www.w3.org/1999/xlink";
 const XML_NAMESPACE_NS: &str = "http://www.w3.org/XML/1998/namespace";
+const XHTML_NS: &str = "http://www.w3.org/1999/xhtml";
 
 impl<'input> Document<'input> {
     /// Parses a [`Document`] from a [`roxmltree::Document`].
-    pub fn parse_tree(xml: &roxmltree::Document<'input>) -> Result<Document<'input>, Error> {
-        parse(xml)
+    ///
+    /// `href_rewrite` is applied to `xlink:href` of `image`, `use` and `feImage` elements.
+    /// Attributes unknown to `usvg` are collected only when `preserve_unknown_attributes` is set.
+    pub fn parse_tree(
+        xml: &roxmltree::Document<'input>,
+        href_rewrite: Option<crate::HrefRewriteFn>,
+        preserve_unknown_attributes: bool,
+    ) -> Result<Document<'input>, Error> {
+        parse(xml, href_rewrite, preserve_unknown_attributes)
+    }
+
+    fn rewrite_href<'a>(&self, href: &'a str) -> std::borrow::Cow<'a, str> {
+        match self.href_rewrite {
+            Some(ref f) => std::borrow::Cow::Owned(f(href)),
+            None => std::borrow::Cow::Borrowed(href),
+        }
     }
 
     pub(crate) fn append(&mut self, parent_id: NodeId, kind: NodeKind) -> NodeId {
@@ -49,11 +65,17 @@ impl<'input> Document<'input> {
     }
 }
 
-fn parse<'input>(xml: &roxmltree::Document<'input>) -> Result<Document<'input>, Error> {
+fn parse<'input>(
+    xml: &roxmltree::Document<'input>,
+    href_rewrite: Option<crate::HrefRewriteFn>,
+    preserve_unknown_attributes: bool,
+) -> Result<Document<'input>, Error> {
     let mut doc = Document {
         nodes: Vec::new(),
         attrs: Vec::new(),
         links: HashMap::new(),
+        href_rewrite,
+        unknown_attrs: preserve_unknown_attributes.then(HashMap::new),
     };
 
     // build a map of id -> node for resolve_href
@@ -74,7 +96,8 @@ fn parse<'input>(xml: &roxmltree::Document<'input>) -> Result<Document<'input>,
         kind: NodeKind::Root,
     });
 
-    let style_sheet = resolve_css(xml);
+    let css = collect_css(xml);
+    let style_sheet = resolve_css(&css);
 
     parse_xml_node_children(
         xml.root(),
@@ -106,11 +129,7 @@ fn parse<'input>(xml: &roxmltree::Document<'input>) -> Result<Document<'input>,
     }
     doc.links = links;
 
-    fix_recursive_patterns(&mut doc);
-    fix_recursive_links(EId::ClipPath, AId::ClipPath, &mut doc);
-    fix_recursive_links(EId::Mask, AId::Mask, &mut doc);
-    fix_recursive_links(EId::Filter, AId::Filter, &mut doc);
-    fix_recursive_fe_image(&mut doc);
+    fix_recursive_links(&mut doc);
 
     Ok(doc)
 }
@@ -169,7 +188,13 @@ fn parse_xml_node<'input>(
 
     let mut tag_name = match parse_tag_name(node) {
         Some(id) => id,
-        None => return Ok(()),
+        None => {
+            if node.has_tag_name((SVG_NS, "foreignObject")) {
+                parse_foreign_object(node, parent_id, style_sheet, ignore_ids, doc)?;
+            }
+
+            return Ok(());
+        }
     };
 
     if tag_name == EId::Style {
@@ -203,6 +228,72 @@ fn parse_xml_node<'input>(
     Ok(())
 }
 
+/// Converts a `foreignObject` filled with a CSS conic gradient into a `rect`.
+///
+/// This is how Figma exports angular gradients: a `div` with a `conic-gradient()` background
+/// which fills the whole `foreignObject`. Any other content is not supported and is skipped.
+fn parse_foreign_object<'input>(
+    node: roxmltree::Node<'_, 'input>,
+    parent_id: NodeId,
+    style_sheet: &simplecss::StyleSheet,
+    ignore_ids: bool,
+    doc: &mut Document<'input>,
+) -> Result<(), Error> {
+    let div = match node.children().find(|n| n.is_element()) {
+        Some(v) if v.has_tag_name((XHTML_NS, "div")) => v,
+        _ => return Ok(()),
+    };
+
+    // `simplecss` ends a value at the first `)`, which breaks nested functions like `rgba()`.
+    let mut background = None;
+    let mut opacity = None;
+    let style = div.attribute("style").unwrap_or("");
+    for declaration in css::split(style, ';') {
+        if let Some((name, value)) = declaration.split_once(':') {
+            match name.trim() {
+                "background" | "background-image" => background = Some(value.trim()),
+                "opacity" => opacity = Some(value.trim()),
+                _ => {}
+            }
+        }
+    }
+
+    let background = match background {
+        Some(v) if v.starts_with("conic-gradient(") => v,
+        _ => return Ok(()),
+    };
+
+    // Attributes like `transform` and `clip-path` are applied via a group,
+    // since the rect attributes cannot be set after the element was created.
+    let group_id = parse_svg_element(node, parent_id, EId::G, style_sheet, ignore_ids, doc)?;
+
+    let attrs_start_idx = doc.attrs.len();
+    for attr in node.attributes() {
+        let aid = match AId::from_str(attr.name()) {
+            Some(aid @ (AId::X | AId::Y | AId::Width | AId::Height)) => aid,
+            _ => continue,
+        };
+
+        if attr.namespace().is_none() {
+            doc.append_attribute(aid, attr.value_storage().clone());
+        }
+    }
+    doc.append_attribute(AId::Fill, roxmltree::StringStorage::new_owned(background));
+    if let Some(opacity) = opacity {
+        doc.append_attribute(AId::Opacity, roxmltree::StringStorage::new_owned(opacity));
+    }
+
+    doc.append(
+        group_id,
+        NodeKind::Element {
+            tag_name: EId::Rect,
+            attributes: ShortRange::new(attrs_start_idx as u32, doc.attrs.len() as u32),
+        },
+    );
+
+    Ok(())
+}
+
 pub(crate) fn parse_svg_element<'input>(
     xml_node: roxmltree::Node<'_, 'input>,
     parent_id: NodeId,
@@ -212,17 +303,26 @@ pub(crate) fn parse_svg_element<'input>(
     doc: &mut Document<'input>,
 ) -> Result<NodeId, Error> {
     let attrs_start_idx = doc.attrs.len();
+    let mut unknown_attrs = Vec::new();
 
     // Copy presentational attributes first.
     for attr in xml_node.attributes() {
-        match attr.namespace() {
-            None | Some(SVG_NS) | Some(XLINK_NS) | Some(XML_NAMESPACE_NS) => {}
-            _ => continue,
-        }
+        let aid = match attr.namespace() {
+            None | Some(SVG_NS) | Some(XLINK_NS) | Some(XML_NAMESPACE_NS) => {
+                AId::from_str(attr.name())
+            }
+            _ => None,
+        };
 
-        let aid = match AId::from_str(attr.name()) {
+        let aid = match aid {
             Some(v) => v,
-            None => continue,
+            None => {
+                if doc.unknown_attrs.is_some() {
+                    unknown_attrs.push(unknown_attribute(xml_node, &attr));
+                }
+
+                continue;
+            }
         };
 
         // During a `use` resolving, all `id` attributes must be ignored.
@@ -236,6 +336,14 @@ pub(crate) fn parse_svg_element<'input>(
             continue;
         }
 
+        if aid == AId::Href && matches!(tag_name, EId::Image | EId::Use | EId::FeImage) {
+            if let std::borrow::Cow::Owned(value) = doc.rewrite_href(attr.value()) {
+                let value = roxmltree::StringStorage::new_owned(value);
+                append_attribute(parent_id, tag_name, aid, value, doc);
+                continue;
+            }
+        }
+
         append_attribute(parent_id, tag_name, aid, attr.value_storage().clone(), doc);
     }
 
@@ -266,34 +374,67 @@ pub(crate) fn parse_svg_element<'input>(
         }
     };
 
-    // Apply CSS.
+    let mut insert_declaration = |declaration: &simplecss::Declaration| {
+        if declaration.name == "marker" {
+            // A shorthand. Can be overwritten by a subsequent longhand.
+            insert_attribute(AId::MarkerStart, declaration.value);
+            insert_attribute(AId::MarkerMid, declaration.value);
+            insert_attribute(AId::MarkerEnd, declaration.value);
+        } else if declaration.name == "font" {
+            if let Ok(shorthand) = svgtypes::FontShorthand::from_str(declaration.value) {
+                // Omitted values must be reset to their initial values.
+                insert_attribute(AId::FontStyle, shorthand.font_style.unwrap_or("normal"));
+                insert_attribute(AId::FontVariant, shorthand.font_variant.unwrap_or("normal"));
+                insert_attribute(AId::FontWeight, shorthand.font_weight.unwrap_or("normal"));
+                insert_attribute(AId::FontStretch, shorthand.font_stretch.unwrap_or("normal"));
+                insert_attribute(AId::FontKerning, "auto");
+                // Line height is ignored, since it has no effect in SVG.
+                insert_attribute(AId::FontSize, shorthand.font_size);
+                insert_attribute(AId::FontFamily, shorthand.font_family);
+            } else {
+                log::warn!("Failed to parse font value: '{}'.", declaration.value);
+            }
+        } else if declaration.name == "transform" {
+            // CSS transforms have a different grammar than the `transform` attribute.
+            if let Some(ts) = parse_css_transform(declaration.value) {
+                let value = format!(
+                    "matrix({} {} {} {} {} {})",
+                    ts.sx, ts.ky, ts.kx, ts.sy, ts.tx, ts.ty
+                );
+                insert_attribute(AId::Transform, &value);
+            } else {
+                log::warn!("Failed to parse transform value: '{}'.", declaration.value);
+            }
+        } else if let Some(aid) = AId::from_str(declaration.name) {
+            // TODO: perform XML attribute normalization
+            // Parse only the presentation attributes.
+            if aid.is_presentation() {
+                insert_attribute(aid, declaration.value);
+            }
+        }
+    };
+
+    // Collect CSS.
+    let mut css_declarations = Vec::new();
     for rule in &style_sheet.rules {
         if rule.selector.matches(&XmlNode(xml_node)) {
-            for declaration in &rule.declarations {
-                // TODO: perform XML attribute normalization
-                if let Some(aid) = AId::from_str(declaration.name) {
-                    // Parse only the presentation attributes.
-                    if aid.is_presentation() {
-                        insert_attribute(aid, declaration.value);
-                    }
-                } else if declaration.name == "marker" {
-                    insert_attribute(AId::MarkerStart, declaration.value);
-                    insert_attribute(AId::MarkerMid, declaration.value);
-                    insert_attribute(AId::MarkerEnd, declaration.value);
-                }
-            }
+            css_declarations.extend(rule.declarations.iter().cloned());
         }
     }
 
     // Split a `style` attribute.
-    if let Some(value) = xml_node.attribute("style") {
-        for declaration in simplecss::DeclarationTokenizer::from(value) {
-            // TODO: preform XML attribute normalization
-            if let Some(aid) = AId::from_str(declaration.name) {
-                // Parse only the presentation attributes.
-                if aid.is_presentation() {
-                    insert_attribute(aid, declaration.value);
-                }
+    let style_value = xml_node.attribute("style").map(strip_slashes);
+    let style_declarations: Vec<_> = match style_value {
+        Some(ref value) => simplecss::DeclarationTokenizer::from(value.as_ref()).collect(),
+        None => Vec::new(),
+    };
+
+    // Apply declarations in the cascade order:
+    // CSS < `style` < `!important` CSS < `!important` `style`.
+    for important in [false, true] {
+        for declarations in [&css_declarations, &style_declarations] {
+            for declaration in declarations.iter().filter(|d| d.important == important) {
+                insert_declaration(declaration);
             }
         }
     }
@@ -310,9 +451,34 @@ pub(crate) fn parse_svg_element<'input>(
         },
     );
 
+    if !unknown_attrs.is_empty() {
+        if let Some(ref mut attrs) = doc.unknown_attrs {
+            attrs.insert(node_id, unknown_attrs);
+        }
+    }
+
     Ok(node_id)
 }
 
+fn unknown_attribute(
+    xml_node: roxmltree::Node,
+    attr: &roxmltree::Attribute,
+) -> usvg_tree::UnknownAttribute {
+    let namespace = attr.namespace().unwrap_or_default();
+    let prefix = if namespace.is_empty() {
+        ""
+    } else {
+        xml_node.lookup_prefix(namespace).unwrap_or_default()
+    };
+
+    usvg_tree::UnknownAttribute {
+        namespace: namespace.to_string(),
+        prefix: prefix.to_string(),
+        name: attr.name().to_string(),
+        value: attr.value().to_string(),
+    }
+}
+
 fn append_attribute<'input>(
     parent_id: NodeId,
     tag_name: EId,
@@ -416,6 +582,7 @@ fn resolve_inherit(parent_id: NodeId, aid: AId, doc: &mut Document) -> bool {
         AId::ClipRule | AId::FillRule => "nonzero",
 
         AId::BaselineShift => "baseline",
+        AId::ColorInterpolation => "sRGB",
         AId::ColorInterpolationFilters => "linearRGB",
         AId::Direction => "ltr",
         AId::Display => "inline",
@@ -438,13 +605,15 @@ fn resolve_inherit(parent_id: NodeId, aid: AId, doc: &mut Document) -> bool {
 
 fn resolve_href<'a, 'input: 'a>(
     node: roxmltree::Node<'a, 'input>,
+    doc: &Document,
     id_map: &HashMap<&str, roxmltree::Node<'a, 'input>>,
 ) -> Option<roxmltree::Node<'a, 'input>> {
     let link_value = node
         .attribute((XLINK_NS, "href"))
         .or_else(|| node.attribute("href"))?;
+    let link_value = doc.rewrite_href(link_value);
 
-    let link_id = svgtypes::IRI::from_str(link_value).ok()?.0;
+    let link_id = svgtypes::IRI::from_str(&link_value).ok()?.0;
 
     id_map.get(link_id).copied()
 }
@@ -458,7 +627,7 @@ fn parse_svg_use_element<'input>(
     doc: &mut Document<'input>,
     id_map: &HashMap<&str, roxmltree::Node<'_, 'input>>,
 ) -> Result<(), Error> {
-    let link = match resolve_href(node, id_map) {
+    let link = match resolve_href(node, doc, id_map) {
         Some(v) => v,
         None => return Ok(()),
     };
@@ -466,7 +635,7 @@ fn parse_svg_use_element<'input>(
     if link == node || link == origin {
         log::warn!(
             "Recursive 'use' detected. '{}' will be skipped.",
-            node.attribute((SVG_NS, "id")).unwrap_or_default()
+            node.attribute("id").unwrap_or_default()
         );
         return Ok(());
     }
@@ -499,7 +668,7 @@ fn parse_svg_use_element<'input>(
         .skip(1)
         .filter(|n| n.has_tag_name((SVG_NS, "use")))
     {
-        if let Some(link2) = resolve_href(link_child, id_map) {
+        if let Some(link2) = resolve_href(link_child, doc, id_map) {
             if link2 == node || link2 == link {
                 is_recursive = true;
                 break;
@@ -507,10 +676,26 @@ fn parse_svg_use_element<'input>(
         }
     }
 
+    // Check that the linked element is not already instantiated by an ancestor `use`.
+    //
+    // Example:
+    // <g id="g1">
+    //     <use xlink:href="#g2" id="use1"/>
+    // </g>
+    // <g id="g2">
+    //     <use xlink:href="#g1" id="use2"/>
+    // </g>
+    // <use xlink:href="#g1" id="use3"/>
+    //
+    // `use2` should be skipped inside `use3`, since `g1` is already being instantiated.
+    if !is_recursive {
+        is_recursive = is_instantiated_by_ancestor(link, parent_id, doc);
+    }
+
     if is_recursive {
         log::warn!(
             "Recursive 'use' detected. '{}' will be skipped.",
-            node.attribute((SVG_NS, "id")).unwrap_or_default()
+            node.attribute("id").unwrap_or_default()
         );
         return Ok(());
     }
@@ -527,8 +712,23 @@ fn parse_svg_use_element<'input>(
     )
 }
 
-fn resolve_css<'a>(xml: &'a roxmltree::Document<'a>) -> simplecss::StyleSheet<'a> {
-    let mut sheet = simplecss::StyleSheet::new();
+fn is_instantiated_by_ancestor(link: roxmltree::Node, use_id: NodeId, doc: &Document) -> bool {
+    let link_id = match link.attribute("id") {
+        Some(v) => v,
+        None => return false,
+    };
+
+    doc.get(use_id)
+        .ancestors()
+        .skip(1)
+        .filter(|n| n.tag_name() == Some(EId::Use))
+        .filter_map(|n| n.attribute::<&str>(AId::Href))
+        .filter_map(|v| svgtypes::IRI::from_str(v).ok())
+        .any(|iri| iri.0 == link_id)
+}
+
+fn collect_css<'a>(xml: &'a roxmltree::Document) -> Vec<std::borrow::Cow<'a, str>> {
+    let mut css = Vec::new();
 
     for node in xml.descendants().filter(|n| n.has_tag_name("style")) {
         match node.attribute("type") {
@@ -542,12 +742,270 @@ fn resolve_css<'a>(xml: &'a roxmltree::Document<'a>) -> simplecss::StyleSheet<'a
             None => continue,
         };
 
+        let text = match strip_slashes(text) {
+            std::borrow::Cow::Borrowed(text) => replace_root_pseudo_class(text),
+            std::borrow::Cow::Owned(text) => {
+                std::borrow::Cow::Owned(replace_root_pseudo_class(&text).into_owned())
+            }
+        };
+        css.push(text);
+    }
+
+    css
+}
+
+/// `simplecss` doesn't support `:root`, so it is replaced with a `:lang` pseudo-class
+/// with this language, which matches only the root element.
+/// See `XmlNode::pseudo_class_matches`.
+const ROOT_LANG: &str = "-usvg-root";
+
+/// Replaces `:root` in selectors with `:lang(ROOT_LANG)`.
+fn replace_root_pseudo_class(text: &str) -> std::borrow::Cow<'_, str> {
+    if !text.contains(":root") {
+        return std::borrow::Cow::Borrowed(text);
+    }
+
+    let is_ident_char = |c: char| c.is_ascii_alphanumeric() || c == '-' || c == '_';
+
+    let mut result = String::with_capacity(text.len());
+    let mut depth = 0;
+    let mut start = 0;
+    for (range, token) in css::Tokenizer::new(text) {
+        match token {
+            Token::Char('{') => depth += 1,
+            Token::Char('}') => depth -= 1,
+            Token::Char(':') if depth == 0 && text[range.start..].starts_with(":root") => {
+                let end = range.start + ":root".len();
+                if !text[end..].starts_with(is_ident_char) {
+                    result.push_str(&text[start..range.start]);
+                    result.push_str(":lang(");
+                    result.push_str(ROOT_LANG);
+                    result.push(')');
+                    start = end;
+                }
+            }
+            _ => {}
+        }
+    }
+    result.push_str(&text[start..]);
+
+    std::borrow::Cow::Owned(result)
+}
+
+fn resolve_css<'a>(css: &'a [std::borrow::Cow<'_, str>]) -> simplecss::StyleSheet<'a> {
+    let mut sheet = simplecss::StyleSheet::new();
+    for text in css {
         sheet.parse_more(text);
     }
 
     sheet
 }
 
+/// Parses the CSS `transform` property.
+///
+/// Unlike the `transform` attribute, functions arguments must be separated by commas,
+/// angles and lengths must have units and `rotate` doesn't accept a center point.
+fn parse_css_transform(value: &str) -> Option<usvg_tree::Transform> {
+    use usvg_tree::Transform;
+
+    let mut ts = Transform::default();
+    let mut text = value.trim();
+    if text == "none" {
+        return Some(ts);
+    }
+
+    while !text.is_empty() {
+        let (name, tail) = text.split_once('(')?;
+        let (args, tail) = tail.split_once(')')?;
+        text = tail.trim_start();
+
+        let args: Vec<&str> = args.split(',').map(|s| s.trim()).collect();
+        let f = match (name, args.as_slice()) {
+            ("matrix", [a, b, c, d, e, f]) => Transform::from_row(
+                parse_css_number(a)?,
+                parse_css_number(b)?,
+                parse_css_number(c)?,
+                parse_css_number(d)?,
+                parse_css_number(e)?,
+                parse_css_number(f)?,
+            ),
+            ("translate", [x]) => Transform::from_translate(parse_css_length(x)?, 0.0),
+            ("translate", [x, y]) => {
+                Transform::from_translate(parse_css_length(x)?, parse_css_length(y)?)
+            }
+            ("translateX", [x]) => Transform::from_translate(parse_css_length(x)?, 0.0),
+            ("translateY", [y]) => Transform::from_translate(0.0, parse_css_length(y)?),
+            ("scale", [s]) => Transform::from_scale(parse_css_number(s)?, parse_css_number(s)?),
+            ("scale", [sx, sy]) => {
+                Transform::from_scale(parse_css_number(sx)?, parse_css_number(sy)?)
+            }
+            ("scaleX", [sx]) => Transform::from_scale(parse_css_number(sx)?, 1.0),
+            ("scaleY", [sy]) => Transform::from_scale(1.0, parse_css_number(sy)?),
+            ("rotate", [a]) => Transform::from_rotate(parse_css_angle(a)?),
+            ("skew", [ax]) => Transform::from_row(
+                1.0,
+                0.0,
+                parse_css_angle(ax)?.to_radians().tan(),
+                1.0,
+                0.0,
+                0.0,
+            ),
+            ("skew", [ax, ay]) => Transform::from_row(
+                1.0,
+                parse_css_angle(ay)?.to_radians().tan(),
+                parse_css_angle(ax)?.to_radians().tan(),
+                1.0,
+                0.0,
+                0.0,
+            ),
+            ("skewX", [a]) => Transform::from_row(
+                1.0,
+                0.0,
+                parse_css_angle(a)?.to_radians().tan(),
+                1.0,
+                0.0,
+                0.0,
+            ),
+            ("skewY", [a]) => Transform::from_row(
+                1.0,
+                parse_css_angle(a)?.to_radians().tan(),
+                0.0,
+                1.0,
+                0.0,
+                0.0,
+            ),
+            _ => return None,
+        };
+
+        ts = ts.pre_concat(f);
+    }
+
+    Some(ts)
+}
+
+fn parse_css_number(text: &str) -> Option<f32> {
+    let n: svgtypes::Number = text.parse().ok()?;
+    Some(n.0 as f32)
+}
+
+/// Parses a length in pixels. Only zero can be unitless.
+pub(crate) fn parse_css_length(text: &str) -> Option<f32> {
+    let length: svgtypes::Length = text.parse().ok()?;
+    match length.unit {
+        svgtypes::LengthUnit::Px => Some(length.number as f32),
+        svgtypes::LengthUnit::None if length.number == 0.0 => Some(0.0),
+        _ => None,
+    }
+}
+
+/// Parses an angle in degrees. Only zero can be unitless.
+pub(crate) fn parse_css_angle(text: &str) -> Option<f32> {
+    let angle: svgtypes::Angle = text.parse().ok()?;
+    let has_unit = text.ends_with(|c: char| c.is_ascii_alphabetic());
+    if !has_unit && angle.number != 0.0 {
+        return None;
+    }
+
+    Some(angle.to_degrees() as f32)
+}
+
+/// Removes values with a `/` from declarations, since `simplecss` cannot parse them
+/// and would silently truncate the value at the `/`.
+///
+/// The line height part of `font` shorthands, like `12px/1.4`, is removed,
+/// since it has no effect in SVG. Other declarations with a `/` are invalid
+/// and are removed completely.
+fn strip_slashes(text: &str) -> std::borrow::Cow<'_, str> {
+    if !text.contains('/') {
+        return std::borrow::Cow::Borrowed(text);
+    }
+
+    let mut removed = Vec::new();
+    let mut parens = 0;
+    // Where the current declaration name starts, if we are before a `:`.
+    let mut name_start = Some(0);
+    let mut decl_start = 0;
+    let mut is_font = false;
+    let mut is_invalid = false;
+    // Where the `font` line height starts and whether its value was reached.
+    let mut line_height: Option<(usize, bool)> = None;
+    for (range, token) in css::Tokenizer::new(text) {
+        if let Some((start, has_value)) = line_height {
+            match token {
+                Token::Char(c) if c.is_ascii_whitespace() && !has_value => continue,
+                Token::Char(c) if !c.is_ascii_whitespace() && !matches!(c, ';' | '}' | '!') => {
+                    line_height = Some((start, true));
+                    continue;
+                }
+                Token::Escape => {
+                    line_height = Some((start, true));
+                    continue;
+                }
+                _ => {
+                    removed.push(start..range.start);
+                    line_height = None;
+                }
+            }
+        }
+
+        match token {
+            Token::Comment => {
+                // Skip comments before a declaration name.
+                if let Some(n) = name_start {
+                    if text[n..range.start].trim().is_empty() {
+                        name_start = Some(range.end);
+                    }
+                }
+            }
+            Token::Char('(') => parens += 1,
+            Token::Char(')') => parens -= 1,
+            Token::Char(c @ ('{' | '}' | ';')) if parens <= 0 => {
+                // `{` ends a selector and not a declaration.
+                if is_invalid && c != '{' {
+                    removed.push(decl_start..range.start);
+                }
+
+                is_font = false;
+                is_invalid = false;
+                parens = 0;
+                name_start = Some(range.end);
+                decl_start = range.end;
+            }
+            Token::Char(':') => {
+                if let Some(n) = name_start.take() {
+                    is_font = text[n..range.start].trim() == "font";
+                }
+            }
+            Token::Char('/') if parens <= 0 && name_start.is_none() => {
+                if is_font {
+                    line_height = Some((range.start, false));
+                } else {
+                    is_invalid = true;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    if let Some((start, _)) = line_height {
+        removed.push(start..text.len());
+    }
+
+    if is_invalid {
+        removed.push(decl_start..text.len());
+    }
+
+    let mut result = String::with_capacity(text.len());
+    let mut start = 0;
+    for range in removed {
+        result.push_str(&text[start..range.start]);
+        start = range.end;
+    }
+    result.push_str(&text[start..]);
+
+    std::borrow::Cow::Owned(result)
+}
+
 struct XmlNode<'a, 'input: 'a>(roxmltree::Node<'a, 'input>);
 
 impl simplecss::Element for XmlNode<'_, '_> {
@@ -573,60 +1031,81 @@ impl simplecss::Element for XmlNode<'_, '_> {
     fn pseudo_class_matches(&self, class: simplecss::PseudoClass) -> bool {
         match class {
             simplecss::PseudoClass::FirstChild => self.prev_sibling_element().is_none(),
+            simplecss::PseudoClass::Lang(ROOT_LANG) => self.0.parent_element().is_none(),
             // TODO: lang
             _ => false, // Since we are querying a static SVG we can ignore other pseudo-classes.
         }
     }
 }
 
-fn fix_recursive_patterns(doc: &mut Document) {
-    while let Some(node_id) = find_recursive_pattern(AId::Fill, doc) {
-        let idx = doc.get(node_id).attribute_id(AId::Fill).unwrap();
-        doc.attrs[idx].value = roxmltree::StringStorage::Borrowed("none");
-    }
-
-    while let Some(node_id) = find_recursive_pattern(AId::Stroke, doc) {
-        let idx = doc.get(node_id).attribute_id(AId::Stroke).unwrap();
-        doc.attrs[idx].value = roxmltree::StringStorage::Borrowed("none");
+/// Breaks reference cycles between patterns, clip paths, masks and filters.
+///
+/// Recursive markers are handled during conversion.
+///
+/// Detects cases like:
+///
+/// ```xml
+/// <pattern id="patt1">
+///   <rect fill="url(#patt2)"/>
+/// </pattern>
+/// <pattern id="patt2">
+///   <rect fill="url(#patt1)"/>
+/// </pattern>
+/// ```
+///
+/// or
+///
+/// ```xml
+/// <filter id="filter1">
+///   <feImage xlink:href="#rect1"/>
+/// </filter>
+/// <rect id="rect1" x="36" y="36" width="120" height="120" fill="green" filter="url(#filter1)"/>
+/// ```
+///
+/// The reference that closes the cycle is replaced with `none`.
+/// Otherwise we will get endless loop/recursion and stack overflow.
+fn fix_recursive_links(doc: &mut Document) {
+    let resources: Vec<NodeId> = doc
+        .root()
+        .descendants()
+        .filter(|n| {
+            matches!(
+                n.tag_name(),
+                Some(EId::Pattern) | Some(EId::ClipPath) | Some(EId::Mask) | Some(EId::Filter)
+            )
+        })
+        .map(|n| n.id)
+        .collect();
+
+    for resource_id in resources {
+        while let Some((node_id, aid)) = find_recursive_link(doc.get(resource_id)) {
+            log::warn!(
+                "Recursive '{}' reference to '{}' detected. The reference will be removed.",
+                aid,
+                doc.get(resource_id).element_id()
+            );
+
+            let idx = doc.get(node_id).attribute_id(aid).unwrap();
+            doc.attrs[idx].value = roxmltree::StringStorage::Borrowed("none");
+        }
     }
 }
 
-fn find_recursive_pattern(aid: AId, doc: &mut Document) -> Option<NodeId> {
-    for pattern_node in doc
-        .root()
-        .descendants()
-        .filter(|n| n.tag_name() == Some(EId::Pattern))
-    {
-        for node in pattern_node.descendants() {
-            let value = match node.attribute(aid) {
-                Some(v) => v,
-                None => continue,
-            };
+fn find_recursive_link(resource: SvgNode) -> Option<(NodeId, AId)> {
+    // Follow links transitively, since a cycle can be arbitrary long,
+    // like `clip1 -> clip2 -> clip3 -> clip1`.
+    let mut visited = vec![resource.id];
+    let mut queue = vec![resource];
+    while let Some(element) = queue.pop() {
+        for child in element.descendants() {
+            for (aid, link) in rendering_links(child) {
+                if link == resource {
+                    return Some((child.id, aid));
+                }
 
-            if let Ok(svgtypes::Paint::FuncIRI(link_id, _)) = svgtypes::Paint::from_str(value) {
-                if link_id == pattern_node.element_id() {
-                    // If a pattern child has a link to the pattern itself
-                    // then we have to replace it with `none`.
-                    // Otherwise we will get endless loop/recursion and stack overflow.
-                    return Some(node.id);
-                } else {
-                    // Check that linked node children doesn't link this pattern.
-                    if let Some(linked_node) = doc.element_by_id(link_id) {
-                        for node2 in linked_node.descendants() {
-                            let value2 = match node2.attribute(aid) {
-                                Some(v) => v,
-                                None => continue,
-                            };
-
-                            if let Ok(svgtypes::Paint::FuncIRI(link_id2, _)) =
-                                svgtypes::Paint::from_str(value2)
-                            {
-                                if link_id2 == pattern_node.element_id() {
-                                    return Some(node2.id);
-                                }
-                            }
-                        }
-                    }
+                if !visited.contains(&link.id) {
+                    visited.push(link.id);
+                    queue.push(link);
                 }
             }
         }
@@ -635,74 +1114,43 @@ fn find_recursive_pattern(aid: AId, doc: &mut Document) -> Option<NodeId> {
     None
 }
 
-fn fix_recursive_links(eid: EId, aid: AId, doc: &mut Document) {
-    while let Some(node_id) = find_recursive_link(eid, aid, doc) {
-        let idx = doc.get(node_id).attribute_id(aid).unwrap();
-        doc.attrs[idx].value = roxmltree::StringStorage::Borrowed("none");
-    }
-}
+/// Returns elements referenced by the node, which are rendered as part of it.
+fn rendering_links<'a, 'input: 'a>(node: SvgNode<'a, 'input>) -> Vec<(AId, SvgNode<'a, 'input>)> {
+    let doc = node.document();
+    let mut links = Vec::new();
 
-fn find_recursive_link(eid: EId, aid: AId, doc: &Document) -> Option<NodeId> {
-    for node in doc
-        .root()
-        .descendants()
-        .filter(|n| n.tag_name() == Some(eid))
-    {
-        for child in node.descendants() {
-            if let Some(link) = child.node_attribute(aid) {
-                if link == node {
-                    // If an element child has a link to the element itself
-                    // then we have to replace it with `none`.
-                    // Otherwise we will get endless loop/recursion and stack overflow.
-                    return Some(child.id);
-                } else {
-                    // Check that linked node children doesn't link this element.
-                    for node2 in link.descendants() {
-                        if let Some(link2) = node2.node_attribute(aid) {
-                            if link2 == node {
-                                return Some(node2.id);
-                            }
-                        }
-                    }
-                }
+    for aid in [AId::Fill, AId::Stroke] {
+        if let Some(value) = node.attribute::<&str>(aid) {
+            if let Ok(svgtypes::Paint::FuncIRI(link_id, _)) = svgtypes::Paint::from_str(value) {
+                links.extend(doc.element_by_id(link_id).map(|n| (aid, n)));
             }
         }
     }
 
-    None
-}
+    for aid in [
+        AId::ClipPath,
+        AId::Mask,
+        AId::MarkerStart,
+        AId::MarkerMid,
+        AId::MarkerEnd,
+    ] {
+        links.extend(node.node_attribute(aid).map(|n| (aid, n)));
+    }
 
-/// Detects cases like:
-///
-/// ```xml
-/// <filter id="filter1">
-///   <feImage xlink:href="#rect1"/>
-/// </filter>
-/// <rect id="rect1" x="36" y="36" width="120" height="120" fill="green" filter="url(#filter1)"/>
-/// ```
-fn fix_recursive_fe_image(doc: &mut Document) {
-    let mut ids = Vec::new();
-    for fe_node in doc
-        .root()
-        .descendants()
-        .filter(|n| n.tag_name() == Some(EId::FeImage))
-    {
-        if let Some(link) = fe_node.node_attribute(AId::Href) {
-            if let Some(filter_uri) = link.attribute::<&str>(AId::Filter) {
-                let filter_id = fe_node.parent().unwrap().element_id();
-                for func in svgtypes::FilterValueListParser::from(filter_uri).flatten() {
-                    if let svgtypes::FilterValue::Url(url) = func {
-                        if url == filter_id {
-                            ids.push(link.id);
-                        }
-                    }
-                }
+    if let Some(value) = node.attribute::<&str>(AId::Filter) {
+        for func in svgtypes::FilterValueListParser::from(value).flatten() {
+            if let svgtypes::FilterValue::Url(link_id) = func {
+                links.extend(doc.element_by_id(link_id).map(|n| (AId::Filter, n)));
             }
         }
     }
 
-    for id in ids {
-        let idx = doc.get(id).attribute_id(AId::Filter).unwrap();
-        doc.attrs[idx].value = roxmltree::StringStorage::Borrowed("none");
+    // Patterns can inherit children via `xlink:href`
+    // and `feImage` renders the referenced element.
+    // `use` elements are already expanded, so their links can be ignored.
+    if matches!(node.tag_name(), Some(EId::Pattern) | Some(EId::FeImage)) {
+        links.extend(node.node_attribute(AId::Href).map(|n| (AId::Href, n)));
     }
+
+    links
 }
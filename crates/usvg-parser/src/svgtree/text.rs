@@ -18,14 +18,10 @@ pub(crate) fn parse_svg_text_element<'input>(
 ) -> Result<(), Error> {
     debug_assert_eq!(parent.tag_name().name(), "text");
 
-    let space = if doc.get(parent_id).has_attribute(AId::Space) {
+    let space = if has_xmlspace(doc.get(parent_id)) {
         get_xmlspace(doc, parent_id, XmlSpace::Default)
     } else {
-        if let Some(node) = doc
-            .get(parent_id)
-            .ancestors()
-            .find(|n| n.has_attribute(AId::Space))
-        {
+        if let Some(node) = doc.get(parent_id).ancestors().find(|n| has_xmlspace(*n)) {
             get_xmlspace(doc, node.id, XmlSpace::Default)
         } else {
             XmlSpace::Default
@@ -135,8 +131,22 @@ enum XmlSpace {
     Preserve,
 }
 
+fn has_xmlspace(node: SvgNode) -> bool {
+    node.has_attribute(AId::Space) || node.has_attribute(AId::WhiteSpace)
+}
+
 fn get_xmlspace(doc: &Document, node_id: NodeId, default: XmlSpace) -> XmlSpace {
-    match doc.get(node_id).attribute(AId::Space) {
+    let node = doc.get(node_id);
+
+    // The CSS `white-space` property takes precedence over `xml:space`.
+    // Since we do not support line wrapping, only spaces collapsing matters.
+    match node.attribute(AId::WhiteSpace) {
+        Some("pre") | Some("pre-wrap") | Some("break-spaces") => return XmlSpace::Preserve,
+        Some("normal") | Some("nowrap") | Some("pre-line") => return XmlSpace::Default,
+        _ => {}
+    }
+
+    match node.attribute(AId::Space) {
         Some("preserve") => XmlSpace::Preserve,
         Some(_) => XmlSpace::Default,
         _ => default,
@@ -2,6 +2,7 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
+use std::convert::TryFrom;
 use std::rc::Rc;
 
 use kurbo::{ParamCurve, ParamCurveArclen};
@@ -9,7 +10,7 @@ use svgtypes::{parse_font_families, FontFamily, Length, LengthUnit};
 use usvg_tree::*;
 
 use crate::svgtree::{AId, EId, FromValue, SvgNode};
-use crate::{converter, style, OptionLog};
+use crate::{converter, style, OptionLog, Options};
 
 impl<'a, 'input: 'a> FromValue<'a, 'input> for usvg_tree::TextAnchor {
     fn parse(_: SvgNode, _: AId, value: &str) -> Option<Self> {
@@ -93,6 +94,11 @@ pub(crate) fn convert(
     let rotate_list = resolve_rotate_list(text_node);
     let writing_mode = convert_writing_mode(text_node);
 
+    // `auto` and invalid values disable wrapping.
+    let inline_size = text_node
+        .try_convert_length(AId::InlineSize, Units::UserSpaceOnUse, state)
+        .filter(|n| n.is_finite() && *n > 0.0);
+
     let chunks = collect_text_chunks(text_node, &pos_list, state, cache);
 
     let rendering_mode: TextRendering = text_node
@@ -113,6 +119,7 @@ pub(crate) fn convert(
         positions: pos_list,
         rotate: rotate_list,
         writing_mode,
+        inline_size,
         chunks,
     };
     parent.append_kind(NodeKind::Text(text));
@@ -251,8 +258,9 @@ fn collect_text_chunks_impl(
             paint_order,
             font,
             font_size,
-            small_caps: parent.find_attribute::<&str>(AId::FontVariant) == Some("small-caps"),
+            small_caps: resolve_small_caps(parent),
             apply_kerning,
+            font_features: resolve_font_features(parent),
             decoration: resolve_decoration(parent, state, cache),
             visibility: parent.find_attribute(AId::Visibility).unwrap_or_default(),
             dominant_baseline,
@@ -387,13 +395,28 @@ fn convert_font(node: SvgNode, state: &converter::State) -> Font {
     }
 
     Font {
-        families: families.into_iter().map(|f| f.to_string()).collect(),
+        families: families
+            .into_iter()
+            .map(|f| match f {
+                // `Display` would quote the name, which will break font matching.
+                FontFamily::Named(name) => substitute_font_family(name, state.opt),
+                _ => f.to_string(),
+            })
+            .collect(),
         style,
         stretch,
         weight,
     }
 }
 
+fn substitute_font_family(name: String, opt: &Options) -> String {
+    opt.font_substitutions
+        .iter()
+        .find(|(from, _)| from.eq_ignore_ascii_case(&name))
+        .map(|(_, to)| to.clone())
+        .unwrap_or(name)
+}
+
 // TODO: properly resolve narrower/wider
 fn conv_font_stretch(node: SvgNode) -> FontStretch {
     if let Some(n) = node.ancestors().find(|n| n.has_attribute(AId::FontStretch)) {
@@ -413,6 +436,114 @@ fn conv_font_stretch(node: SvgNode) -> FontStretch {
     }
 }
 
+fn resolve_small_caps(node: SvgNode) -> bool {
+    match node.find_attribute::<&str>(AId::FontVariant) {
+        Some(value) => value.split_ascii_whitespace().any(|s| s == "small-caps"),
+        None => false,
+    }
+}
+
+// Resolves OpenType features set by `font-variant` and `font-feature-settings`.
+//
+// `small-caps` is handled by `resolve_small_caps`.
+fn resolve_font_features(node: SvgNode) -> Vec<FontFeature> {
+    let mut features = Vec::new();
+
+    if let Some(value) = node.find_attribute::<&str>(AId::FontVariant) {
+        for name in value.split_ascii_whitespace() {
+            let list: &[(&[u8; 4], u32)] = match name {
+                "none" => &[
+                    (b"liga", 0),
+                    (b"clig", 0),
+                    (b"dlig", 0),
+                    (b"hlig", 0),
+                    (b"calt", 0),
+                ],
+                "common-ligatures" => &[(b"liga", 1), (b"clig", 1)],
+                "no-common-ligatures" => &[(b"liga", 0), (b"clig", 0)],
+                "discretionary-ligatures" => &[(b"dlig", 1)],
+                "no-discretionary-ligatures" => &[(b"dlig", 0)],
+                "historical-ligatures" => &[(b"hlig", 1)],
+                "no-historical-ligatures" => &[(b"hlig", 0)],
+                "contextual" => &[(b"calt", 1)],
+                "no-contextual" => &[(b"calt", 0)],
+                "all-small-caps" => &[(b"smcp", 1), (b"c2sc", 1)],
+                "petite-caps" => &[(b"pcap", 1)],
+                "all-petite-caps" => &[(b"pcap", 1), (b"c2pc", 1)],
+                "unicase" => &[(b"unic", 1)],
+                "titling-caps" => &[(b"titl", 1)],
+                "lining-nums" => &[(b"lnum", 1)],
+                "oldstyle-nums" => &[(b"onum", 1)],
+                "proportional-nums" => &[(b"pnum", 1)],
+                "tabular-nums" => &[(b"tnum", 1)],
+                "diagonal-fractions" => &[(b"frac", 1)],
+                "stacked-fractions" => &[(b"afrc", 1)],
+                "ordinal" => &[(b"ordn", 1)],
+                "slashed-zero" => &[(b"zero", 1)],
+                "sub" => &[(b"subs", 1)],
+                "super" => &[(b"sups", 1)],
+                _ => &[],
+            };
+
+            features.extend(list.iter().map(|(tag, value)| FontFeature {
+                tag: **tag,
+                value: *value,
+            }));
+        }
+    }
+
+    // Low-level settings must be applied last.
+    if let Some(value) = node.find_attribute::<&str>(AId::FontFeatureSettings) {
+        match parse_font_feature_settings(value) {
+            Some(list) => features.extend(list),
+            None => log::warn!("Failed to parse font-feature-settings value: '{}'.", value),
+        }
+    }
+
+    features
+}
+
+// Parses `font-feature-settings`, like `"liga" 0, "tnum"`.
+//
+// Returns `None` when any of the features is invalid,
+// since the whole declaration must be ignored in this case.
+fn parse_font_feature_settings(text: &str) -> Option<Vec<FontFeature>> {
+    let mut features = Vec::new();
+    if text.trim() == "normal" {
+        return Some(features);
+    }
+
+    for item in text.split(',') {
+        let mut iter = item.split_ascii_whitespace();
+
+        let tag = iter.next()?;
+        let tag = tag
+            .strip_prefix('"')
+            .and_then(|s| s.strip_suffix('"'))
+            .or_else(|| tag.strip_prefix('\'').and_then(|s| s.strip_suffix('\'')))?;
+
+        // A tag must consist of exactly four printable ASCII characters.
+        let tag = <[u8; 4]>::try_from(tag.as_bytes()).ok()?;
+        if !tag.iter().all(|c| (0x20..=0x7E).contains(c)) {
+            return None;
+        }
+
+        let value = match iter.next() {
+            None | Some("on") => 1,
+            Some("off") => 0,
+            Some(n) => n.parse().ok()?,
+        };
+
+        if iter.next().is_some() {
+            return None;
+        }
+
+        features.push(FontFeature { tag, value });
+    }
+
+    Some(features)
+}
+
 fn resolve_font_weight(node: SvgNode) -> u16 {
     fn bound(min: usize, val: usize, max: usize) -> usize {
         std::cmp::max(min, std::cmp::min(max, val))
@@ -16,17 +16,14 @@ pub(crate) fn convert_length(
     object_units: Units,
     state: &converter::State,
 ) -> f32 {
-    let dpi = state.opt.dpi;
     let n = length.number as f32;
     match length.unit {
         Unit::None | Unit::Px => n,
         Unit::Em => n * resolve_font_size(node, state),
         Unit::Ex => n * resolve_font_size(node, state) / 2.0,
-        Unit::In => n * dpi,
-        Unit::Cm => n * dpi / 2.54,
-        Unit::Mm => n * dpi / 25.4,
-        Unit::Pt => n * dpi / 72.0,
-        Unit::Pc => n * dpi / 6.0,
+        Unit::In | Unit::Cm | Unit::Mm | Unit::Pt | Unit::Pc => {
+            convert_physical_length(length, state.opt.dpi)
+        }
         Unit::Percent => {
             if object_units == Units::ObjectBoundingBox {
                 n / 100.0
@@ -79,7 +76,12 @@ pub(crate) fn convert_list(node: SvgNode, aid: AId, state: &converter::State) ->
     if let Some(text) = node.attribute::<&str>(aid) {
         let mut num_list = Vec::new();
         for length in svgtypes::LengthListParser::from(text).flatten() {
-            num_list.push(convert_user_length(length, node, aid, state));
+            let n = convert_user_length(length, node, aid, state);
+            if !n.is_finite() {
+                return None;
+            }
+
+            num_list.push(n);
         }
 
         Some(num_list)
@@ -88,6 +90,35 @@ pub(crate) fn convert_list(node: SvgNode, aid: AId, state: &converter::State) ->
     }
 }
 
+/// Converts a length in physical units into user units (pixels) using the specified DPI.
+///
+/// The conversion factors are:
+///
+/// - `1in` = `dpi` pixels
+/// - `1cm` = `dpi / 2.54` pixels
+/// - `1mm` = `dpi / 25.4` pixels
+/// - `1pt` = `dpi / 72` pixels
+/// - `1pc` = `dpi / 6` pixels
+///
+/// So at the default 96 DPI, `10mm` is `37.795` pixels, and at 300 DPI it's `118.11` pixels.
+///
+/// The math is done in `f64`, so the result is as close to the exact value as `f32` allows.
+/// Other units are returned as is.
+pub(crate) fn convert_physical_length(length: Length, dpi: f32) -> f32 {
+    let n = length.number;
+    let dpi = dpi as f64;
+    let px = match length.unit {
+        Unit::In => n * dpi,
+        Unit::Cm => n * dpi / 2.54,
+        Unit::Mm => n * dpi / 25.4,
+        Unit::Pt => n * dpi / 72.0,
+        Unit::Pc => n * dpi / 6.0,
+        _ => n,
+    };
+
+    px as f32
+}
+
 fn convert_percent(length: Length, base: f32) -> f32 {
     base * (length.number as f32) / 100.0
 }
@@ -110,11 +141,9 @@ pub(crate) fn resolve_font_size(node: SvgNode, state: &converter::State) -> f32
                 Unit::None | Unit::Px => n,
                 Unit::Em => n * font_size,
                 Unit::Ex => n * font_size / 2.0,
-                Unit::In => n * dpi,
-                Unit::Cm => n * dpi / 2.54,
-                Unit::Mm => n * dpi / 25.4,
-                Unit::Pt => n * dpi / 72.0,
-                Unit::Pc => n * dpi / 6.0,
+                Unit::In | Unit::Cm | Unit::Mm | Unit::Pt | Unit::Pc => {
+                    convert_physical_length(length, dpi)
+                }
                 Unit::Percent => {
                     // If `font-size` has percent units that it's value
                     // is relative to the parent node `font-size`.
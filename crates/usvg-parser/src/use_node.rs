@@ -12,6 +12,8 @@ use usvg_tree::{
 
 use crate::converter;
 use crate::svgtree::{AId, EId, SvgNode};
+use crate::units;
+use crate::OptionLog;
 
 pub(crate) fn convert(
     node: SvgNode,
@@ -50,15 +52,20 @@ pub(crate) fn convert(
 
             // Make group for `use`.
             let mut parent = match converter::convert_group(node, state, true, cache, &mut g) {
-                converter::GroupKind::Create(g) => {
+                converter::GroupKind::Create(use_g) => {
                     // We must reset transform, because it was already set
                     // to the group with clip-path.
-                    if let NodeKind::Group(ref mut g) = *g.borrow_mut() {
-                        g.id = String::new(); // Prevent ID duplication.
-                        g.transform = Transform::default();
+                    if let NodeKind::Group(ref mut use_g) = *use_g.borrow_mut() {
+                        use_g.id = String::new(); // Prevent ID duplication.
+                        use_g.transform = Transform::default();
+
+                        // The group with clip-path represents the `use` element now.
+                        if let NodeKind::Group(ref mut g) = *g.borrow_mut() {
+                            g.use_source = use_g.use_source.take();
+                        }
                     }
 
-                    g
+                    use_g
                 }
                 converter::GroupKind::Skip => g.clone(),
                 converter::GroupKind::Ignore => return None,
@@ -177,7 +184,7 @@ pub(crate) fn convert_svg(
     }
 }
 
-fn clip_element(
+pub(crate) fn clip_element(
     node: SvgNode,
     clip_rect: NonZeroRect,
     transform: Transform,
@@ -204,13 +211,7 @@ fn clip_element(
     //   <elem/>
     // </g>
 
-    let clip_path = usvg_tree::ClipPath::default();
-
-    let mut path = Path::new(Rc::new(tiny_skia_path::PathBuilder::from_rect(
-        clip_rect.to_rect(),
-    )));
-    path.fill = Some(usvg_tree::Fill::default());
-    clip_path.root.append_kind(NodeKind::Path(path));
+    let clip_path = rect_clip_path(clip_rect);
 
     // Nodes generated by markers must not have an ID. Otherwise we would have duplicates.
     let id = if state.parent_markers.is_empty() {
@@ -222,11 +223,24 @@ fn clip_element(
     parent.append_kind(NodeKind::Group(Group {
         id,
         transform,
-        clip_path: Some(Rc::new(clip_path)),
+        clip_path: Some(clip_path),
         ..Group::default()
     }))
 }
 
+/// Creates a clip path with a single rectangle.
+pub(crate) fn rect_clip_path(rect: NonZeroRect) -> Rc<usvg_tree::ClipPath> {
+    let clip_path = usvg_tree::ClipPath::default();
+
+    let mut path = Path::new(Rc::new(tiny_skia_path::PathBuilder::from_rect(
+        rect.to_rect(),
+    )));
+    path.fill = Some(usvg_tree::Fill::default());
+    clip_path.root.append_kind(NodeKind::Path(path));
+
+    Rc::new(clip_path)
+}
+
 fn convert_children(
     node: SvgNode,
     transform: Transform,
@@ -260,6 +274,7 @@ fn get_clip_rect(
     state: &converter::State,
 ) -> Option<NonZeroRect> {
     // No need to clip elements with overflow:visible.
+    // Just like with the root `svg` element, the deprecated `clip` property is ignored as well.
     if matches!(
         symbol_node.attribute(AId::Overflow),
         Some("visible") | Some("auto")
@@ -267,6 +282,19 @@ fn get_clip_rect(
         return None;
     }
 
+    if symbol_node.has_attribute(AId::Clip) {
+        let (w, h) = use_node_size(use_node, state);
+        let viewport = NonZeroRect::from_xywh(
+            use_node.convert_user_length(AId::X, state, Length::zero()),
+            use_node.convert_user_length(AId::Y, state, Length::zero()),
+            state.use_size.0.unwrap_or(w),
+            state.use_size.1.unwrap_or(h),
+        );
+        if let Some(rect) = viewport.and_then(|r| resolve_clip_rect(symbol_node, r, state)) {
+            return Some(rect);
+        }
+    }
+
     // A nested `svg` with only the `viewBox` attribute and no "rectangle" (x, y, width, height)
     // should not be clipped.
     if use_node.tag_name() == Some(EId::Svg) {
@@ -300,6 +328,63 @@ fn get_clip_rect(
     NonZeroRect::from_xywh(x, y, w, h)
 }
 
+/// Resolves the deprecated `clip` property inside the specified viewport.
+///
+/// Only the `rect(top, right, bottom, left)` shape is supported.
+/// `auto` components are resolved to the respective viewport edge.
+/// Offsets are relative to the top-left corner of the viewport.
+///
+/// Returns `None` for `auto`, an invalid value or an empty rectangle.
+pub(crate) fn resolve_clip_rect(
+    node: SvgNode,
+    viewport: NonZeroRect,
+    state: &converter::State,
+) -> Option<NonZeroRect> {
+    let value: &str = node.attribute(AId::Clip)?;
+    let value = value.trim();
+    if value == "auto" {
+        return None;
+    }
+
+    let args = value
+        .strip_prefix("rect(")
+        .and_then(|v| v.strip_suffix(')'))
+        .log_none(|| log::warn!("Unsupported 'clip' value: '{}'. Skipped.", value))?;
+
+    let mut offsets = [None; 4];
+    let mut count = 0;
+    for (i, arg) in args
+        .split(|c: char| c == ',' || c.is_ascii_whitespace())
+        .filter(|s| !s.is_empty())
+        .enumerate()
+    {
+        if i >= offsets.len() {
+            return None;
+        }
+
+        if arg != "auto" {
+            let length: Length = arg.parse().ok()?;
+            // Top and bottom are vertical offsets, right and left are horizontal.
+            let aid = if i % 2 == 0 { AId::Y } else { AId::X };
+            offsets[i] = Some(units::convert_user_length(length, node, aid, state));
+        }
+
+        count += 1;
+    }
+
+    if count != offsets.len() {
+        return None;
+    }
+
+    let [top, right, bottom, left] = offsets;
+    NonZeroRect::from_ltrb(
+        viewport.x() + left.unwrap_or(0.0),
+        viewport.y() + top.unwrap_or(0.0),
+        viewport.x() + right.unwrap_or(viewport.width()),
+        viewport.y() + bottom.unwrap_or(viewport.height()),
+    )
+}
+
 fn use_node_size(node: SvgNode, state: &converter::State) -> (f32, f32) {
     let def = Length::new(100.0, LengthUnit::Percent);
     let w = node.convert_user_length(AId::Width, state, def);
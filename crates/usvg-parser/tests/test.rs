@@ -35,6 +35,49 @@ fn simplify_paths() {
     };
 }
 
+#[test]
+fn large_path() {
+    use usvg_tree::tiny_skia_path::{PathSegment, Point};
+
+    let p = |x: f32, y: f32| Point::from_xy(x, y);
+
+    let mut data = String::new();
+    let mut expected = Vec::new();
+    for i in 0..10_000 {
+        let x = (i % 1000) as f32;
+        data.push_str(&format!(
+            "M {} 10 l 10 0 h 5 v 5 c 1 2 3 4 5 6 s 1 1 2 2 q 1 1 2 2 t 2 2 Z ",
+            x
+        ));
+        expected.extend_from_slice(&[
+            PathSegment::MoveTo(p(x, 10.0)),
+            PathSegment::LineTo(p(x + 10.0, 10.0)),
+            PathSegment::LineTo(p(x + 15.0, 10.0)),
+            PathSegment::LineTo(p(x + 15.0, 15.0)),
+            PathSegment::CubicTo(p(x + 16.0, 17.0), p(x + 18.0, 19.0), p(x + 20.0, 21.0)),
+            // The first control point is a reflection of the previous one.
+            PathSegment::CubicTo(p(x + 22.0, 23.0), p(x + 21.0, 22.0), p(x + 22.0, 23.0)),
+            PathSegment::QuadTo(p(x + 23.0, 24.0), p(x + 24.0, 25.0)),
+            PathSegment::QuadTo(p(x + 25.0, 26.0), p(x + 26.0, 27.0)),
+            PathSegment::Close,
+        ]);
+    }
+
+    let svg = format!(
+        "<svg xmlns='http://www.w3.org/2000/svg' viewBox='0 0 100 100'><path d='{}'/></svg>",
+        data
+    );
+
+    let tree = usvg_tree::Tree::from_str(&svg, &usvg_parser::Options::default()).unwrap();
+    let path = tree.root.first_child().unwrap();
+    match *path.borrow() {
+        usvg_tree::NodeKind::Path(ref path) => {
+            assert!(path.data.segments().eq(expected.into_iter()));
+        }
+        _ => unreachable!(),
+    };
+}
+
 #[test]
 fn size_detection_1() {
     let svg = "<svg viewBox='0 0 10 20' xmlns='http://www.w3.org/2000/svg'/>";
@@ -86,3 +129,1613 @@ fn invalid_size_1() {
     let result = usvg_tree::Tree::from_str(&svg, &usvg_parser::Options::default());
     assert!(result.is_err());
 }
+
+#[test]
+fn physical_units_at_default_dpi() {
+    let svg = "<svg width='10mm' height='10mm' xmlns='http://www.w3.org/2000/svg'/>";
+    let tree = usvg_tree::Tree::from_str(svg, &usvg_parser::Options::default()).unwrap();
+    // 10mm * 96 / 25.4
+    assert_eq!(
+        tree.size,
+        usvg_tree::Size::from_wh(37.795277, 37.795277).unwrap()
+    );
+}
+
+#[test]
+fn physical_units_at_custom_dpi() {
+    let svg = "<svg width='10mm' height='1in' xmlns='http://www.w3.org/2000/svg'/>";
+    let opt = usvg_parser::Options {
+        dpi: 300.0,
+        ..usvg_parser::Options::default()
+    };
+    let tree = usvg_tree::Tree::from_str(svg, &opt).unwrap();
+    // 10mm * 300 / 25.4
+    assert_eq!(
+        tree.size,
+        usvg_tree::Size::from_wh(118.11024, 300.0).unwrap()
+    );
+}
+
+fn first_path(svg: &str) -> usvg_tree::Path {
+    let tree = usvg_tree::Tree::from_str(svg, &usvg_parser::Options::default()).unwrap();
+    let node = tree.root.first_child().unwrap();
+    let kind = node.borrow();
+    match *kind {
+        usvg_tree::NodeKind::Path(ref path) => path.clone(),
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn path_length_of_line() {
+    let svg = "
+    <svg xmlns='http://www.w3.org/2000/svg' viewBox='0 0 200 200'>
+        <path d='M 20 30 L 120 30'/>
+    </svg>
+    ";
+
+    let path = first_path(svg);
+    assert!((path.length() - 100.0).abs() < 0.01);
+
+    let p = path.point_at_length(50.0).unwrap();
+    assert!((p.point.x - 70.0).abs() < 0.01);
+    assert!((p.point.y - 30.0).abs() < 0.01);
+    assert!(p.angle.abs() < 0.01);
+    assert_eq!(p.subpath, 0);
+
+    assert!(path.point_at_length(-1.0).is_none());
+    assert!(path.point_at_length(101.0).is_none());
+}
+
+#[test]
+fn path_length_of_quarter_circle() {
+    // A quarter of a circle with a radius of 100 centered at (0, 0).
+    let svg = "
+    <svg xmlns='http://www.w3.org/2000/svg' viewBox='0 0 200 200'>
+        <path d='M 100 0 A 100 100 0 0 1 0 100'/>
+    </svg>
+    ";
+
+    let path = first_path(svg);
+    let length = std::f64::consts::PI * 100.0 / 2.0;
+    assert!((path.length() - length).abs() < 0.05);
+
+    let p = path.point_at_length(length / 2.0).unwrap();
+    let c = 100.0 * std::f32::consts::FRAC_1_SQRT_2;
+    assert!((p.point.x - c).abs() < 0.05);
+    assert!((p.point.y - c).abs() < 0.05);
+    assert!((p.angle - 135.0).abs() < 0.1);
+}
+
+#[test]
+fn path_length_of_multiple_subpaths() {
+    let svg = "
+    <svg xmlns='http://www.w3.org/2000/svg' viewBox='0 0 200 200'>
+        <path d='M 0 0 L 10 0 M 0 50 L 0 70'/>
+    </svg>
+    ";
+
+    let path = first_path(svg);
+    assert!((path.length() - 30.0).abs() < 0.01);
+
+    let p = path.point_at_length(15.0).unwrap();
+    assert_eq!(p.subpath, 1);
+    assert!((p.point.x - 0.0).abs() < 0.01);
+    assert!((p.point.y - 55.0).abs() < 0.01);
+    assert!((p.angle - 90.0).abs() < 0.01);
+}
+
+#[test]
+fn path_union_of_rects() {
+    let a = first_path(
+        "<svg xmlns='http://www.w3.org/2000/svg'><rect width='100' height='100'/></svg>",
+    );
+    let b = first_path(
+        "<svg xmlns='http://www.w3.org/2000/svg'><rect x='50' y='50' width='100' height='100'/></svg>",
+    );
+
+    let path = usvg_tree::path_union(&a, &b).unwrap();
+    assert_eq!(path.data.points().len(), 8);
+    assert_eq!(
+        path.data.bounds(),
+        usvg_tree::Rect::from_xywh(0.0, 0.0, 150.0, 150.0).unwrap()
+    );
+    assert!((area(&path.data) - 17500.0).abs() < 0.01);
+}
+
+#[test]
+fn path_difference_of_rects() {
+    let a = first_path(
+        "<svg xmlns='http://www.w3.org/2000/svg'><rect width='100' height='100'/></svg>",
+    );
+    let b = first_path(
+        "<svg xmlns='http://www.w3.org/2000/svg'><rect x='25' y='25' width='50' height='50'/></svg>",
+    );
+
+    let path = usvg_tree::path_difference(&a, &b).unwrap();
+    // An outer rectangle and a hole.
+    let verbs = path.data.verbs();
+    assert_eq!(path.data.points().len(), 8);
+    assert_eq!(
+        verbs
+            .iter()
+            .filter(|v| **v == usvg_tree::tiny_skia_path::PathVerb::Move)
+            .count(),
+        2
+    );
+    assert!((area(&path.data) - 7500.0).abs() < 0.01);
+}
+
+#[test]
+fn path_intersection_respects_fill_rule() {
+    // Two nested squares drawn in the same direction.
+    // With `evenodd` the inner one is a hole, which leaves nothing to intersect with.
+    let svg = |rule: &str| {
+        format!(
+            "<svg xmlns='http://www.w3.org/2000/svg'>
+                <path fill-rule='{}' d='M 0 0 H 100 V 100 H 0 Z M 25 25 H 75 V 75 H 25 Z'/>
+            </svg>",
+            rule
+        )
+    };
+    let b = first_path(
+        "<svg xmlns='http://www.w3.org/2000/svg'><rect x='40' y='40' width='20' height='20'/></svg>",
+    );
+
+    let a = first_path(&svg("nonzero"));
+    let path = usvg_tree::path_intersection(&a, &b).unwrap();
+    assert!((area(&path.data) - 400.0).abs() < 0.01);
+
+    let a = first_path(&svg("evenodd"));
+    assert!(usvg_tree::path_intersection(&a, &b).is_none());
+}
+
+/// Computes a signed area of a polygonal path, where holes have an opposite sign.
+fn area(path: &usvg_tree::tiny_skia_path::Path) -> f32 {
+    use usvg_tree::tiny_skia_path::PathSegment;
+
+    let mut area = 0.0;
+    let mut start = None;
+    let mut prev = None;
+    for seg in path.segments() {
+        match seg {
+            PathSegment::MoveTo(p) => {
+                start = Some(p);
+                prev = Some(p);
+            }
+            PathSegment::LineTo(p) => {
+                let p0 = prev.unwrap();
+                area += p0.x * p.y - p.x * p0.y;
+                prev = Some(p);
+            }
+            PathSegment::Close => {
+                let (p0, p) = (prev.unwrap(), start.unwrap());
+                area += p0.x * p.y - p.x * p0.y;
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    (area / 2.0).abs()
+}
+
+#[test]
+fn href_rewrite() {
+    let svg = "
+    <svg xmlns='http://www.w3.org/2000/svg' xmlns:xlink='http://www.w3.org/1999/xlink'>
+        <image id='image1' width='10' height='10' xlink:href='asset:pixel'/>
+    </svg>
+    ";
+
+    const PIXEL: &str = "data:image/png;base64,\
+        iVBORw0KGgoAAAANSUhEUgAAAAEAAAABCAYAAAAfFcSJAAAADUlEQVR42mNkYPhfDwAChwGA60e6kgAAAABJRU5ErkJggg==";
+
+    let opt = usvg_parser::Options {
+        href_rewrite: Some(std::sync::Arc::new(|href: &str| match href {
+            "asset:pixel" => PIXEL.to_string(),
+            _ => href.to_string(),
+        })),
+        ..usvg_parser::Options::default()
+    };
+
+    let tree = usvg_tree::Tree::from_str(svg, &opt).unwrap();
+    let node = tree.node_by_id("image1").unwrap();
+    let kind = node.borrow();
+    match *kind {
+        usvg_tree::NodeKind::Image(ref image) => {
+            assert!(matches!(image.kind, usvg_tree::ImageKind::PNG(_)));
+        }
+        _ => unreachable!(),
+    }
+
+    // Without a rewrite, the image cannot be resolved and will be skipped.
+    let tree = usvg_tree::Tree::from_str(svg, &usvg_parser::Options::default()).unwrap();
+    assert!(tree.node_by_id("image1").is_none());
+}
+
+#[test]
+fn href_rewrite_on_use() {
+    let svg = "
+    <svg xmlns='http://www.w3.org/2000/svg' xmlns:xlink='http://www.w3.org/1999/xlink'>
+        <defs>
+            <rect id='rect1' width='10' height='10'/>
+        </defs>
+        <use id='use1' xlink:href='#logical'/>
+    </svg>
+    ";
+
+    let opt = usvg_parser::Options {
+        href_rewrite: Some(std::sync::Arc::new(|href: &str| {
+            href.replace("logical", "rect1")
+        })),
+        ..usvg_parser::Options::default()
+    };
+
+    let tree = usvg_tree::Tree::from_str(svg, &opt).unwrap();
+    assert!(tree.node_by_id("use1").is_some());
+}
+
+fn fill_color(tree: &usvg_tree::Tree, id: &str) -> usvg_tree::Color {
+    let node = tree.node_by_id(id).unwrap();
+    let kind = node.borrow();
+    match *kind {
+        usvg_tree::NodeKind::Path(ref path) => match path.fill.as_ref().unwrap().paint {
+            usvg_tree::Paint::Color(c) => c,
+            _ => unreachable!(),
+        },
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn color_transform_invert_lightness() {
+    let svg = "
+    <svg xmlns='http://www.w3.org/2000/svg' viewBox='0 0 200 200' color='white'>
+        <rect id='rect1' width='10' height='10' fill='white'/>
+        <rect id='rect2' width='10' height='10' fill='red'/>
+        <rect id='rect3' width='10' height='10' fill='currentColor'/>
+    </svg>
+    ";
+
+    let opt = usvg_parser::Options {
+        color_transform: Some(usvg_parser::ColorTransform::InvertLightness),
+        ..usvg_parser::Options::default()
+    };
+
+    let tree = usvg_tree::Tree::from_str(svg, &opt).unwrap();
+    let white = fill_color(&tree, "rect1");
+    assert!(white.red < 10 && white.green < 10 && white.blue < 10);
+    assert_eq!(
+        fill_color(&tree, "rect2"),
+        usvg_tree::Color::new_rgb(255, 0, 0)
+    );
+    // Applied after `currentColor` resolving.
+    assert_eq!(fill_color(&tree, "rect3"), white);
+}
+
+#[test]
+fn color_transform_on_gradient_and_opt_out() {
+    let svg = "
+    <svg xmlns='http://www.w3.org/2000/svg' viewBox='0 0 200 200'>
+        <linearGradient id='lg1'>
+            <stop offset='0' stop-color='white'/>
+            <stop offset='1' stop-color='black'/>
+        </linearGradient>
+        <rect id='rect1' width='10' height='10' fill='url(#lg1)'/>
+    </svg>
+    ";
+
+    let opt = usvg_parser::Options {
+        color_transform: Some(usvg_parser::ColorTransform::Custom(std::sync::Arc::new(
+            |_| usvg_tree::Color::new_rgb(0, 0, 255),
+        ))),
+        ..usvg_parser::Options::default()
+    };
+
+    let tree = usvg_tree::Tree::from_str(svg, &opt).unwrap();
+    let node = tree.node_by_id("rect1").unwrap();
+    match *node.borrow() {
+        usvg_tree::NodeKind::Path(ref path) => match path.fill.as_ref().unwrap().paint {
+            usvg_tree::Paint::LinearGradient(ref lg) => {
+                assert!(lg
+                    .stops
+                    .iter()
+                    .all(|s| s.color == usvg_tree::Color::new_rgb(0, 0, 255)));
+            }
+            _ => unreachable!(),
+        },
+        _ => unreachable!(),
+    }
+
+    // `color-scheme: only light` opts out of color transforms.
+    let svg = "
+    <svg xmlns='http://www.w3.org/2000/svg' viewBox='0 0 200 200'
+         style='color-scheme: only light'>
+        <rect id='rect1' width='10' height='10' fill='white'/>
+    </svg>
+    ";
+    let tree = usvg_tree::Tree::from_str(svg, &opt).unwrap();
+    assert_eq!(fill_color(&tree, "rect1"), usvg_tree::Color::white());
+}
+
+#[test]
+fn zero_and_negative_stroke_width() {
+    let svg = "
+    <svg xmlns='http://www.w3.org/2000/svg' viewBox='0 0 200 200'>
+        <rect id='rect1' width='10' height='10' stroke='black' stroke-width='0'/>
+        <rect id='rect2' width='10' height='10' stroke='black' stroke-width='-5'/>
+    </svg>
+    ";
+
+    let tree = usvg_tree::Tree::from_str(svg, &usvg_parser::Options::default()).unwrap();
+    for id in ["rect1", "rect2"].iter() {
+        let node = tree.node_by_id(id).unwrap();
+        match *node.borrow() {
+            usvg_tree::NodeKind::Path(ref path) => {
+                assert!(path.stroke.is_none());
+                assert!(path.fill.is_some());
+            }
+            _ => unreachable!(),
+        };
+    }
+}
+
+#[test]
+fn degenerate_shapes() {
+    let svg = "
+    <svg xmlns='http://www.w3.org/2000/svg' viewBox='0 0 200 200'>
+        <rect id='rect1' width='0' height='10'/>
+        <rect id='rect2' width='10' height='-10'/>
+        <ellipse id='ellipse1' rx='0' ry='10'/>
+        <circle id='circle1' r='-1'/>
+        <rect id='rect3' width='1e39' height='10'/>
+    </svg>
+    ";
+
+    let tree = usvg_tree::Tree::from_str(svg, &usvg_parser::Options::default()).unwrap();
+    for id in ["rect1", "rect2", "ellipse1", "circle1", "rect3"].iter() {
+        assert!(tree.node_by_id(id).is_none());
+    }
+}
+
+#[test]
+fn non_finite_path_data() {
+    let svg = "
+    <svg xmlns='http://www.w3.org/2000/svg' viewBox='0 0 200 200'>
+        <path id='path1' d='M 10 10 L 20 20 L 1e39 30 L 40 40' stroke='black'/>
+        <polyline id='polyline1' points='10 10 20 20 1e39 30 40 40' stroke='black'/>
+    </svg>
+    ";
+
+    let tree = usvg_tree::Tree::from_str(svg, &usvg_parser::Options::default()).unwrap();
+    for id in ["path1", "polyline1"].iter() {
+        let node = tree.node_by_id(id).unwrap();
+        match *node.borrow() {
+            usvg_tree::NodeKind::Path(ref path) => {
+                // Rendered up to the first invalid segment.
+                assert_eq!(path.data.len(), 2);
+                assert!(path.data.bounds().right() <= 20.0);
+            }
+            _ => unreachable!(),
+        };
+    }
+}
+
+#[test]
+fn transform_box() {
+    let svg = "
+    <svg xmlns='http://www.w3.org/2000/svg' viewBox='0 0 200 200'>
+        <rect id='rect1' x='20' y='20' width='40' height='40'
+              transform='rotate(90)' transform-origin='center' transform-box='fill-box'/>
+        <rect id='rect2' x='20' y='20' width='40' height='40'
+              transform='rotate(90)' transform-origin='center' transform-box='view-box'/>
+    </svg>
+    ";
+
+    let tree = usvg_tree::Tree::from_str(svg, &usvg_parser::Options::default()).unwrap();
+    // A point that is not affected by the rotation.
+    let is_pivot = |id: &str, x: f32, y: f32| {
+        let ts = usvg_tree::NodeExt::abs_transform(&tree.node_by_id(id).unwrap());
+        let mut p = usvg_tree::tiny_skia_path::Point::from_xy(x, y);
+        ts.map_point(&mut p);
+        (p.x - x).abs() < 0.001 && (p.y - y).abs() < 0.001
+    };
+
+    // The center of the rect.
+    assert!(is_pivot("rect1", 40.0, 40.0));
+    // The center of the viewBox.
+    assert!(is_pivot("rect2", 100.0, 100.0));
+    assert!(!is_pivot("rect2", 40.0, 40.0));
+}
+
+#[test]
+fn text_whitespace_handling() {
+    let svg = "
+    <svg xmlns='http://www.w3.org/2000/svg' viewBox='0 0 200 200'>
+        <text id='text1'>
+            Some
+            text
+        </text>
+        <text id='text2' xml:space='preserve'>
+            Some
+            text
+        </text>
+        <text id='text3' style='white-space:pre'>
+            Some
+            text
+        </text>
+        <text id='text4' xml:space='preserve' style='white-space:normal'>
+            Some
+            text
+        </text>
+    </svg>
+    ";
+
+    let tree = usvg_tree::Tree::from_str(svg, &usvg_parser::Options::default()).unwrap();
+    let text = |id: &str| match *tree.node_by_id(id).unwrap().borrow() {
+        usvg_tree::NodeKind::Text(ref text) => text.chunks[0].text.clone(),
+        _ => unreachable!(),
+    };
+
+    assert_eq!(text("text1"), "Some text");
+    // Newlines are converted into spaces, but not collapsed.
+    let preserved = " ".repeat(13) + "Some" + &" ".repeat(13) + "text" + &" ".repeat(9);
+    assert_eq!(text("text2"), preserved);
+    assert_eq!(text("text3"), preserved);
+    // `white-space` takes precedence over `xml:space`.
+    assert_eq!(text("text4"), "Some text");
+}
+
+#[test]
+fn resolved_paint_of_inherited_gradient() {
+    let svg = "
+    <svg xmlns='http://www.w3.org/2000/svg' viewBox='0 0 200 200'>
+        <linearGradient id='lg1'>
+            <stop offset='0.2' stop-color='currentColor'/>
+            <stop offset='0.8' stop-color='blue' stop-opacity='0.5'/>
+        </linearGradient>
+        <linearGradient id='lg2' xlink:href='#lg1' x1='0.5'
+                        xmlns:xlink='http://www.w3.org/1999/xlink'/>
+        <rect id='rect1' x='20' y='40' width='100' height='50'
+              fill='url(#lg2)' fill-opacity='0.7' color='green' stroke='red'/>
+    </svg>
+    ";
+
+    let tree = usvg_tree::Tree::from_str(svg, &usvg_parser::Options::default()).unwrap();
+    match tree.resolved_paint("rect1").unwrap() {
+        usvg_tree::ResolvedPaint::LinearGradient {
+            x1,
+            x2,
+            transform,
+            stops,
+            opacity,
+            ..
+        } => {
+            assert_eq!((x1, x2), (0.5, 1.0));
+            // objectBoundingBox units are converted into user space.
+            assert_eq!(
+                transform,
+                usvg_tree::Transform::from_row(100.0, 0.0, 0.0, 50.0, 20.0, 40.0)
+            );
+            assert_eq!(opacity.get(), 0.7);
+
+            assert_eq!(stops.len(), 2);
+            assert_eq!(stops[0].offset.get(), 0.2);
+            assert_eq!(stops[0].color, usvg_tree::Color::new_rgb(0, 128, 0));
+            assert_eq!(stops[0].opacity.get(), 1.0);
+            assert_eq!(stops[1].offset.get(), 0.8);
+            assert_eq!(stops[1].color, usvg_tree::Color::new_rgb(0, 0, 255));
+            assert_eq!(stops[1].opacity.get(), 0.5);
+        }
+        _ => unreachable!(),
+    }
+
+    match tree.resolved_stroke_paint("rect1").unwrap() {
+        usvg_tree::ResolvedPaint::Color { color, .. } => {
+            assert_eq!(color, usvg_tree::Color::new_rgb(255, 0, 0));
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn font_shorthand() {
+    let svg = "
+    <svg xmlns='http://www.w3.org/2000/svg' viewBox='0 0 200 200'
+         font-weight='900' font-variant='small-caps'>
+        <style>
+            #text2 { font: bold 12px/1.4 'Noto Serif', serif }
+        </style>
+        <text id='text1' style='font: italic bold 20px/1.4 \"Arial\", sans-serif'>Text</text>
+        <text id='text2'>Text</text>
+        <text id='text3' style='font: small-caps condensed 10pt Arial'>Text</text>
+        <text id='text4' style='font: 30px Arial; font-style: oblique'>Text</text>
+    </svg>
+    ";
+
+    let tree = usvg_tree::Tree::from_str(svg, &usvg_parser::Options::default()).unwrap();
+    let span = |id: &str| match *tree.node_by_id(id).unwrap().borrow() {
+        usvg_tree::NodeKind::Text(ref text) => text.chunks[0].spans[0].clone(),
+        _ => unreachable!(),
+    };
+
+    let span1 = span("text1");
+    assert_eq!(span1.font.families, vec!["Arial", "sans-serif"]);
+    assert_eq!(span1.font.style, usvg_tree::FontStyle::Italic);
+    assert_eq!(span1.font.weight, 700);
+    assert_eq!(span1.font.stretch, usvg_tree::FontStretch::Normal);
+    assert_eq!(span1.font_size.get(), 20.0);
+    // Omitted values are reset and not inherited.
+    assert!(!span1.small_caps);
+
+    let span2 = span("text2");
+    assert_eq!(span2.font.families, vec!["Noto Serif", "serif"]);
+    assert_eq!(span2.font.style, usvg_tree::FontStyle::Normal);
+    assert_eq!(span2.font.weight, 700);
+    assert_eq!(span2.font_size.get(), 12.0);
+
+    let span3 = span("text3");
+    assert_eq!(span3.font.families, vec!["Arial"]);
+    assert_eq!(span3.font.weight, 400);
+    assert_eq!(span3.font.stretch, usvg_tree::FontStretch::Condensed);
+    assert!(span3.small_caps);
+    assert!((span3.font_size.get() - 13.333).abs() < 0.01);
+
+    // Longhands can overwrite the shorthand.
+    let span4 = span("text4");
+    assert_eq!(span4.font.style, usvg_tree::FontStyle::Oblique);
+    assert_eq!(span4.font_size.get(), 30.0);
+}
+
+#[test]
+fn stroke_dashoffset() {
+    let svg = "
+    <svg xmlns='http://www.w3.org/2000/svg' viewBox='0 0 200 200'
+         stroke='black' stroke-dasharray='10 20'>
+        <path id='path1' d='M 10 10 L 190 10' stroke-dashoffset='-15'/>
+        <path id='path2' d='M 10 20 L 190 20' stroke-dashoffset='-45'/>
+        <path id='path3' d='M 10 30 L 190 30' stroke-dashoffset='5%'/>
+        <path id='path4' d='M 10 40 L 190 40' stroke-dashoffset='-5%'/>
+        <path id='path5' d='M 10 50 L 190 50' stroke-dashoffset='40'/>
+        <path id='path6' d='M 10 60 L 190 60' stroke-dashoffset='-15' stroke-dasharray='none'/>
+    </svg>
+    ";
+
+    let tree = usvg_tree::Tree::from_str(svg, &usvg_parser::Options::default()).unwrap();
+    let offset = |id: &str| match *tree.node_by_id(id).unwrap().borrow() {
+        usvg_tree::NodeKind::Path(ref path) => path.stroke.as_ref().unwrap().dashoffset,
+        _ => unreachable!(),
+    };
+
+    // Offsets are normalized into the dash period, which is 30 here.
+    assert_eq!(offset("path1"), 15.0);
+    assert_eq!(offset("path2"), 15.0);
+    // Percentages are resolved against the normalized viewport diagonal, which is 200 here.
+    assert_eq!(offset("path3"), 10.0);
+    assert_eq!(offset("path4"), 20.0);
+    assert_eq!(offset("path5"), 10.0);
+    // An offset has no effect without a dash array.
+    assert_eq!(offset("path6"), 0.0);
+}
+
+#[test]
+fn font_features() {
+    let svg = "
+    <svg xmlns='http://www.w3.org/2000/svg' viewBox='0 0 200 200'>
+        <text id='text1' font-variant='small-caps tabular-nums'>Text</text>
+        <text id='text2' style='font-variant: no-common-ligatures; font-feature-settings: \"liga\", \"ss01\" 2'>Text</text>
+        <g font-feature-settings='\"kern\" off'>
+            <text id='text3'>Text</text>
+        </g>
+        <text id='text4' font-feature-settings='\"kern\" maybe'>Text</text>
+    </svg>
+    ";
+
+    let tree = usvg_tree::Tree::from_str(svg, &usvg_parser::Options::default()).unwrap();
+    let span = |id: &str| match *tree.node_by_id(id).unwrap().borrow() {
+        usvg_tree::NodeKind::Text(ref text) => text.chunks[0].spans[0].clone(),
+        _ => unreachable!(),
+    };
+    let feature = |tag: &[u8; 4], value| usvg_tree::FontFeature { tag: *tag, value };
+
+    let span1 = span("text1");
+    assert!(span1.small_caps);
+    assert_eq!(span1.font_features, vec![feature(b"tnum", 1)]);
+
+    assert_eq!(
+        span("text2").font_features,
+        vec![
+            feature(b"liga", 0),
+            feature(b"clig", 0),
+            feature(b"liga", 1),
+            feature(b"ss01", 2),
+        ]
+    );
+
+    // Inherited.
+    assert_eq!(span("text3").font_features, vec![feature(b"kern", 0)]);
+
+    // Invalid values are ignored.
+    assert!(span("text4").font_features.is_empty());
+}
+
+#[test]
+fn grid_snap() {
+    use usvg_tree::tiny_skia_path::{PathSegment, Point};
+
+    let svg = "
+    <svg xmlns='http://www.w3.org/2000/svg' width='200' height='200' viewBox='0 0 100 100'>
+        <rect id='rect1' x='10.3' y='20.6' width='30.4' height='40.2' stroke='black' stroke-width='1.3'/>
+        <rect id='rect2' x='50.05' y='50' width='0.175' height='10'/>
+        <rect id='rect3' x='70.2' y='70' width='0.1' height='10'/>
+    </svg>
+    ";
+
+    let opt = usvg_parser::Options {
+        grid_snap: Some(usvg_parser::GridSnap {
+            step: 1.0,
+            min_size: 0.25,
+            stroke_width: true,
+        }),
+        ..usvg_parser::Options::default()
+    };
+    let tree = usvg_tree::Tree::from_str(svg, &opt).unwrap();
+    let path = |id: &str| match *tree.node_by_id(id).unwrap().borrow() {
+        usvg_tree::NodeKind::Path(ref path) => path.clone(),
+        _ => unreachable!(),
+    };
+
+    // The grid is in canvas pixels, which are two times smaller than user units here.
+    let rect1 = path("rect1");
+    let segments: Vec<_> = rect1.data.segments().collect();
+    assert_eq!(
+        segments,
+        vec![
+            PathSegment::MoveTo(Point::from_xy(10.5, 20.5)),
+            PathSegment::LineTo(Point::from_xy(40.5, 20.5)),
+            PathSegment::LineTo(Point::from_xy(40.5, 61.0)),
+            PathSegment::LineTo(Point::from_xy(10.5, 61.0)),
+            PathSegment::Close,
+        ]
+    );
+    assert_eq!(rect1.stroke.unwrap().width.get(), 1.5);
+
+    // Would collapse into a line, so left as is.
+    let rect2 = path("rect2");
+    assert!((rect2.data.bounds().width() - 0.175).abs() < 0.001);
+
+    // Smaller than `min_size`, so left as is.
+    let rect3 = path("rect3");
+    assert!((rect3.data.bounds().width() - 0.1).abs() < 0.001);
+}
+
+#[test]
+fn text_encodings() {
+    fn check(data: &[u8], encoding: Option<usvg_parser::Encoding>) {
+        let opt = usvg_parser::Options::default();
+        let tree = usvg_tree::Tree::from_data_with_encoding(data, encoding, &opt).unwrap();
+        assert!(tree.node_by_id("café").is_some());
+    }
+
+    let svg = "<svg xmlns='http://www.w3.org/2000/svg' viewBox='0 0 10 10'>\
+               <rect id='café' width='5' height='5'/></svg>";
+
+    // UTF-16 LE with a BOM.
+    let mut data = Vec::new();
+    for c in std::iter::once('\u{FEFF}').chain(svg.chars()) {
+        let mut buf = [0; 2];
+        for unit in c.encode_utf16(&mut buf) {
+            data.extend_from_slice(&unit.to_le_bytes());
+        }
+    }
+    check(&data, None);
+
+    // UTF-16 BE with a BOM and a declaration.
+    let text = format!("\u{FEFF}<?xml version='1.0' encoding='UTF-16'?>{}", svg);
+    let data: Vec<u8> = text.encode_utf16().flat_map(|u| u.to_be_bytes()).collect();
+    check(&data, None);
+
+    // Latin-1 with a declaration.
+    let text = format!("<?xml version=\"1.0\" encoding=\"ISO-8859-1\"?>\n{}", svg);
+    let data: Vec<u8> = text.chars().map(|c| c as u8).collect();
+    check(&data, None);
+
+    // Latin-1 without a declaration requires an explicit encoding.
+    let data: Vec<u8> = svg.chars().map(|c| c as u8).collect();
+    let opt = usvg_parser::Options::default();
+    assert!(matches!(
+        usvg_tree::Tree::from_data(&data, &opt),
+        Err(usvg_parser::Error::NotAnUtf8Str)
+    ));
+    check(&data, Some(usvg_parser::Encoding::Latin1));
+
+    // UTF-8 with a BOM.
+    let text = format!("\u{FEFF}{}", svg);
+    check(text.as_bytes(), None);
+}
+
+#[test]
+fn tree_parsing_default_methods() {
+    // External implementors don't have to implement `from_data_with_encoding`.
+    struct Wrapper(usvg_tree::Tree);
+
+    impl usvg_parser::TreeParsing for Wrapper {
+        fn from_data(data: &[u8], opt: &usvg_parser::Options) -> Result<Self, usvg_parser::Error> {
+            Self::from_data_with_encoding(data, None, opt)
+        }
+
+        fn from_str(text: &str, opt: &usvg_parser::Options) -> Result<Self, usvg_parser::Error> {
+            usvg_tree::Tree::from_str(text, opt).map(Wrapper)
+        }
+
+        fn from_xmltree(
+            doc: &roxmltree::Document,
+            opt: &usvg_parser::Options,
+        ) -> Result<Self, usvg_parser::Error> {
+            usvg_tree::Tree::from_xmltree(doc, opt).map(Wrapper)
+        }
+    }
+
+    let svg =
+        "<svg xmlns='http://www.w3.org/2000/svg'><rect id='café' width='5' height='5'/></svg>";
+    let data: Vec<u8> = svg.chars().map(|c| c as u8).collect();
+    let opt = usvg_parser::Options::default();
+    let tree = Wrapper::from_data_with_encoding(&data, Some(usvg_parser::Encoding::Latin1), &opt);
+    assert!(tree.unwrap().0.node_by_id("café").is_some());
+}
+
+#[test]
+fn max_image_pixels() {
+    // Just a PNG header. Enough to detect the image size.
+    fn png_href(width: u32, height: u32, padding: usize) -> String {
+        let mut png = b"\x89PNG\r\n\x1a\n\0\0\0\x0dIHDR".to_vec();
+        png.extend_from_slice(&width.to_be_bytes());
+        png.extend_from_slice(&height.to_be_bytes());
+        png.extend_from_slice(&[1, 0, 0, 0, 0, 0, 0, 0, 0]);
+        png.resize(png.len() + padding, 0);
+        let data: String = png.iter().map(|b| format!("%{:02X}", b)).collect();
+        format!("data:image/png,{}", data)
+    }
+
+    let svg = format!(
+        "
+    <svg xmlns='http://www.w3.org/2000/svg' xmlns:xlink='http://www.w3.org/1999/xlink'
+         viewBox='0 0 200 200'>
+        <image id='image1' width='100' height='100' xlink:href='{}'/>
+        <image id='image2' x='100' width='100' height='100' xlink:href='{}'/>
+    </svg>
+    ",
+        png_href(100_000, 100_000, 0),
+        png_href(64, 64, 200),
+    );
+
+    let mut opt = usvg_parser::Options::default();
+
+    let tree = usvg_tree::Tree::from_str(&svg, &opt).unwrap();
+    assert!(tree.node_by_id("image1").is_some());
+    assert!(tree.node_by_id("image2").is_some());
+
+    opt.max_image_pixels = Some(10_000_000);
+    let tree = usvg_tree::Tree::from_str(&svg, &opt).unwrap();
+    assert!(tree.node_by_id("image1").is_none());
+    assert!(tree.node_by_id("image2").is_some());
+
+    opt.max_image_pixels = None;
+    opt.max_image_bytes = Some(100);
+    let tree = usvg_tree::Tree::from_str(&svg, &opt).unwrap();
+    assert!(tree.node_by_id("image1").is_some());
+    assert!(tree.node_by_id("image2").is_none());
+}
+
+#[test]
+fn transform_grammars() {
+    let svg = "
+    <svg xmlns='http://www.w3.org/2000/svg' viewBox='0 0 200 200'>
+        <rect id='rect1' width='10' height='10' transform='rotate(90 10 10)'/>
+        <rect id='rect2' width='10' height='10' transform='skewX(45) skewY(45)'/>
+        <rect id='rect3' width='10' height='10' style='transform: rotate(90deg)'/>
+        <rect id='rect4' width='10' height='10'
+              style='transform: translate(10px, 0) rotate(0.25turn) translateX(-10px)'/>
+        <rect id='rect5' width='10' height='10' style='transform: skew(45deg)'/>
+        <rect id='rect6' width='10' height='10' transform='translate(5 5)'
+              style='transform: rotate(90 10 10)'/>
+        <rect id='rect7' width='10' height='10' transform='translate(5 5)'
+              style='transform: rotate(90)'/>
+        <rect id='rect8' width='10' height='10' transform='translate(5 5)'
+              style='transform: none'/>
+    </svg>
+    ";
+
+    let tree = usvg_tree::Tree::from_str(svg, &usvg_parser::Options::default()).unwrap();
+    let transform = |id: &str| {
+        let ts = usvg_tree::NodeExt::abs_transform(&tree.node_by_id(id).unwrap());
+        let round = |n: f32| (n * 1000.0).round() / 1000.0;
+        [ts.sx, ts.ky, ts.kx, ts.sy, ts.tx, ts.ty].map(round)
+    };
+
+    // The `transform` attribute.
+    assert_eq!(transform("rect1"), [0.0, 1.0, -1.0, 0.0, 20.0, 0.0]);
+    assert_eq!(transform("rect2"), [2.0, 1.0, 1.0, 1.0, 0.0, 0.0]);
+    // The `transform` property.
+    assert_eq!(transform("rect3"), [0.0, 1.0, -1.0, 0.0, 0.0, 0.0]);
+    assert_eq!(transform("rect4"), [0.0, 1.0, -1.0, 0.0, 10.0, -10.0]);
+    assert_eq!(transform("rect5"), [1.0, 0.0, 1.0, 1.0, 0.0, 0.0]);
+    // Attribute syntax is not allowed in CSS, so the attribute is used instead.
+    assert_eq!(transform("rect6"), [1.0, 0.0, 0.0, 1.0, 5.0, 5.0]);
+    assert_eq!(transform("rect7"), [1.0, 0.0, 0.0, 1.0, 5.0, 5.0]);
+    assert_eq!(transform("rect8"), [1.0, 0.0, 0.0, 1.0, 0.0, 0.0]);
+}
+
+#[test]
+fn conditional_attributes_outside_switch() {
+    let svg = "
+    <svg xmlns='http://www.w3.org/2000/svg' xmlns:xlink='http://www.w3.org/1999/xlink'>
+        <defs>
+            <rect id='rect5' width='10' height='10' systemLanguage='ru'/>
+        </defs>
+        <rect id='rect1' width='10' height='10' systemLanguage='ru'/>
+        <rect id='rect2' width='10' height='10' systemLanguage='en-US'/>
+        <rect id='rect3' width='10' height='10'
+              requiredFeatures='http://www.w3.org/TR/SVG11/feature#Font'/>
+        <g id='g1' systemLanguage='ru'>
+            <rect id='rect4' width='10' height='10'/>
+        </g>
+        <use id='use1' xlink:href='#rect5'/>
+    </svg>
+    ";
+
+    let tree = usvg_tree::Tree::from_str(svg, &usvg_parser::Options::default()).unwrap();
+    assert!(tree.node_by_id("rect1").is_none());
+    assert!(tree.node_by_id("rect2").is_some());
+    assert!(tree.node_by_id("rect3").is_none());
+    assert!(tree.node_by_id("g1").is_none());
+    assert!(tree.node_by_id("rect4").is_none());
+    assert!(tree.node_by_id("use1").is_none());
+
+    let opt = usvg_parser::Options {
+        languages: vec!["ru".to_string()],
+        ..usvg_parser::Options::default()
+    };
+    let tree = usvg_tree::Tree::from_str(svg, &opt).unwrap();
+    assert!(tree.node_by_id("rect1").is_some());
+    assert!(tree.node_by_id("rect2").is_none());
+    assert!(tree.node_by_id("rect4").is_some());
+    assert!(tree.node_by_id("use1").is_some());
+}
+
+#[test]
+fn important_declarations() {
+    let svg = "
+    <svg xmlns='http://www.w3.org/2000/svg'>
+        <style>
+            #rect1 { fill:red }
+            #rect2 { fill:red !important }
+            #rect3 { fill:red !important }
+            #rect4 { fill:green !important }
+            #rect4 { fill:red }
+        </style>
+        <rect id='rect1' width='10' height='10' fill='blue' style='fill:green'/>
+        <rect id='rect2' width='10' height='10' style='fill:blue'/>
+        <rect id='rect3' width='10' height='10' style='fill:green !important'/>
+        <rect id='rect4' width='10' height='10' style='fill:blue'/>
+    </svg>
+    ";
+
+    let tree = usvg_tree::Tree::from_str(svg, &usvg_parser::Options::default()).unwrap();
+    let green = usvg_tree::Color::new_rgb(0, 128, 0);
+    // A normal inline style beats a normal stylesheet rule.
+    assert_eq!(fill_color(&tree, "rect1"), green);
+    // An important stylesheet rule beats a normal inline style.
+    assert_eq!(
+        fill_color(&tree, "rect2"),
+        usvg_tree::Color::new_rgb(255, 0, 0)
+    );
+    // An important inline style beats an important stylesheet rule.
+    assert_eq!(fill_color(&tree, "rect3"), green);
+    // An important stylesheet rule beats a later normal one.
+    assert_eq!(fill_color(&tree, "rect4"), green);
+}
+
+#[test]
+fn css_comments_and_import() {
+    let svg = "
+    <svg xmlns='http://www.w3.org/2000/svg'>
+        <style>
+            @import url(other.css);
+            /* A comment. */
+            #rect1, /* a comment inside a selector list */ #rect2 {
+                fill: /* before a value */ green;
+                /* between declarations */
+                stroke: green /* after a value */;
+            }
+            @import 'other.css';
+            #rect3 { fill: green }
+        </style>
+        <rect id='rect1' width='10' height='10'/>
+        <rect id='rect2' width='10' height='10'/>
+        <rect id='rect3' width='10' height='10'/>
+        <rect id='rect4' width='10' height='10' style='/* a */ fill: /* b */ green; /* c */'/>
+    </svg>
+    ";
+
+    let tree = usvg_tree::Tree::from_str(svg, &usvg_parser::Options::default()).unwrap();
+    let green = usvg_tree::Color::new_rgb(0, 128, 0);
+    assert_eq!(fill_color(&tree, "rect1"), green);
+    assert_eq!(fill_color(&tree, "rect2"), green);
+    assert_eq!(fill_color(&tree, "rect3"), green);
+    assert_eq!(fill_color(&tree, "rect4"), green);
+
+    let node = tree.node_by_id("rect2").unwrap();
+    match *node.borrow() {
+        usvg_tree::NodeKind::Path(ref path) => {
+            let stroke = path.stroke.as_ref().unwrap();
+            assert_eq!(stroke.paint, usvg_tree::Paint::Color(green));
+        }
+        _ => unreachable!(),
+    };
+}
+
+#[test]
+fn paint_fallback() {
+    let svg = "
+    <svg xmlns='http://www.w3.org/2000/svg'>
+        <rect id='rect1' width='10' height='10' fill='url(#missing) green'/>
+        <rect id='rect2' width='10' height='10' fill='url(#rect1) green'/>
+        <rect id='rect3' width='10' height='10' fill='url(#rect1)'/>
+    </svg>
+    ";
+
+    let tree = usvg_tree::Tree::from_str(svg, &usvg_parser::Options::default()).unwrap();
+    let green = usvg_tree::Color::new_rgb(0, 128, 0);
+    assert_eq!(fill_color(&tree, "rect1"), green);
+    // A link to an element that is not a paint server is an invalid reference as well.
+    assert_eq!(fill_color(&tree, "rect2"), green);
+    let node = tree.node_by_id("rect3").unwrap();
+    match *node.borrow() {
+        usvg_tree::NodeKind::Path(ref path) => assert!(path.fill.is_none()),
+        _ => unreachable!(),
+    };
+}
+
+#[test]
+fn use_sources() {
+    let svg = "
+    <svg xmlns='http://www.w3.org/2000/svg' xmlns:xlink='http://www.w3.org/1999/xlink'>
+        <symbol id='icon'>
+            <rect width='10' height='10'/>
+        </symbol>
+        <use id='use1' xlink:href='#icon'/>
+        <use id='use2' xlink:href='#icon' x='20'/>
+        <defs>
+            <circle id='circle1' r='5'/>
+        </defs>
+        <use id='use3' xlink:href='#circle1'/>
+    </svg>
+    ";
+
+    let use_source = |tree: &usvg_tree::Tree, id: &str| {
+        let node = tree.node_by_id(id).unwrap();
+        let source = match *node.borrow() {
+            usvg_tree::NodeKind::Group(ref g) => g.use_source.clone(),
+            _ => unreachable!(),
+        };
+        assert!(node.has_children());
+        source
+    };
+
+    let tree = usvg_tree::Tree::from_str(svg, &usvg_parser::Options::default()).unwrap();
+    assert_eq!(use_source(&tree, "use1"), None);
+
+    let opt = usvg_parser::Options {
+        preserve_use_sources: true,
+        ..usvg_parser::Options::default()
+    };
+    let tree = usvg_tree::Tree::from_str(svg, &opt).unwrap();
+    for (id, def_id) in [("use1", "icon"), ("use2", "icon"), ("use3", "circle1")] {
+        let expected = usvg_tree::UseSource {
+            use_id: id.to_string(),
+            def_id: def_id.to_string(),
+        };
+        assert_eq!(use_source(&tree, id), Some(expected));
+    }
+}
+
+#[test]
+fn conic_gradient_paint() {
+    let svg = "
+    <svg viewBox='0 0 100 100' xmlns='http://www.w3.org/2000/svg'>
+        <rect id='rect1' width='100' height='100'
+              fill='conic-gradient(from 90deg at 25% 75%, red, lime 50%, blue)'/>
+        <rect id='rect2' width='100' height='100'
+              fill='conic-gradient(at 10px 20px, red, blue)'/>
+        <rect id='conicGradient2' width='100' height='100'
+              fill='conic-gradient(red, blue)'/>
+    </svg>
+    ";
+
+    let tree = usvg_tree::Tree::from_str(svg, &usvg_parser::Options::default()).unwrap();
+    let gradient = |id: &str| {
+        let node = tree.node_by_id(id).unwrap();
+        let paint = match *node.borrow() {
+            usvg_tree::NodeKind::Path(ref path) => path.fill.as_ref().unwrap().paint.clone(),
+            _ => unreachable!(),
+        };
+        match paint {
+            usvg_tree::Paint::ConicGradient(ref cg) => (**cg).clone(),
+            _ => panic!("not a conic gradient"),
+        }
+    };
+
+    let cg = gradient("rect1");
+    assert_eq!(cg.units, usvg_tree::Units::ObjectBoundingBox);
+    assert_eq!((cg.cx, cg.cy, cg.angle), (0.25, 0.75, 90.0));
+    let offsets: Vec<f32> = cg.stops.iter().map(|s| s.offset.get()).collect();
+    assert_eq!(offsets, [0.0, 0.5, 1.0]);
+
+    let cg = gradient("rect2");
+    assert_eq!(cg.units, usvg_tree::Units::UserSpaceOnUse);
+    assert_eq!((cg.cx, cg.cy, cg.angle), (10.0, 20.0, 0.0));
+
+    // Generated IDs do not collide with existing ones.
+    let ids = ["rect1", "rect2", "conicGradient2"].map(|id| gradient(id).id);
+    assert_eq!(ids, ["conicGradient1", "conicGradient3", "conicGradient4"]);
+}
+
+#[test]
+fn fill_and_stroke_none_blocks_inheritance() {
+    let svg = "
+    <svg viewBox='0 0 100 100' xmlns='http://www.w3.org/2000/svg'>
+        <g fill='red' stroke='blue'>
+            <rect id='none' width='10' height='10' fill='none' stroke='none'/>
+            <rect id='missing' width='10' height='10'/>
+            <g fill='none' stroke='none'>
+                <rect id='nested-missing' width='10' height='10'/>
+                <rect id='inherit' width='10' height='10' fill='inherit' stroke='inherit'/>
+                <g>
+                    <rect id='deep-inherit' width='10' height='10'
+                          style='fill:inherit;stroke:inherit'/>
+                </g>
+                <rect id='override' width='10' height='10' fill='green' stroke='green'/>
+            </g>
+        </g>
+    </svg>
+    ";
+
+    let tree = usvg_tree::Tree::from_str(svg, &usvg_parser::Options::default()).unwrap();
+    let paint = |id: &str| {
+        let node = tree.node_by_id(id).unwrap();
+        let path = match *node.borrow() {
+            usvg_tree::NodeKind::Path(ref path) => path.clone(),
+            _ => unreachable!(),
+        };
+        let color = |paint: &usvg_tree::Paint| match paint {
+            usvg_tree::Paint::Color(c) => *c,
+            _ => unreachable!(),
+        };
+        (
+            path.fill.as_ref().map(|f| color(&f.paint)),
+            path.stroke.as_ref().map(|s| color(&s.paint)),
+        )
+    };
+
+    let red = usvg_tree::Color::new_rgb(255, 0, 0);
+    let blue = usvg_tree::Color::new_rgb(0, 0, 255);
+    let green = usvg_tree::Color::new_rgb(0, 128, 0);
+    assert_eq!(paint("none"), (None, None));
+    assert_eq!(paint("missing"), (Some(red), Some(blue)));
+    assert_eq!(paint("nested-missing"), (None, None));
+    assert_eq!(paint("inherit"), (None, None));
+    assert_eq!(paint("deep-inherit"), (None, None));
+    assert_eq!(paint("override"), (Some(green), Some(green)));
+}
+
+/// Returns the orientation of each subpath of a polygonal path.
+fn orientations(path: &usvg_tree::tiny_skia_path::Path) -> Vec<usvg_tree::Orientation> {
+    use usvg_tree::tiny_skia_path::PathSegment;
+
+    let mut areas = Vec::new();
+    let mut start = None;
+    let mut prev = None;
+    for seg in path.segments() {
+        match seg {
+            PathSegment::MoveTo(p) => {
+                areas.push(0.0);
+                start = Some(p);
+                prev = Some(p);
+            }
+            PathSegment::LineTo(p) => {
+                let p0 = prev.unwrap();
+                *areas.last_mut().unwrap() += p0.x * p.y - p.x * p0.y;
+                prev = Some(p);
+            }
+            PathSegment::Close => {
+                let (p0, p) = (prev.unwrap(), start.unwrap());
+                *areas.last_mut().unwrap() += p0.x * p.y - p.x * p0.y;
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    // A positive area is clockwise on a Y-down canvas.
+    areas
+        .into_iter()
+        .map(|area| {
+            if area > 0.0 {
+                usvg_tree::Orientation::Clockwise
+            } else {
+                usvg_tree::Orientation::CounterClockwise
+            }
+        })
+        .collect()
+}
+
+#[test]
+fn normalize_winding_of_rect_with_hole() {
+    use usvg_tree::Orientation::{Clockwise as CW, CounterClockwise as CCW};
+
+    let svg = |rule: &str, hole: &str| {
+        format!(
+            "
+        <svg xmlns='http://www.w3.org/2000/svg' viewBox='0 0 100 100'>
+            <path fill-rule='{}' d='M 10 10 L 90 10 L 90 90 L 10 90 Z {}'/>
+        </svg>
+        ",
+            rule, hole
+        )
+    };
+
+    let cw_hole = "M 30 30 L 70 30 L 70 70 L 30 70 Z";
+    let ccw_hole = "M 30 30 L 30 70 L 70 70 L 70 30 Z";
+
+    let mut path = first_path(&svg("evenodd", cw_hole));
+    assert_eq!(orientations(&path.data), [CW, CW]);
+    path.normalize_winding(CCW);
+    assert_eq!(orientations(&path.data), [CCW, CW]);
+    assert_eq!(area(&path.data), 4800.0);
+
+    let mut path = first_path(&svg("nonzero", ccw_hole));
+    path.normalize_winding(CCW);
+    assert_eq!(orientations(&path.data), [CCW, CW]);
+
+    // With the non-zero rule, an inner subpath with the same direction is not a hole.
+    let mut path = first_path(&svg("nonzero", cw_hole));
+    path.normalize_winding(CCW);
+    assert_eq!(orientations(&path.data), [CCW, CCW]);
+}
+
+#[test]
+fn normalize_winding_of_dashed_path() {
+    let svg = "
+    <svg xmlns='http://www.w3.org/2000/svg' viewBox='0 0 100 100'>
+        <path fill-rule='evenodd' stroke='black' stroke-dasharray='5 10'
+              d='M 10 10 L 90 10 L 90 90 L 10 90 Z M 30 30 L 70 30 L 70 70 L 30 70 Z'/>
+    </svg>
+    ";
+
+    // Reversing a subpath would move its dashes.
+    let mut path = first_path(svg);
+    let data = path.data.clone();
+    path.normalize_winding(usvg_tree::Orientation::CounterClockwise);
+    assert!(std::rc::Rc::ptr_eq(&path.data, &data));
+}
+
+#[test]
+fn normalize_winding_option() {
+    let svg = "
+    <svg xmlns='http://www.w3.org/2000/svg' viewBox='0 0 100 100'>
+        <path id='path1' fill-rule='evenodd'
+              d='M 10 10 L 90 10 L 90 90 L 10 90 Z M 30 30 L 70 30 L 70 70 L 30 70 Z'/>
+        <path id='path2' fill-rule='evenodd' transform='matrix(-1 0 0 1 100 0)'
+              d='M 10 10 L 90 10 L 90 90 L 10 90 Z M 30 30 L 70 30 L 70 70 L 30 70 Z'/>
+    </svg>
+    ";
+
+    let opt = usvg_parser::Options {
+        normalize_winding: true,
+        ..usvg_parser::Options::default()
+    };
+    let tree = usvg_tree::Tree::from_str(svg, &opt).unwrap();
+    let orientations = |id: &str| match *tree.node_by_id(id).unwrap().borrow() {
+        usvg_tree::NodeKind::Path(ref path) => orientations(&path.data),
+        _ => unreachable!(),
+    };
+
+    use usvg_tree::Orientation::{Clockwise as CW, CounterClockwise as CCW};
+    assert_eq!(orientations("path1"), [CCW, CW]);
+    // Mirrored, so it will be counter-clockwise on the canvas.
+    assert_eq!(orientations("path2"), [CW, CCW]);
+}
+
+#[test]
+fn normalize_winding_of_curves() {
+    let svg = "
+    <svg xmlns='http://www.w3.org/2000/svg' viewBox='0 0 100 100'>
+        <path d='M 50 10 C 80 10 90 20 90 50 Q 90 90 50 90 L 50 10 M 10 10 L 20 10'/>
+    </svg>
+    ";
+
+    let mut path = first_path(svg);
+    let bounds = path.data.bounds();
+    path.normalize_winding(usvg_tree::Orientation::CounterClockwise);
+
+    use usvg_tree::tiny_skia_path::{PathSegment, Point};
+    let segments: Vec<_> = path.data.segments().collect();
+    assert_eq!(
+        segments,
+        [
+            PathSegment::MoveTo(Point::from_xy(50.0, 10.0)),
+            PathSegment::LineTo(Point::from_xy(50.0, 90.0)),
+            PathSegment::QuadTo(Point::from_xy(90.0, 90.0), Point::from_xy(90.0, 50.0)),
+            PathSegment::CubicTo(
+                Point::from_xy(90.0, 20.0),
+                Point::from_xy(80.0, 10.0),
+                Point::from_xy(50.0, 10.0)
+            ),
+            // Subpaths without an area are left as is.
+            PathSegment::MoveTo(Point::from_xy(10.0, 10.0)),
+            PathSegment::LineTo(Point::from_xy(20.0, 10.0)),
+        ]
+    );
+    assert_eq!(path.data.bounds(), bounds);
+}
+
+#[test]
+fn root_pseudo_class() {
+    let svg = "
+    <svg viewBox='0 0 100 100' xmlns='http://www.w3.org/2000/svg'>
+        <style>
+            :root { color: blue }
+            :root.unknown-class { color: red }
+            :rooted { color: red }
+        </style>
+        <g>
+            <rect id='rect1' width='10' height='10' fill='currentColor'/>
+        </g>
+    </svg>
+    ";
+
+    let tree = usvg_tree::Tree::from_str(svg, &usvg_parser::Options::default()).unwrap();
+    assert_eq!(
+        fill_color(&tree, "rect1"),
+        usvg_tree::Color::new_rgb(0, 0, 255)
+    );
+}
+
+#[test]
+fn root_pseudo_class_after_comment() {
+    let svg = "
+    <svg viewBox='0 0 100 100' xmlns='http://www.w3.org/2000/svg'>
+        <style>
+            /* don't */
+            :root { color: blue }
+        </style>
+        <rect id='rect1' width='10' height='10' fill='currentColor'/>
+    </svg>
+    ";
+
+    let tree = usvg_tree::Tree::from_str(svg, &usvg_parser::Options::default()).unwrap();
+    assert_eq!(
+        fill_color(&tree, "rect1"),
+        usvg_tree::Color::new_rgb(0, 0, 255)
+    );
+}
+
+#[test]
+fn draw_list_of_grouped_document() {
+    let svg = "
+    <svg viewBox='0 0 200 200' xmlns='http://www.w3.org/2000/svg'>
+        <linearGradient id='lg1' gradientUnits='userSpaceOnUse' x2='10'>
+            <stop offset='0' stop-color='white'/>
+            <stop offset='1' stop-color='black'/>
+        </linearGradient>
+        <g transform='translate(10 20)' opacity='0.5'>
+            <rect id='rect1' width='10' height='10' fill='red' stroke='blue' stroke-width='2'/>
+            <g transform='scale(2)'>
+                <rect id='rect2' x='5' y='5' width='10' height='10'
+                      fill='green' stroke='black' paint-order='stroke'/>
+            </g>
+        </g>
+        <rect id='rect3' width='10' height='10' visibility='hidden'/>
+        <rect id='rect4' width='10' height='10' fill='url(#lg1)' transform='translate(50 50)'/>
+    </svg>
+    ";
+
+    let tree = usvg_tree::Tree::from_str(svg, &usvg_parser::Options::default()).unwrap();
+    let list = tree.to_draw_list();
+
+    let rect = |x, y, w, h| usvg_tree::Rect::from_xywh(x, y, w, h).unwrap();
+    let summary: Vec<_> = list
+        .iter()
+        .map(|cmd| match cmd {
+            usvg_tree::DrawCommand::Fill { id, path, .. } => ("fill", id.as_str(), path.bounds()),
+            usvg_tree::DrawCommand::Stroke { id, path, .. } => {
+                ("stroke", id.as_str(), path.bounds())
+            }
+            usvg_tree::DrawCommand::Image(_) => unreachable!(),
+        })
+        .collect();
+
+    assert_eq!(
+        summary,
+        [
+            ("fill", "rect1", rect(10.0, 20.0, 10.0, 10.0)),
+            ("stroke", "rect1", rect(10.0, 20.0, 10.0, 10.0)),
+            ("stroke", "rect2", rect(20.0, 30.0, 20.0, 20.0)),
+            ("fill", "rect2", rect(20.0, 30.0, 20.0, 20.0)),
+            ("fill", "rect4", rect(50.0, 50.0, 10.0, 10.0)),
+        ]
+    );
+
+    match list[1] {
+        usvg_tree::DrawCommand::Stroke {
+            ref outline,
+            ref stroke,
+            ref paint,
+            ..
+        } => {
+            // The outline is in canvas coordinates, while the stroke width is not.
+            assert_eq!(
+                outline.as_ref().unwrap().bounds(),
+                rect(9.0, 19.0, 12.0, 12.0)
+            );
+            assert_eq!(stroke.width.get(), 2.0);
+            match paint {
+                usvg_tree::ResolvedPaint::Color { color, .. } => {
+                    assert_eq!(*color, usvg_tree::Color::new_rgb(0, 0, 255));
+                }
+                _ => unreachable!(),
+            }
+        }
+        _ => unreachable!(),
+    }
+
+    match list[4] {
+        usvg_tree::DrawCommand::Fill {
+            paint: usvg_tree::ResolvedPaint::LinearGradient { transform, .. },
+            ..
+        } => {
+            // Gradients are in canvas coordinates.
+            assert_eq!(transform, usvg_tree::Transform::from_translate(50.0, 50.0));
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn draw_list_skips_hidden_images() {
+    let svg = "
+    <svg viewBox='0 0 200 200' xmlns='http://www.w3.org/2000/svg'>
+        <image id='image1' width='10' height='10'
+               href='data:image/svg+xml;utf8,&lt;svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"0 0 1 1\"&gt;&lt;rect width=\"1\" height=\"1\"/&gt;&lt;/svg&gt;'/>
+        <image id='image2' width='10' height='10' visibility='hidden'
+               href='data:image/svg+xml;utf8,&lt;svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"0 0 1 1\"&gt;&lt;rect width=\"1\" height=\"1\"/&gt;&lt;/svg&gt;'/>
+    </svg>
+    ";
+
+    let tree = usvg_tree::Tree::from_str(svg, &usvg_parser::Options::default()).unwrap();
+    let ids: Vec<_> = tree
+        .to_draw_list()
+        .into_iter()
+        .map(|cmd| match cmd {
+            usvg_tree::DrawCommand::Image(image) => image.id,
+            _ => unreachable!(),
+        })
+        .collect();
+
+    assert_eq!(ids, ["image1"]);
+}
+
+#[test]
+fn root_overflow() {
+    let parse = |attrs: &str| {
+        let svg = format!(
+            "<svg width='100' height='100' {} xmlns='http://www.w3.org/2000/svg'>
+                <rect width='100' height='100'/>
+            </svg>",
+            attrs
+        );
+        usvg_tree::Tree::from_str(&svg, &usvg_parser::Options::default()).unwrap()
+    };
+
+    assert!(parse("").clip_to_viewport);
+    assert!(parse("overflow='hidden'").clip_to_viewport);
+    assert!(parse("overflow='scroll'").clip_to_viewport);
+    assert!(!parse("overflow='visible'").clip_to_viewport);
+    assert!(!parse("style='overflow:auto'").clip_to_viewport);
+
+    // `clip` is ignored when `overflow` is `visible`,
+    // the same way on the root `svg`, nested `svg` and `symbol` elements.
+    let has_clip_path = |tree: &usvg_tree::Tree| {
+        tree.root.descendants().any(|node| match *node.borrow() {
+            usvg_tree::NodeKind::Group(ref g) => g.clip_path.is_some(),
+            _ => false,
+        })
+    };
+    let clip = "clip='rect(10, 90, 90, 10)'";
+    assert!(has_clip_path(&parse(clip)));
+    assert!(!has_clip_path(&parse(&format!(
+        "{} overflow='visible'",
+        clip
+    ))));
+
+    let parse_nested = |attrs: &str| {
+        let svg = format!(
+            "<svg width='100' height='100' overflow='visible' xmlns='http://www.w3.org/2000/svg'
+                  xmlns:xlink='http://www.w3.org/1999/xlink'>
+                <svg width='50' height='50' {0}>
+                    <rect width='100' height='100'/>
+                </svg>
+                <symbol id='symbol1' {0}>
+                    <rect width='100' height='100'/>
+                </symbol>
+                <use xlink:href='#symbol1' width='50' height='50'/>
+            </svg>",
+            attrs
+        );
+        usvg_tree::Tree::from_str(&svg, &usvg_parser::Options::default()).unwrap()
+    };
+    let clip_paths = |tree: &usvg_tree::Tree| {
+        tree.root
+            .descendants()
+            .filter(|node| match *node.borrow() {
+                usvg_tree::NodeKind::Group(ref g) => g.clip_path.is_some(),
+                _ => false,
+            })
+            .count()
+    };
+    assert_eq!(clip_paths(&parse_nested(clip)), 2);
+    assert_eq!(
+        clip_paths(&parse_nested(&format!("{} overflow='visible'", clip))),
+        0
+    );
+    assert_eq!(
+        clip_paths(&parse_nested(&format!("{} overflow='auto'", clip))),
+        0
+    );
+}
+
+#[test]
+fn font_face_rules() {
+    // The parser only checks the font signature, so four bytes are enough.
+    let svg = "
+    <svg xmlns='http://www.w3.org/2000/svg'>
+        <style>
+            /* @font-face { font-family: Commented; src: url(data:font/ttf;base64,AAEAAA==) } */
+            @font-face {
+                font-family: 'Font } One'; /* } */
+                src: url('data:font/ttf;base64,AAEAAA==');
+            }
+            rect { fill: green }
+            @font-face { font-family: \"Font Two\"; font-weight: bold; src: url(data:font/ttf;base64,AAEAAA==) }
+        </style>
+    </svg>
+    ";
+
+    let tree = usvg_tree::Tree::from_str(svg, &usvg_parser::Options::default()).unwrap();
+    let families: Vec<_> = tree.font_faces.iter().map(|f| f.family.as_str()).collect();
+    assert_eq!(families, ["Font } One", "Font Two"]);
+    assert_eq!(tree.font_faces[1].weight, Some(700));
+}
+
+#[test]
+fn woff_with_invalid_table_length() {
+    let woff_with_orig_length = |orig_length: u32| {
+        let mut data = Vec::new();
+        data.extend_from_slice(b"wOFF");
+        data.extend_from_slice(&0x00010000u32.to_be_bytes()); // flavor
+        data.extend_from_slice(&72u32.to_be_bytes()); // length
+        data.extend_from_slice(&1u16.to_be_bytes()); // numTables
+        data.extend_from_slice(&[0; 26]);
+        data.extend_from_slice(b"glyf");
+        data.extend_from_slice(&64u32.to_be_bytes()); // offset
+        data.extend_from_slice(&8u32.to_be_bytes()); // compLength
+        data.extend_from_slice(&orig_length.to_be_bytes());
+        data.extend_from_slice(&0u32.to_be_bytes()); // checksum
+        data.extend_from_slice(&[0x78, 0x9c, 0x03, 0x00, 0x00, 0x00, 0x00, 0x01]);
+
+        let url: String = data.iter().map(|b| format!("%{:02X}", b)).collect();
+        let svg = format!(
+            "<svg xmlns='http://www.w3.org/2000/svg'>
+                <style>@font-face {{ font-family: Test; src: url(data:font/woff,{}) }}</style>
+            </svg>",
+            url
+        );
+        usvg_tree::Tree::from_str(&svg, &usvg_parser::Options::default()).unwrap()
+    };
+
+    // An empty zlib stream cannot be expanded into gigabytes.
+    assert!(woff_with_orig_length(u32::MAX).font_faces.is_empty());
+    // A compressed table cannot be larger than the original one.
+    assert!(woff_with_orig_length(4).font_faces.is_empty());
+}
+
+#[test]
+fn slash_outside_of_font_shorthand() {
+    let svg = "
+    <svg xmlns='http://www.w3.org/2000/svg' viewBox='0 0 200 200'>
+        <style>
+            #rect1 { stroke: black; stroke-dasharray: 4/2; stroke-width: 3 }
+        </style>
+        <rect id='rect1' width='10' height='10'/>
+        <rect id='rect2' width='10' height='10'
+              style='stroke: black; stroke-dasharray: 4/2; stroke-width: 3'/>
+    </svg>
+    ";
+
+    let tree = usvg_tree::Tree::from_str(svg, &usvg_parser::Options::default()).unwrap();
+    for id in ["rect1", "rect2"] {
+        match *tree.node_by_id(id).unwrap().borrow() {
+            usvg_tree::NodeKind::Path(ref path) => {
+                // An invalid value is ignored and doesn't affect other declarations.
+                let stroke = path.stroke.as_ref().unwrap();
+                assert_eq!(stroke.dasharray, None);
+                assert_eq!(stroke.width.get(), 3.0);
+            }
+            _ => unreachable!(),
+        }
+    }
+}
+
+#[test]
+fn approx_bbox_contains_exact_bbox() {
+    use usvg_tree::NodeExt;
+
+    let svg = "
+    <svg viewBox='0 0 200 200' xmlns='http://www.w3.org/2000/svg'>
+        <g transform='rotate(30 100 100)'>
+            <path d='M 20 100 C 20 -20 180 -20 180 100 C 180 220 20 220 20 100
+                     M 60 100 C 60 60 140 160 140 100 Q 100 0 60 100'/>
+            <circle cx='100' cy='100' r='30' transform='translate(10 20) scale(1.5)'/>
+        </g>
+    </svg>
+    ";
+
+    let tree = usvg_tree::Tree::from_str(svg, &usvg_parser::Options::default()).unwrap();
+    let approx = tree.root.calculate_approx_bbox().unwrap();
+
+    // Calculate the exact bbox by hand, using transformed curves extrema.
+    let mut exact: Option<usvg_tree::Rect> = None;
+    for node in tree.root.descendants() {
+        if let usvg_tree::NodeKind::Path(ref path) = *node.borrow() {
+            let data = (*path.data)
+                .clone()
+                .transform(node.abs_transform())
+                .unwrap();
+            let bbox = data.compute_tight_bounds().unwrap();
+            exact = Some(match exact {
+                Some(r) => usvg_tree::Rect::from_ltrb(
+                    r.left().min(bbox.left()),
+                    r.top().min(bbox.top()),
+                    r.right().max(bbox.right()),
+                    r.bottom().max(bbox.bottom()),
+                )
+                .unwrap(),
+                None => bbox,
+            });
+        }
+    }
+    let exact = exact.unwrap();
+
+    assert!(approx.left() <= exact.left());
+    assert!(approx.top() <= exact.top());
+    assert!(approx.right() >= exact.right());
+    assert!(approx.bottom() >= exact.bottom());
+    // Control points are far outside of the curves.
+    assert!(approx.width() * approx.height() > exact.width() * exact.height() * 1.2);
+
+    // Without transforms and curves both are the same.
+    let svg = "
+    <svg viewBox='0 0 200 200' xmlns='http://www.w3.org/2000/svg'>
+        <rect x='20' y='30' width='100' height='50'/>
+    </svg>
+    ";
+    let tree = usvg_tree::Tree::from_str(svg, &usvg_parser::Options::default()).unwrap();
+    assert_eq!(
+        tree.root.calculate_bbox(),
+        tree.root.calculate_approx_bbox()
+    );
+    assert_eq!(
+        tree.root.calculate_bbox(),
+        usvg_tree::Rect::from_xywh(20.0, 30.0, 100.0, 50.0)
+    );
+}
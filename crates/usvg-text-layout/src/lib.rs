@@ -39,11 +39,179 @@ use usvg_tree::*;
 pub trait TreeTextToPath {
     /// Converts text nodes into paths.
     fn convert_text(&mut self, fontdb: &fontdb::Database);
+
+    /// Converts text nodes into paths using a shaping cache.
+    ///
+    /// The same cache can be reused across multiple trees,
+    /// as long as the same `fontdb` is used.
+    /// The result is identical to [`convert_text`](TreeTextToPath::convert_text).
+    ///
+    /// Trees with embedded fonts, i.e. with a non-empty `Tree::font_faces`,
+    /// are converted using a temporary database and cache, leaving `cache` untouched.
+    ///
+    /// Returns `false` when `cache` was not used because of embedded fonts.
+    fn convert_text_with_cache(
+        &mut self,
+        fontdb: &fontdb::Database,
+        cache: &mut ShapingCache,
+    ) -> bool;
+
+    /// Replaces the content of a text node and converts only this node into paths.
+    ///
+    /// `source` is the original tree, before text-to-path conversion,
+    /// and `self` is a tree with text already converted into paths.
+    /// The text node with the specified ID is taken from `source`, which is left untouched,
+    /// so the same `source` can be used as a template for multiple updates.
+    /// Other nodes of `self` are not modified.
+    ///
+    /// The new string uses the style of the first text span
+    /// and the position of the first character.
+    ///
+    /// Returns `false` when there is no such node in `source` or in `self`.
+    fn replace_text(
+        &mut self,
+        source: &usvg_tree::Tree,
+        id: &str,
+        text: &str,
+        fontdb: &fontdb::Database,
+    ) -> bool;
 }
 
 impl TreeTextToPath for usvg_tree::Tree {
     fn convert_text(&mut self, fontdb: &fontdb::Database) {
-        convert_text(self.root.clone(), fontdb);
+        self.convert_text_with_cache(fontdb, &mut ShapingCache::new());
+    }
+
+    fn convert_text_with_cache(
+        &mut self,
+        fontdb: &fontdb::Database,
+        cache: &mut ShapingCache,
+    ) -> bool {
+        if self.font_faces.is_empty() {
+            convert_text(self.root.clone(), fontdb, cache);
+            true
+        } else {
+            // Embedded fonts must not leak into the caller's database.
+            let mut fontdb = fontdb.clone();
+            load_font_faces(&self.font_faces, &mut fontdb);
+            // Face IDs of embedded fonts are not stable across documents,
+            // therefore we cannot use a shared cache.
+            convert_text(self.root.clone(), &fontdb, &mut ShapingCache::new());
+            false
+        }
+    }
+
+    fn replace_text(
+        &mut self,
+        source: &usvg_tree::Tree,
+        id: &str,
+        text: &str,
+        fontdb: &fontdb::Database,
+    ) -> bool {
+        if source.font_faces.is_empty() {
+            replace_text(self, source, id, text, fontdb)
+        } else {
+            let mut fontdb = fontdb.clone();
+            load_font_faces(&source.font_faces, &mut fontdb);
+            replace_text(self, source, id, text, &fontdb)
+        }
+    }
+}
+
+/// A text shaping cache.
+///
+/// Stores glyph IDs, offsets and advances for each shaped string.
+/// Shaping results are stored in font units, so they do not depend on font size.
+///
+/// Useful when converting many documents that share fonts and strings.
+/// The cache is bound to a specific `fontdb::Database`, since faces are referenced by ID.
+/// Entries are never evicted. Use [`clear`](ShapingCache::clear) to limit memory usage.
+#[derive(Default)]
+pub struct ShapingCache {
+    entries: HashMap<ShapingKey, Vec<Glyph>>,
+    hits: usize,
+    misses: usize,
+}
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+struct ShapingKey {
+    font: ID,
+    small_caps: bool,
+    apply_kerning: bool,
+    features: Vec<FontFeature>,
+    text: String,
+}
+
+impl ShapingCache {
+    /// Creates a new, empty cache.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of cached strings.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Checks that the cache is empty.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the number of shaping requests resolved from the cache.
+    pub fn hits(&self) -> usize {
+        self.hits
+    }
+
+    /// Returns the number of shaping requests that required actual shaping.
+    pub fn misses(&self) -> usize {
+        self.misses
+    }
+
+    /// Removes all cached entries and resets statistics.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.hits = 0;
+        self.misses = 0;
+    }
+}
+
+impl std::fmt::Debug for ShapingCache {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("ShapingCache")
+            .field("len", &self.len())
+            .field("hits", &self.hits)
+            .field("misses", &self.misses)
+            .finish()
+    }
+}
+
+/// Registers fonts embedded via `@font-face` under their CSS family names.
+fn load_font_faces(faces: &[FontFace], fontdb: &mut fontdb::Database) {
+    for face in faces {
+        let ids = fontdb.load_font_source(fontdb::Source::Binary(face.data.clone()));
+        if ids.is_empty() {
+            log::warn!("Failed to load an embedded '{}' font.", face.family);
+        }
+
+        for id in ids {
+            let mut info = match fontdb.face(id) {
+                Some(v) => v.clone(),
+                None => continue,
+            };
+
+            fontdb.remove_face(id);
+
+            info.families = vec![(face.family.clone(), fontdb::Language::English_UnitedStates)];
+            if let Some(style) = face.style {
+                info.style = convert_font_style(style);
+            }
+            if let Some(weight) = face.weight {
+                info.weight = fontdb::Weight(weight);
+            }
+
+            fontdb.push_face_info(info);
+        }
     }
 }
 
@@ -57,27 +225,36 @@ pub trait TextToPath {
 
 impl TextToPath for Text {
     fn convert(&self, fontdb: &fontdb::Database, absolute_ts: Transform) -> Option<Node> {
-        let (new_paths, bbox) = text_to_paths(self, fontdb, absolute_ts)?;
-
-        // Create a group will all paths that was created during text-to-path conversion.
-        let group = Node::new(NodeKind::Group(Group {
-            id: self.id.clone(),
-            transform: self.transform,
-            ..Group::default()
-        }));
-
-        let rendering_mode = resolve_rendering_mode(self);
-        for mut path in new_paths {
-            fix_obj_bounding_box(&mut path, bbox);
-            path.rendering_mode = rendering_mode;
-            group.append_kind(NodeKind::Path(path));
-        }
-
-        Some(group)
+        convert_text_node(self, fontdb, absolute_ts, &mut ShapingCache::new())
     }
 }
 
-fn convert_text(root: Node, fontdb: &fontdb::Database) {
+fn convert_text_node(
+    text: &Text,
+    fontdb: &fontdb::Database,
+    absolute_ts: Transform,
+    cache: &mut ShapingCache,
+) -> Option<Node> {
+    let (new_paths, bbox) = text_to_paths(text, fontdb, absolute_ts, cache)?;
+
+    // Create a group will all paths that was created during text-to-path conversion.
+    let group = Node::new(NodeKind::Group(Group {
+        id: text.id.clone(),
+        transform: text.transform,
+        ..Group::default()
+    }));
+
+    let rendering_mode = resolve_rendering_mode(text);
+    for mut path in new_paths {
+        fix_obj_bounding_box(&mut path, bbox);
+        path.rendering_mode = rendering_mode;
+        group.append_kind(NodeKind::Path(path));
+    }
+
+    Some(group)
+}
+
+fn convert_text(root: Node, fontdb: &fontdb::Database, cache: &mut ShapingCache) {
     let mut text_nodes = Vec::new();
     // We have to update text nodes in clipPaths, masks and patterns as well.
     for node in root.descendants() {
@@ -85,7 +262,7 @@ fn convert_text(root: Node, fontdb: &fontdb::Database) {
             text_nodes.push(node.clone());
         }
 
-        node.subroots(|subroot| convert_text(subroot, fontdb))
+        node.subroots(|subroot| convert_text(subroot, fontdb, cache))
     }
 
     if text_nodes.is_empty() {
@@ -97,7 +274,7 @@ fn convert_text(root: Node, fontdb: &fontdb::Database) {
         if let NodeKind::Text(ref text) = *node.borrow() {
             let mut absolute_ts = node.parent().unwrap().abs_transform();
             absolute_ts = absolute_ts.pre_concat(text.transform);
-            new_node = text.convert(fontdb, absolute_ts);
+            new_node = convert_text_node(text, fontdb, absolute_ts, cache);
         }
 
         if let Some(new_node) = new_node {
@@ -108,6 +285,89 @@ fn convert_text(root: Node, fontdb: &fontdb::Database) {
     text_nodes.iter().for_each(|n| n.detach());
 }
 
+fn replace_text(
+    tree: &usvg_tree::Tree,
+    source: &usvg_tree::Tree,
+    id: &str,
+    string: &str,
+    fontdb: &fontdb::Database,
+) -> bool {
+    let source_node = match source.node_by_id(id) {
+        Some(v) => v,
+        None => return false,
+    };
+
+    let node = match tree.node_by_id(id) {
+        Some(v) => v,
+        None => return false,
+    };
+
+    let new_node = match *source_node.borrow() {
+        NodeKind::Text(ref text) => {
+            let text = replace_string(text, string);
+            let mut absolute_ts = source_node.parent().unwrap().abs_transform();
+            absolute_ts = absolute_ts.pre_concat(text.transform);
+            convert_text_node(&text, fontdb, absolute_ts, &mut ShapingCache::new())
+        }
+        _ => return false,
+    };
+
+    // Just like during regular conversion, a text without glyphs is removed.
+    if let Some(new_node) = new_node {
+        node.insert_after(new_node);
+    }
+
+    node.detach();
+    true
+}
+
+/// Creates a copy of a text with a new string.
+///
+/// The whole string will use the style of the first span.
+fn replace_string(text: &Text, string: &str) -> Text {
+    let count = string.chars().count();
+
+    let mut positions = vec![
+        CharacterPosition {
+            x: None,
+            y: None,
+            dx: None,
+            dy: None,
+        };
+        count
+    ];
+    if let (Some(pos), Some(first)) = (positions.first_mut(), text.positions.first()) {
+        *pos = *first;
+    }
+
+    // The last angle is used for all remaining characters, just like in SVG.
+    let rotate = (0..count)
+        .map(|i| text.rotate.get(i).or(text.rotate.last()).copied())
+        .map(|a| a.unwrap_or(0.0))
+        .collect();
+
+    let chunks = match text.chunks.first() {
+        Some(chunk) => {
+            let mut chunk = chunk.clone();
+            chunk.spans.truncate(1);
+            for span in &mut chunk.spans {
+                span.start = 0;
+                span.end = string.len();
+            }
+            chunk.text = string.to_string();
+            vec![chunk]
+        }
+        None => Vec::new(),
+    };
+
+    Text {
+        positions,
+        rotate,
+        chunks,
+        ..text.clone()
+    }
+}
+
 trait DatabaseExt {
     fn load_font(&self, id: ID) -> Option<ResolvedFont>;
     fn outline(&self, id: ID, glyph_id: GlyphId) -> Option<tiny_skia_path::Path>;
@@ -124,6 +384,7 @@ impl DatabaseExt for Database {
 
             let ascent = font.ascender();
             let descent = font.descender();
+            let line_gap = font.line_gap();
 
             let x_height = font
                 .x_height()
@@ -178,6 +439,7 @@ impl DatabaseExt for Database {
                 units_per_em,
                 ascent,
                 descent,
+                line_gap,
                 x_height,
                 underline_position,
                 underline_thickness,
@@ -222,6 +484,7 @@ struct ResolvedFont {
     // All values below are in font units.
     ascent: i16,
     descent: i16,
+    line_gap: i16,
     x_height: NonZeroU16,
 
     underline_position: i16,
@@ -257,6 +520,12 @@ impl ResolvedFont {
         self.ascent(font_size) - self.descent(font_size)
     }
 
+    /// Returns a distance between baselines of two lines.
+    #[inline]
+    fn line_height(&self, font_size: f32) -> f32 {
+        self.height(font_size) + self.line_gap as f32 * self.scale(font_size)
+    }
+
     #[inline]
     fn x_height(&self, font_size: f32) -> f32 {
         self.x_height.get() as f32 * self.scale(font_size)
@@ -459,6 +728,7 @@ fn text_to_paths(
     text_node: &Text,
     fontdb: &fontdb::Database,
     abs_ts: Transform,
+    cache: &mut ShapingCache,
 ) -> Option<(Vec<Path>, Rect)> {
     let mut fonts_cache: FontsCache = HashMap::new();
     for chunk in &text_node.chunks {
@@ -471,6 +741,18 @@ fn text_to_paths(
         }
     }
 
+    let wrapped_text;
+    let text_node = match text_node.inline_size {
+        Some(inline_size) => match wrap_text(text_node, inline_size, &fonts_cache, fontdb, cache) {
+            Some(text) => {
+                wrapped_text = text;
+                &wrapped_text
+            }
+            None => text_node,
+        },
+        None => text_node,
+    };
+
     let mut bbox = BBox::default();
     let mut char_offset = 0;
     let mut last_x = 0.0;
@@ -482,7 +764,7 @@ fn text_to_paths(
             TextFlow::Path(_) => (0.0, 0.0),
         };
 
-        let mut clusters = outline_chunk(chunk, &fonts_cache, fontdb);
+        let mut clusters = outline_chunk(chunk, &fonts_cache, fontdb, cache);
         if clusters.is_empty() {
             char_offset += chunk.text.chars().count();
             continue;
@@ -601,6 +883,136 @@ fn text_to_paths(
     Some((new_paths, bbox))
 }
 
+/// Splits a text into lines no longer than `inline_size`.
+///
+/// A basic greedy line breaking at spaces, which implements SVG 2 `inline-size`.
+/// Each line becomes a separate text chunk, therefore `text-anchor` is applied per line.
+/// Spaces at line breaks are removed and words longer than `inline_size` overflow.
+/// Lines are separated by the largest line height of all used fonts.
+///
+/// Only horizontal text with a single linear chunk is supported.
+///
+/// Returns `None` when a text should not be changed.
+fn wrap_text(
+    text: &Text,
+    inline_size: f32,
+    fonts_cache: &FontsCache,
+    fontdb: &fontdb::Database,
+    cache: &mut ShapingCache,
+) -> Option<Text> {
+    let chunk = match text.chunks.as_slice() {
+        [chunk] if matches!(chunk.text_flow, TextFlow::Linear) => chunk,
+        _ => {
+            log::warn!("'inline-size' on text with multiple chunks is not supported.");
+            return None;
+        }
+    };
+
+    if text.writing_mode != WritingMode::LeftToRight {
+        log::warn!("'inline-size' on vertical text is not supported.");
+        return None;
+    }
+
+    let mut clusters = outline_chunk(chunk, fonts_cache, fontdb, cache);
+    apply_letter_spacing(chunk, &mut clusters);
+    apply_word_spacing(chunk, &mut clusters);
+    // Clusters are in the visual order, but lines must be filled in the logical one.
+    clusters.sort_by_key(|c| c.byte_idx.value());
+
+    let mut lines = Vec::new();
+    let mut line_start = 0;
+    let mut line_width = 0.0;
+    // The last space on the current line and the line width up to and including it.
+    let mut last_space: Option<(usize, f32)> = None;
+    for cluster in &clusters {
+        line_width += cluster.advance;
+
+        let idx = cluster.byte_idx.value();
+        if cluster.codepoint == ' ' {
+            if idx > line_start {
+                last_space = Some((idx, line_width));
+            }
+
+            continue;
+        }
+
+        if line_width > inline_size {
+            if let Some((space_idx, width)) = last_space.take() {
+                lines.push(line_start..space_idx);
+                line_start = space_idx + 1;
+                line_width -= width;
+            }
+        }
+    }
+    lines.push(line_start..chunk.text.len());
+
+    if lines.len() == 1 {
+        return None;
+    }
+
+    let line_height = chunk
+        .spans
+        .iter()
+        .filter_map(|span| {
+            let font = fonts_cache.get(&span.font)?;
+            Some(font.line_height(span.font_size.get()))
+        })
+        .fold(0.0, f32::max);
+
+    let x = chunk.x.unwrap_or(0.0);
+    let y = chunk.y.unwrap_or(0.0);
+    let mut chunks = Vec::new();
+    for (i, range) in lines.iter().enumerate() {
+        let spans = chunk
+            .spans
+            .iter()
+            .filter(|span| span.start < range.end && span.end > range.start)
+            .map(|span| TextSpan {
+                start: span.start.max(range.start) - range.start,
+                end: span.end.min(range.end) - range.start,
+                ..span.clone()
+            })
+            .collect();
+
+        chunks.push(TextChunk {
+            x: Some(x),
+            y: Some(y + line_height * i as f32),
+            anchor: chunk.anchor,
+            spans,
+            text_flow: TextFlow::Linear,
+            text: chunk.text[range.clone()].to_string(),
+        });
+    }
+
+    // Remove positions and angles of removed spaces.
+    let removed: Vec<usize> = lines
+        .iter()
+        .skip(1)
+        .map(|range| ByteIndex::new(range.start - 1).code_point_at(&chunk.text))
+        .collect();
+    let positions = text
+        .positions
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| !removed.contains(i))
+        .map(|(_, p)| *p)
+        .collect();
+    let rotate = text
+        .rotate
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| !removed.contains(i))
+        .map(|(_, a)| *a)
+        .collect();
+
+    Some(Text {
+        positions,
+        rotate,
+        chunks,
+        ..text.clone()
+    })
+}
+
 fn resolve_font(font: &Font, fontdb: &fontdb::Database) -> Option<ResolvedFont> {
     let mut name_list = Vec::new();
     for family in &font.families {
@@ -629,17 +1041,11 @@ fn resolve_font(font: &Font, fontdb: &fontdb::Database) -> Option<ResolvedFont>
         FontStretch::UltraExpanded => fontdb::Stretch::UltraExpanded,
     };
 
-    let style = match font.style {
-        FontStyle::Normal => fontdb::Style::Normal,
-        FontStyle::Italic => fontdb::Style::Italic,
-        FontStyle::Oblique => fontdb::Style::Oblique,
-    };
-
     let query = fontdb::Query {
         families: &name_list,
         weight: fontdb::Weight(font.weight),
         stretch,
-        style,
+        style: convert_font_style(font.style),
     };
 
     let id = fontdb.query(&query);
@@ -650,6 +1056,14 @@ fn resolve_font(font: &Font, fontdb: &fontdb::Database) -> Option<ResolvedFont>
     fontdb.load_font(id?)
 }
 
+fn convert_font_style(style: FontStyle) -> fontdb::Style {
+    match style {
+        FontStyle::Normal => fontdb::Style::Normal,
+        FontStyle::Italic => fontdb::Style::Italic,
+        FontStyle::Oblique => fontdb::Style::Oblique,
+    }
+}
+
 fn convert_span(
     span: &TextSpan,
     clusters: &mut [OutlinedCluster],
@@ -844,6 +1258,7 @@ fn paint_server_to_user_space_on_use(paint: Paint, bbox: Rect) -> Option<Paint>
                     transform,
                     spread_method: lg.spread_method,
                     stops: lg.stops.clone(),
+                    color_interpolation: lg.color_interpolation,
                 },
             }))
         }
@@ -861,6 +1276,23 @@ fn paint_server_to_user_space_on_use(paint: Paint, bbox: Rect) -> Option<Paint>
                     transform,
                     spread_method: rg.spread_method,
                     stops: rg.stops.clone(),
+                    color_interpolation: rg.color_interpolation,
+                },
+            }))
+        }
+        Paint::ConicGradient(ref cg) => {
+            let transform = cg.transform.post_concat(ts);
+            Paint::ConicGradient(Rc::new(ConicGradient {
+                id: String::new(),
+                cx: cg.cx,
+                cy: cg.cy,
+                angle: cg.angle,
+                base: BaseGradient {
+                    units: Units::UserSpaceOnUse,
+                    transform,
+                    spread_method: cg.spread_method,
+                    stops: cg.stops.clone(),
+                    color_interpolation: cg.color_interpolation,
                 },
             }))
         }
@@ -1034,6 +1466,7 @@ fn outline_chunk(
     chunk: &TextChunk,
     fonts_cache: &FontsCache,
     fontdb: &fontdb::Database,
+    cache: &mut ShapingCache,
 ) -> Vec<OutlinedCluster> {
     let mut glyphs = Vec::new();
     for span in &chunk.spans {
@@ -1042,13 +1475,7 @@ fn outline_chunk(
             None => continue,
         };
 
-        let tmp_glyphs = shape_text(
-            &chunk.text,
-            font,
-            span.small_caps,
-            span.apply_kerning,
-            fontdb,
-        );
+        let tmp_glyphs = shape_text(&chunk.text, font, span, fontdb, cache);
 
         // Do nothing with the first run.
         if glyphs.is_empty() {
@@ -1091,12 +1518,12 @@ fn outline_chunk(
 fn shape_text(
     text: &str,
     font: Rc<ResolvedFont>,
-    small_caps: bool,
-    apply_kerning: bool,
+    span: &TextSpan,
     fontdb: &fontdb::Database,
+    cache: &mut ShapingCache,
 ) -> Vec<Glyph> {
-    let mut glyphs = shape_text_with_font(text, font.clone(), small_caps, apply_kerning, fontdb)
-        .unwrap_or_default();
+    let mut glyphs =
+        shape_text_with_font(text, font.clone(), span, fontdb, cache).unwrap_or_default();
 
     // Remember all fonts used for shaping.
     let mut used_fonts = vec![font.id];
@@ -1118,14 +1545,9 @@ fn shape_text(
             };
 
             // Shape again, using a new font.
-            let fallback_glyphs = shape_text_with_font(
-                text,
-                fallback_font.clone(),
-                small_caps,
-                apply_kerning,
-                fontdb,
-            )
-            .unwrap_or_default();
+            let fallback_glyphs =
+                shape_text_with_font(text, fallback_font.clone(), span, fontdb, cache)
+                    .unwrap_or_default();
 
             let all_matched = fallback_glyphs.iter().all(|g| !g.is_missing());
             if all_matched {
@@ -1172,14 +1594,40 @@ fn shape_text(
     glyphs
 }
 
+/// Converts a text into a list of glyph IDs using a shaping cache.
+fn shape_text_with_font(
+    text: &str,
+    font: Rc<ResolvedFont>,
+    span: &TextSpan,
+    fontdb: &fontdb::Database,
+    cache: &mut ShapingCache,
+) -> Option<Vec<Glyph>> {
+    let key = ShapingKey {
+        font: font.id,
+        small_caps: span.small_caps,
+        apply_kerning: span.apply_kerning,
+        features: span.font_features.clone(),
+        text: text.to_string(),
+    };
+
+    if let Some(glyphs) = cache.entries.get(&key) {
+        cache.hits += 1;
+        return Some(glyphs.clone());
+    }
+
+    cache.misses += 1;
+    let glyphs = shape_text_with_font_impl(text, font, span, fontdb)?;
+    cache.entries.insert(key, glyphs.clone());
+    Some(glyphs)
+}
+
 /// Converts a text into a list of glyph IDs.
 ///
 /// This function will do the BIDI reordering and text shaping.
-fn shape_text_with_font(
+fn shape_text_with_font_impl(
     text: &str,
     font: Rc<ResolvedFont>,
-    small_caps: bool,
-    apply_kerning: bool,
+    span: &TextSpan,
     fontdb: &fontdb::Database,
 ) -> Option<Vec<Glyph>> {
     fontdb.with_face_data(font.id, |font_data, face_index| -> Option<Vec<Glyph>> {
@@ -1209,7 +1657,7 @@ fn shape_text_with_font(
             buffer.set_direction(hb_direction);
 
             let mut features = Vec::new();
-            if small_caps {
+            if span.small_caps {
                 features.push(rustybuzz::Feature::new(
                     rustybuzz::Tag::from_bytes(b"smcp"),
                     1,
@@ -1217,7 +1665,7 @@ fn shape_text_with_font(
                 ));
             }
 
-            if !apply_kerning {
+            if !span.apply_kerning {
                 features.push(rustybuzz::Feature::new(
                     rustybuzz::Tag::from_bytes(b"kern"),
                     0,
@@ -1225,6 +1673,15 @@ fn shape_text_with_font(
                 ));
             }
 
+            // Applied last, so they can override the features above.
+            for feature in &span.font_features {
+                features.push(rustybuzz::Feature::new(
+                    rustybuzz::Tag::from_bytes(&feature.tag),
+                    feature.value,
+                    ..,
+                ));
+            }
+
             let output = rustybuzz::shape(&rb_font, &features, buffer);
 
             let positions = output.glyph_positions();
@@ -0,0 +1,432 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Boolean operations on paths.
+//!
+//! Curves are flattened first, therefore the result always consists of line segments only.
+//!
+//! The implementation is based on a planar arrangement: all edges are split at their
+//! intersections, each resulting edge is classified using the winding numbers
+//! of both operands on each side of it, and the edges that separate the inside
+//! from the outside of the result are chained back into closed contours.
+
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use tiny_skia_path::{PathBuilder, PathSegment, Transform};
+
+use crate::{FillRule, Path};
+
+/// Curves flattening tolerance in user units.
+const FLATTEN_TOLERANCE: f64 = 0.05;
+
+/// Flattened edges shorter than this are removed.
+const MIN_EDGE_LENGTH: f64 = 1e-6;
+
+/// Relative tolerance used for intersection tests.
+const EPSILON: f64 = 1e-9;
+
+/// Computes a union of two paths.
+///
+/// See [`path_boolean`] for details.
+pub fn path_union(a: &Path, b: &Path) -> Option<Path> {
+    path_boolean(a, b, BooleanOp::Union)
+}
+
+/// Computes an intersection of two paths.
+///
+/// See [`path_boolean`] for details.
+pub fn path_intersection(a: &Path, b: &Path) -> Option<Path> {
+    path_boolean(a, b, BooleanOp::Intersection)
+}
+
+/// Subtracts the second path from the first one.
+///
+/// See [`path_boolean`] for details.
+pub fn path_difference(a: &Path, b: &Path) -> Option<Path> {
+    path_boolean(a, b, BooleanOp::Difference)
+}
+
+/// Computes an exclusive or of two paths.
+///
+/// See [`path_boolean`] for details.
+pub fn path_xor(a: &Path, b: &Path) -> Option<Path> {
+    path_boolean(a, b, BooleanOp::Xor)
+}
+
+/// A boolean operation kind.
+#[allow(missing_docs)]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum BooleanOp {
+    Union,
+    Intersection,
+    Difference,
+    Xor,
+}
+
+impl BooleanOp {
+    fn apply(self, a: bool, b: bool) -> bool {
+        match self {
+            BooleanOp::Union => a || b,
+            BooleanOp::Intersection => a && b,
+            BooleanOp::Difference => a && !b,
+            BooleanOp::Xor => a != b,
+        }
+    }
+}
+
+/// Applies a boolean operation to the fill areas of two paths.
+///
+/// The fill area of each path is defined by its `fill-rule`,
+/// or by `nonzero` when the path has no fill.
+/// Open subpaths are treated as implicitly closed, just like during filling.
+/// Path transforms are applied, so the result has an identity transform.
+///
+/// The result inherits all other properties, like fill and stroke, from the first path.
+/// Its contours never overlap and holes are oriented in the opposite direction,
+/// so it can be filled using any fill rule.
+///
+/// Returns `None` when the result is empty.
+pub fn path_boolean(a: &Path, b: &Path, op: BooleanOp) -> Option<Path> {
+    let a_edges = flatten(a)?;
+    let b_edges = flatten(b)?;
+    let a_rule = a.fill.as_ref().map(|f| f.rule).unwrap_or_default();
+    let b_rule = b.fill.as_ref().map(|f| f.rule).unwrap_or_default();
+
+    let mut edges = Vec::with_capacity(a_edges.len() + b_edges.len());
+    edges.extend_from_slice(&a_edges);
+    edges.extend_from_slice(&b_edges);
+
+    let mut result = Vec::new();
+    for edge in split_edges(&edges) {
+        let d = edge.1 - edge.0;
+        let len = d.length();
+        // A normal that points to the left of the edge.
+        let n = Vec2::new(-d.y / len, d.x / len);
+        let offset = (len * 0.25).min(1e-4);
+        let mid = Vec2::new((edge.0.x + edge.1.x) * 0.5, (edge.0.y + edge.1.y) * 0.5);
+
+        let is_inside = |p: Vec2| {
+            let in_a = is_filled(winding(&a_edges, p), a_rule);
+            let in_b = is_filled(winding(&b_edges, p), b_rule);
+            op.apply(in_a, in_b)
+        };
+
+        let left = is_inside(mid + n * offset);
+        let right = is_inside(mid - n * offset);
+        if left != right {
+            // Keep the result inside on the left side of each edge.
+            if left {
+                result.push(edge);
+            } else {
+                result.push((edge.1, edge.0));
+            }
+        }
+    }
+
+    let data = build_contours(&result)?;
+    Some(Path {
+        transform: Transform::default(),
+        text_bbox: None,
+        data: Rc::new(data),
+        ..a.clone()
+    })
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct Vec2 {
+    x: f64,
+    y: f64,
+}
+
+impl Vec2 {
+    fn new(x: f64, y: f64) -> Self {
+        Vec2 { x, y }
+    }
+
+    fn cross(self, other: Self) -> f64 {
+        self.x * other.y - self.y * other.x
+    }
+
+    fn dot(self, other: Self) -> f64 {
+        self.x * other.x + self.y * other.y
+    }
+
+    fn length(self) -> f64 {
+        self.x.hypot(self.y)
+    }
+
+    fn key(self) -> (u64, u64) {
+        // Make sure that -0 and 0 are the same point.
+        ((self.x + 0.0).to_bits(), (self.y + 0.0).to_bits())
+    }
+}
+
+impl std::ops::Add for Vec2 {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Vec2::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl std::ops::Sub for Vec2 {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Vec2::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl std::ops::Mul<f64> for Vec2 {
+    type Output = Self;
+
+    fn mul(self, k: f64) -> Self {
+        Vec2::new(self.x * k, self.y * k)
+    }
+}
+
+type Edge = (Vec2, Vec2);
+
+/// Flattens a path into a list of edges of closed polygons.
+fn flatten(path: &Path) -> Option<Vec<Edge>> {
+    let data = path.data.as_ref().clone().transform(path.transform)?;
+
+    let mut bez = kurbo::BezPath::new();
+    for seg in data.segments() {
+        let p = |p: tiny_skia_path::Point| kurbo::Point::new(p.x as f64, p.y as f64);
+        match seg {
+            PathSegment::MoveTo(p0) => bez.move_to(p(p0)),
+            PathSegment::LineTo(p0) => bez.line_to(p(p0)),
+            PathSegment::QuadTo(p1, p0) => bez.quad_to(p(p1), p(p0)),
+            PathSegment::CubicTo(p1, p2, p0) => bez.curve_to(p(p1), p(p2), p(p0)),
+            PathSegment::Close => bez.close_path(),
+        }
+    }
+
+    let mut polygons = Vec::new();
+    let mut polygon = Vec::new();
+    bez.flatten(FLATTEN_TOLERANCE, |el| match el {
+        kurbo::PathEl::MoveTo(p) => {
+            polygons.push(std::mem::take(&mut polygon));
+            polygon.push(Vec2::new(p.x, p.y));
+        }
+        kurbo::PathEl::LineTo(p) => polygon.push(Vec2::new(p.x, p.y)),
+        kurbo::PathEl::ClosePath => polygons.push(std::mem::take(&mut polygon)),
+        // Not produced by flattening.
+        kurbo::PathEl::QuadTo(..) | kurbo::PathEl::CurveTo(..) => {}
+    });
+    polygons.push(polygon);
+
+    let mut edges = Vec::new();
+    for mut polygon in polygons {
+        // Flattening can produce almost identical points, which are useless
+        // and cannot be classified afterwards.
+        polygon.dedup_by(|p, prev| (*p - *prev).length() <= MIN_EDGE_LENGTH);
+        while polygon.len() > 1
+            && (polygon[polygon.len() - 1] - polygon[0]).length() <= MIN_EDGE_LENGTH
+        {
+            polygon.pop();
+        }
+
+        if polygon.len() < 3 {
+            continue;
+        }
+
+        for (i, p) in polygon.iter().enumerate() {
+            edges.push((*p, polygon[(i + 1) % polygon.len()]));
+        }
+    }
+
+    Some(edges)
+}
+
+/// Splits edges at all intersections and removes duplicates.
+fn split_edges(edges: &[Edge]) -> Vec<Edge> {
+    let mut splits: Vec<Vec<(f64, Vec2)>> = vec![Vec::new(); edges.len()];
+    for i in 0..edges.len() {
+        for j in i + 1..edges.len() {
+            intersect(edges, i, j, &mut splits);
+        }
+    }
+
+    let mut seen = HashMap::new();
+    let mut result = Vec::new();
+    for (edge, mut points) in edges.iter().zip(splits) {
+        points.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        let mut prev = edge.0;
+        for p in points.into_iter().map(|(_, p)| p).chain(Some(edge.1)) {
+            if p == prev {
+                continue;
+            }
+
+            // Edges shared by both operands or overlapping edges
+            // must be processed only once.
+            let key = if prev.key() < p.key() {
+                (prev.key(), p.key())
+            } else {
+                (p.key(), prev.key())
+            };
+
+            if seen.insert(key, ()).is_none() {
+                result.push((prev, p));
+            }
+
+            prev = p;
+        }
+    }
+
+    result
+}
+
+fn intersect(edges: &[Edge], i: usize, j: usize, splits: &mut [Vec<(f64, Vec2)>]) {
+    let (p, p2) = edges[i];
+    let (q, q2) = edges[j];
+
+    if p.x.max(p2.x) < q.x.min(q2.x)
+        || q.x.max(q2.x) < p.x.min(p2.x)
+        || p.y.max(p2.y) < q.y.min(q2.y)
+        || q.y.max(q2.y) < p.y.min(p2.y)
+    {
+        return;
+    }
+
+    let r = p2 - p;
+    let s = q2 - q;
+    let denom = r.cross(s);
+    let is_interior = |t: f64| t > EPSILON && t < 1.0 - EPSILON;
+
+    if denom.abs() > EPSILON * r.length() * s.length() {
+        let t = (q - p).cross(s) / denom;
+        let u = (q - p).cross(r) / denom;
+        if !(-EPSILON..=1.0 + EPSILON).contains(&t) || !(-EPSILON..=1.0 + EPSILON).contains(&u) {
+            return;
+        }
+
+        match (is_interior(t), is_interior(u)) {
+            (true, true) => {
+                let x = p + r * t;
+                splits[i].push((t, x));
+                splits[j].push((u, x));
+            }
+            // An endpoint of one edge touches the other one.
+            // Use exact endpoint coordinates, so both edges will share the same vertex.
+            (true, false) => splits[i].push((t, if u < 0.5 { q } else { q2 })),
+            (false, true) => splits[j].push((u, if t < 0.5 { p } else { p2 })),
+            (false, false) => {}
+        }
+    } else if (q - p).cross(r).abs() <= EPSILON * r.dot(r).max(1.0) {
+        // Collinear edges. Split them at each other's endpoints.
+        for v in [q, q2] {
+            let t = (v - p).dot(r) / r.dot(r);
+            if is_interior(t) {
+                splits[i].push((t, v));
+            }
+        }
+
+        for v in [p, p2] {
+            let u = (v - q).dot(s) / s.dot(s);
+            if is_interior(u) {
+                splits[j].push((u, v));
+            }
+        }
+    }
+}
+
+/// Computes a winding number of a point using a horizontal ray.
+fn winding(edges: &[Edge], p: Vec2) -> i32 {
+    let mut winding = 0;
+    for (a, b) in edges {
+        let side = (*b - *a).cross(p - *a);
+        if a.y <= p.y {
+            if b.y > p.y && side > 0.0 {
+                winding += 1;
+            }
+        } else if b.y <= p.y && side < 0.0 {
+            winding -= 1;
+        }
+    }
+
+    winding
+}
+
+fn is_filled(winding: i32, rule: FillRule) -> bool {
+    match rule {
+        FillRule::NonZero => winding != 0,
+        FillRule::EvenOdd => winding % 2 != 0,
+    }
+}
+
+/// Chains directed edges into closed contours.
+fn build_contours(edges: &[Edge]) -> Option<tiny_skia_path::Path> {
+    let mut outgoing: HashMap<(u64, u64), Vec<usize>> = HashMap::new();
+    for (idx, edge) in edges.iter().enumerate() {
+        outgoing.entry(edge.0.key()).or_default().push(idx);
+    }
+
+    let mut used = vec![false; edges.len()];
+    let mut builder = PathBuilder::new();
+    for first in 0..edges.len() {
+        if used[first] {
+            continue;
+        }
+
+        used[first] = true;
+        let start = edges[first].0;
+        let mut contour = vec![start];
+        let mut end = edges[first].1;
+        while end != start {
+            let next = outgoing
+                .get(&end.key())
+                .and_then(|list| list.iter().copied().find(|idx| !used[*idx]));
+
+            match next {
+                Some(idx) => {
+                    used[idx] = true;
+                    contour.push(end);
+                    end = edges[idx].1;
+                }
+                // An open chain. Can happen only because of numeric errors.
+                None => break,
+            }
+        }
+
+        if end != start {
+            continue;
+        }
+
+        let contour = remove_collinear(contour);
+        if contour.len() < 3 {
+            continue;
+        }
+
+        builder.move_to(contour[0].x as f32, contour[0].y as f32);
+        for p in &contour[1..] {
+            builder.line_to(p.x as f32, p.y as f32);
+        }
+        builder.close();
+    }
+
+    builder.finish()
+}
+
+/// Removes vertices that lie on a straight line between their neighbors.
+fn remove_collinear(mut points: Vec<Vec2>) -> Vec<Vec2> {
+    let mut i = 0;
+    while points.len() > 2 && i < points.len() {
+        let prev = points[(i + points.len() - 1) % points.len()];
+        let next = points[(i + 1) % points.len()];
+        let d1 = points[i] - prev;
+        let d2 = next - points[i];
+        if d1.cross(d2).abs() <= EPSILON * d1.length() * d2.length() && d1.dot(d2) > 0.0 {
+            points.remove(i);
+            i = i.saturating_sub(1);
+        } else {
+            i += 1;
+        }
+    }
+
+    points
+}
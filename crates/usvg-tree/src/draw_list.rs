@@ -0,0 +1,182 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use tiny_skia_path::{PathStroker, Transform};
+
+use crate::{
+    EmbeddedImage, FillRule, LineCap, LineJoin, Node, NodeExt, NodeKind, PaintOrder, ResolvedPaint,
+    Stroke, Tree, Visibility,
+};
+
+/// A draw command.
+///
+/// Returned by [`Tree::to_draw_list`].
+#[derive(Clone, Debug)]
+pub enum DrawCommand {
+    /// A filled path.
+    Fill {
+        /// Element's ID.
+        ///
+        /// Can be empty.
+        id: String,
+        /// A path in canvas coordinates.
+        path: tiny_skia_path::Path,
+        /// A fill rule.
+        rule: FillRule,
+        /// A fill paint.
+        ///
+        /// Gradients are in canvas coordinates. Patterns are left in the element's user space.
+        paint: ResolvedPaint,
+        /// Absolute transform that maps the element's user space onto the canvas.
+        ///
+        /// Already applied to `path` and `paint`.
+        abs_transform: Transform,
+    },
+
+    /// A stroked path.
+    Stroke {
+        /// Element's ID.
+        ///
+        /// Can be empty.
+        id: String,
+        /// A path in canvas coordinates.
+        path: tiny_skia_path::Path,
+        /// A stroke outline in canvas coordinates, with dashes applied.
+        ///
+        /// Filling it using the non-zero rule produces the stroke.
+        /// `None` when the outline cannot be built.
+        outline: Option<tiny_skia_path::Path>,
+        /// Stroke properties in the element's user space.
+        ///
+        /// Unlike `path`, they are not affected by `abs_transform`.
+        stroke: Stroke,
+        /// A stroke paint.
+        ///
+        /// Gradients are in canvas coordinates. Patterns are left in the element's user space.
+        paint: ResolvedPaint,
+        /// Absolute transform that maps the element's user space onto the canvas.
+        ///
+        /// Already applied to `path`, `outline` and `paint`.
+        abs_transform: Transform,
+    },
+
+    /// An image.
+    Image(EmbeddedImage),
+}
+
+impl Tree {
+    /// Flattens the tree into a list of draw commands.
+    ///
+    /// Commands are in the painting order and have absolute transforms applied,
+    /// which is useful for exporting to formats without grouping.
+    ///
+    /// Group opacity, blending, clip paths, masks and filters are ignored.
+    /// Hidden paths and images, and elements inside clip paths, masks, patterns and filters
+    /// are not included. Text must be converted into paths first.
+    pub fn to_draw_list(&self) -> Vec<DrawCommand> {
+        let mut list = Vec::new();
+        for node in self.root.descendants() {
+            collect_commands(&node, &mut list);
+        }
+
+        list
+    }
+}
+
+fn collect_commands(node: &Node, list: &mut Vec<DrawCommand>) {
+    match *node.borrow() {
+        NodeKind::Path(ref path) => {
+            if path.visibility != Visibility::Visible {
+                return;
+            }
+
+            let abs_transform = node.abs_transform();
+            let bbox = path.data.bounds().to_non_zero_rect();
+            let canvas_path = match path.data.as_ref().clone().transform(abs_transform) {
+                Some(v) => v,
+                None => return,
+            };
+
+            let fill = path.fill.as_ref().and_then(|fill| {
+                let paint = ResolvedPaint::new(&fill.paint, fill.opacity, bbox)?;
+                Some(DrawCommand::Fill {
+                    id: path.id.clone(),
+                    path: canvas_path.clone(),
+                    rule: fill.rule,
+                    paint: paint.pre_transform(abs_transform),
+                    abs_transform,
+                })
+            });
+
+            let stroke = path.stroke.as_ref().and_then(|stroke| {
+                let paint = ResolvedPaint::new(&stroke.paint, stroke.opacity, bbox)?;
+                Some(DrawCommand::Stroke {
+                    id: path.id.clone(),
+                    path: canvas_path.clone(),
+                    outline: stroke_outline(&path.data, stroke, abs_transform),
+                    stroke: stroke.clone(),
+                    paint: paint.pre_transform(abs_transform),
+                    abs_transform,
+                })
+            });
+
+            let (first, second) = match path.paint_order {
+                PaintOrder::FillAndStroke => (fill, stroke),
+                PaintOrder::StrokeAndFill => (stroke, fill),
+            };
+            list.extend(first);
+            list.extend(second);
+        }
+        NodeKind::Image(ref image) => {
+            if image.visibility != Visibility::Visible {
+                return;
+            }
+
+            list.push(DrawCommand::Image(EmbeddedImage {
+                id: image.id.clone(),
+                kind: image.kind.clone(),
+                view_box: image.view_box,
+                abs_transform: node.abs_transform(),
+            }));
+        }
+        NodeKind::Group(_) | NodeKind::Text(_) => {}
+    }
+}
+
+fn stroke_outline(
+    path: &tiny_skia_path::Path,
+    stroke: &Stroke,
+    transform: Transform,
+) -> Option<tiny_skia_path::Path> {
+    let res_scale = PathStroker::compute_resolution_scale(&transform);
+
+    let dashed;
+    let path = match stroke.dasharray {
+        Some(ref list) => {
+            let dash = tiny_skia_path::StrokeDash::new(list.clone(), stroke.dashoffset)?;
+            dashed = path.dash(&dash, res_scale)?;
+            &dashed
+        }
+        None => path,
+    };
+
+    let ts_stroke = tiny_skia_path::Stroke {
+        width: stroke.width.get(),
+        miter_limit: stroke.miterlimit.get(),
+        line_cap: match stroke.linecap {
+            LineCap::Butt => tiny_skia_path::LineCap::Butt,
+            LineCap::Round => tiny_skia_path::LineCap::Round,
+            LineCap::Square => tiny_skia_path::LineCap::Square,
+        },
+        line_join: match stroke.linejoin {
+            LineJoin::Miter => tiny_skia_path::LineJoin::Miter,
+            LineJoin::MiterClip => tiny_skia_path::LineJoin::MiterClip,
+            LineJoin::Round => tiny_skia_path::LineJoin::Round,
+            LineJoin::Bevel => tiny_skia_path::LineJoin::Bevel,
+        },
+        dash: None,
+    };
+
+    path.stroke(&ts_stroke, res_scale)?.transform(transform)
+}
@@ -18,9 +18,15 @@
 #![allow(clippy::too_many_arguments)]
 #![allow(clippy::derivable_impls)]
 
+#[cfg(feature = "boolean-ops")]
+mod boolean;
+mod draw_list;
 pub mod filter;
 mod geom;
+mod measure;
+mod resolved_paint;
 mod text;
+mod winding;
 
 use std::rc::Rc;
 use std::sync::Arc;
@@ -30,8 +36,14 @@ pub use svgtypes::{Align, AspectRatio};
 
 pub use tiny_skia_path;
 
+#[cfg(feature = "boolean-ops")]
+pub use crate::boolean::*;
+pub use crate::draw_list::*;
 pub use crate::geom::*;
+pub use crate::measure::*;
+pub use crate::resolved_paint::*;
 pub use crate::text::*;
+pub use crate::winding::*;
 
 /// An alias to `NormalizedF32`.
 pub type Opacity = NormalizedF32;
@@ -254,6 +266,11 @@ pub struct BaseGradient {
 
     /// A list of `stop` elements.
     pub stops: Vec<Stop>,
+
+    /// A color space used for stops interpolation.
+    ///
+    /// `color-interpolation` in SVG. Only `sRGB` and `linearRGB` are supported.
+    pub color_interpolation: filter::ColorInterpolation,
 }
 
 /// A linear gradient.
@@ -315,6 +332,55 @@ impl std::ops::Deref for RadialGradient {
     }
 }
 
+/// A conic gradient.
+///
+/// Not a part of SVG. Parsed from the CSS `conic-gradient()` function used
+/// as a `fill` or `stroke` value, which is produced by some design tools.
+///
+/// Figma exports angular gradients as a `foreignObject` containing an HTML `div`
+/// with a `conic-gradient()` background. Such `foreignObject` is converted into a rect
+/// of the same size filled with this gradient.
+///
+/// Since SVG has no conic gradients, it's written as a pattern of solid color wedges.
+///
+/// Colors are swept clockwise around the center, starting from the `angle` direction.
+///
+/// Unlike in CSS, a gradient with `objectBoundingBox` units (i.e. a center set in percentages)
+/// is stretched with the bounding box just like any other SVG gradient.
+/// This means that on non-square shapes the start angle and the stops are distorted.
+#[derive(Clone, Debug)]
+pub struct ConicGradient {
+    /// Gradient's ID.
+    ///
+    /// Generated by the parser, since the gradient is defined inline.
+    /// Used only during SVG writing.
+    pub id: String,
+
+    /// Center X coordinate.
+    pub cx: f32,
+
+    /// Center Y coordinate.
+    pub cy: f32,
+
+    /// Start angle in degrees.
+    ///
+    /// Zero points up and angles grow clockwise, like in CSS.
+    pub angle: f32,
+
+    /// Base gradient data.
+    ///
+    /// `spread_method` is always `Pad`, since a conic gradient has no gaps to fill.
+    pub base: BaseGradient,
+}
+
+impl std::ops::Deref for ConicGradient {
+    type Target = BaseGradient;
+
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
 /// An alias to `NormalizedF32`.
 pub type StopOffset = NormalizedF32;
 
@@ -580,6 +646,7 @@ pub enum Paint {
     Color(Color),
     LinearGradient(Rc<LinearGradient>),
     RadialGradient(Rc<RadialGradient>),
+    ConicGradient(Rc<ConicGradient>),
     Pattern(Rc<Pattern>),
 }
 
@@ -593,6 +660,7 @@ impl Paint {
             Self::Color(_) => None,
             Self::LinearGradient(ref lg) => Some(lg.units),
             Self::RadialGradient(ref rg) => Some(rg.units),
+            Self::ConicGradient(ref cg) => Some(cg.units),
             Self::Pattern(ref patt) => Some(patt.units),
         }
     }
@@ -605,6 +673,7 @@ impl PartialEq for Paint {
             (Self::Color(lc), Self::Color(rc)) => lc == rc,
             (Self::LinearGradient(ref lg1), Self::LinearGradient(ref lg2)) => Rc::ptr_eq(lg1, lg2),
             (Self::RadialGradient(ref rg1), Self::RadialGradient(ref rg2)) => Rc::ptr_eq(rg1, rg2),
+            (Self::ConicGradient(ref cg1), Self::ConicGradient(ref cg2)) => Rc::ptr_eq(cg1, cg2),
             (Self::Pattern(ref p1), Self::Pattern(ref p2)) => Rc::ptr_eq(p1, p2),
             _ => false,
         }
@@ -701,6 +770,12 @@ pub struct Mask {
     /// `mask-type` in SVG.
     pub kind: MaskType,
 
+    /// A color space used for luminance calculation.
+    ///
+    /// `color-interpolation` in SVG. Only `sRGB` and `linearRGB` are supported.
+    /// Has no effect on alpha masks.
+    pub color_interpolation: filter::ColorInterpolation,
+
     /// Additional mask.
     ///
     /// `mask` in SVG.
@@ -742,6 +817,82 @@ impl NodeKind {
             NodeKind::Text(ref e) => e.transform,
         }
     }
+
+    /// Sets node's transform.
+    pub fn set_transform(&mut self, transform: Transform) {
+        match self {
+            NodeKind::Group(ref mut e) => e.transform = transform,
+            NodeKind::Path(ref mut e) => e.transform = transform,
+            NodeKind::Image(ref mut e) => e.transform = transform,
+            NodeKind::Text(ref mut e) => e.transform = transform,
+        }
+    }
+
+    /// Replaces the fill paint of a path or of all text spans and decorations.
+    ///
+    /// Elements without a fill are not affected.
+    /// Paths with a zero-sized bbox lose their fill when `paint` uses `objectBoundingBox` units,
+    /// just like during parsing.
+    pub fn set_fill_paint(&mut self, paint: Paint) {
+        match self {
+            NodeKind::Path(ref mut path) => {
+                if !path.can_use_paint(&paint) {
+                    path.fill = None;
+                } else if let Some(ref mut fill) = path.fill {
+                    fill.paint = paint;
+                }
+            }
+            NodeKind::Text(ref mut text) => {
+                for_each_text_style(text, &mut |fill, _| {
+                    if let Some(fill) = fill {
+                        fill.paint = paint.clone();
+                    }
+                });
+            }
+            NodeKind::Group(_) | NodeKind::Image(_) => {}
+        }
+    }
+
+    /// Replaces the stroke paint of a path or of all text spans and decorations.
+    ///
+    /// Elements without a stroke are not affected.
+    /// Paths with a zero-sized bbox lose their stroke when `paint` uses `objectBoundingBox` units,
+    /// just like during parsing.
+    pub fn set_stroke_paint(&mut self, paint: Paint) {
+        match self {
+            NodeKind::Path(ref mut path) => {
+                if !path.can_use_paint(&paint) {
+                    path.stroke = None;
+                } else if let Some(ref mut stroke) = path.stroke {
+                    stroke.paint = paint;
+                }
+            }
+            NodeKind::Text(ref mut text) => {
+                for_each_text_style(text, &mut |_, stroke| {
+                    if let Some(stroke) = stroke {
+                        stroke.paint = paint.clone();
+                    }
+                });
+            }
+            NodeKind::Group(_) | NodeKind::Image(_) => {}
+        }
+    }
+}
+
+fn for_each_text_style(text: &mut Text, f: &mut dyn FnMut(&mut Option<Fill>, &mut Option<Stroke>)) {
+    for span in text.chunks.iter_mut().flat_map(|c| c.spans.iter_mut()) {
+        f(&mut span.fill, &mut span.stroke);
+
+        let decoration = &mut span.decoration;
+        for style in decoration
+            .underline
+            .iter_mut()
+            .chain(decoration.overline.iter_mut())
+            .chain(decoration.line_through.iter_mut())
+        {
+            f(&mut style.fill, &mut style.stroke);
+        }
+    }
 }
 
 /// A group container.
@@ -786,6 +937,17 @@ pub struct Group {
 
     /// Element's filters.
     pub filters: Vec<Rc<filter::Filter>>,
+
+    /// The `use` element this group was instantiated from.
+    ///
+    /// Set only when `usvg::Options::preserve_use_sources` is enabled.
+    /// All children of this group are the expanded content of the referenced element.
+    pub use_source: Option<UseSource>,
+
+    /// Attributes of the source element that are not supported by `usvg`.
+    ///
+    /// Set only when `usvg::Options::preserve_unknown_attributes` is enabled.
+    pub unknown_attributes: Vec<UnknownAttribute>,
 }
 
 impl Default for Group {
@@ -799,6 +961,8 @@ impl Default for Group {
             clip_path: None,
             mask: None,
             filters: Vec::new(),
+            use_source: None,
+            unknown_attributes: Vec::new(),
         }
     }
 }
@@ -815,6 +979,40 @@ impl Group {
     }
 }
 
+/// A link between an expanded `use` element and its source.
+#[derive(Clone, PartialEq, Debug)]
+pub struct UseSource {
+    /// The `use` element ID.
+    ///
+    /// Can be empty.
+    pub use_id: String,
+
+    /// The referenced element ID.
+    pub def_id: String,
+}
+
+/// An attribute that is not supported by `usvg`.
+///
+/// Like `data-*` attributes or attributes in a foreign namespace.
+#[derive(Clone, PartialEq, Debug)]
+pub struct UnknownAttribute {
+    /// The attribute namespace URI.
+    ///
+    /// Empty for attributes without a namespace.
+    pub namespace: String,
+
+    /// The namespace prefix used in the source document.
+    ///
+    /// Empty for attributes without a namespace.
+    pub prefix: String,
+
+    /// The attribute local name.
+    pub name: String,
+
+    /// The attribute value.
+    pub value: String,
+}
+
 /// Representation of the [`paint-order`] property.
 ///
 /// `usvg` will handle `markers` automatically,
@@ -902,6 +1100,54 @@ impl Path {
             data,
         }
     }
+
+    /// Replaces path segments.
+    ///
+    /// Also resets [`Path::text_bbox`], since it no longer matches the geometry.
+    pub fn set_data(&mut self, data: Rc<tiny_skia_path::Path>) {
+        self.data = data;
+        self.text_bbox = None;
+    }
+
+    fn can_use_paint(&self, paint: &Paint) -> bool {
+        paint.units() != Some(Units::ObjectBoundingBox)
+            || self.data.bounds().to_non_zero_rect().is_some()
+    }
+
+    /// Returns the total path length.
+    ///
+    /// Path transform is not applied.
+    pub fn length(&self) -> f64 {
+        path_length(&self.data)
+    }
+
+    /// Returns a point at the specified distance along the path.
+    ///
+    /// Path transform is not applied.
+    pub fn point_at_length(&self, distance: f64) -> Option<PathPoint> {
+        point_at_length(&self.data, distance)
+    }
+
+    /// Normalizes subpaths orientation using the fill rule.
+    ///
+    /// The non-zero rule is used when the path has no fill.
+    /// See [`normalize_winding`] for details.
+    ///
+    /// Paths with a dashed stroke are left as is, since reversing a subpath
+    /// would move its dashes.
+    ///
+    /// Path transform is not applied.
+    pub fn normalize_winding(&mut self, outer: Orientation) {
+        if self.stroke.as_ref().is_some_and(|s| s.dasharray.is_some()) {
+            return;
+        }
+
+        let rule = self.fill.as_ref().map(|f| f.rule).unwrap_or_default();
+        // The geometry stays the same, so `text_bbox` is still valid.
+        if let Some(data) = normalize_winding(&self.data, rule, outer) {
+            self.data = Rc::new(data);
+        }
+    }
 }
 
 /// An embedded image kind.
@@ -961,6 +1207,30 @@ pub struct Image {
     pub kind: ImageKind,
 }
 
+/// An image found in a [`Tree`].
+///
+/// Returned by [`Tree::images`].
+#[derive(Clone, Debug)]
+pub struct EmbeddedImage {
+    /// Element's ID.
+    ///
+    /// Can be empty.
+    pub id: String,
+
+    /// Image data.
+    ///
+    /// Raster images are stored in their original encoding.
+    pub kind: ImageKind,
+
+    /// An image rectangle in which it should be fit, in the element's user space.
+    pub view_box: ViewBox,
+
+    /// Absolute transform that maps the element's user space onto the canvas.
+    ///
+    /// Includes the element's own transform.
+    pub abs_transform: Transform,
+}
+
 /// Alias for `rctree::Node<NodeKind>`.
 pub type Node = rctree::Node<NodeKind>;
 
@@ -987,6 +1257,17 @@ pub struct Tree {
     ///
     /// The root node is always `Group`.
     pub root: Node,
+
+    /// Whether the content should be clipped by the viewport,
+    /// i.e. by a rectangle at the origin with the tree's `size`.
+    ///
+    /// `false` when the root `svg` element has `overflow` set to `visible` or `auto`.
+    pub clip_to_viewport: bool,
+
+    /// Fonts embedded via CSS `@font-face` rules.
+    ///
+    /// Should be made available during text-to-path conversion.
+    pub font_faces: Vec<FontFace>,
 }
 
 impl Tree {
@@ -1003,11 +1284,89 @@ impl Tree {
         self.root.descendants().find(|node| &*node.id() == id)
     }
 
+    /// Returns a resolved fill paint of a path with the specified ID.
+    ///
+    /// Returns `None` when there is no such path or it has no fill.
+    /// Text must be converted into paths first.
+    pub fn resolved_paint(&self, id: &str) -> Option<ResolvedPaint> {
+        let node = self.node_by_id(id)?;
+        let kind = node.borrow();
+        let path = match *kind {
+            NodeKind::Path(ref path) => path,
+            _ => return None,
+        };
+
+        let fill = path.fill.as_ref()?;
+        let bbox = path.data.bounds().to_non_zero_rect();
+        ResolvedPaint::new(&fill.paint, fill.opacity, bbox)
+    }
+
+    /// Returns a resolved stroke paint of a path with the specified ID.
+    ///
+    /// Returns `None` when there is no such path or it has no stroke.
+    /// Text must be converted into paths first.
+    pub fn resolved_stroke_paint(&self, id: &str) -> Option<ResolvedPaint> {
+        let node = self.node_by_id(id)?;
+        let kind = node.borrow();
+        let path = match *kind {
+            NodeKind::Path(ref path) => path,
+            _ => return None,
+        };
+
+        let stroke = path.stroke.as_ref()?;
+        let bbox = path.data.bounds().to_non_zero_rect();
+        ResolvedPaint::new(&stroke.paint, stroke.opacity, bbox)
+    }
+
+    /// Returns an iterator over all images in the tree.
+    ///
+    /// Images inside clip paths, masks, patterns and filters are not included,
+    /// since they are not placed onto the canvas directly.
+    pub fn images(&self) -> impl Iterator<Item = EmbeddedImage> + '_ {
+        self.root.descendants().filter_map(|node| {
+            if let NodeKind::Image(ref image) = *node.borrow() {
+                Some(EmbeddedImage {
+                    id: image.id.clone(),
+                    kind: image.kind.clone(),
+                    view_box: image.view_box,
+                    abs_transform: node.abs_transform(),
+                })
+            } else {
+                None
+            }
+        })
+    }
+
     /// Checks if the current tree has any text nodes.
     pub fn has_text_nodes(&self) -> bool {
         has_text_nodes(&self.root)
     }
 
+    /// Calls a closure for each node in the tree, allowing it to be modified.
+    ///
+    /// Nodes are visited in document order. Clip paths, masks, patterns
+    /// and `feImage` subtrees are visited right after the node that references them,
+    /// so subtrees that are no longer referenced after `f` was called are skipped.
+    /// Shared subtrees are visited only once.
+    ///
+    /// Can be used to implement custom passes, like recoloring, without rebuilding the tree.
+    /// See [`NodeKind::set_transform`], [`NodeKind::set_fill_paint`],
+    /// [`NodeKind::set_stroke_paint`] and [`Path::set_data`].
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// fn recolor(tree: &mut usvg_tree::Tree) {
+    ///     tree.postprocess(|node| {
+    ///         node.set_fill_paint(usvg_tree::Paint::Color(usvg_tree::Color::black()));
+    ///     });
+    /// }
+    /// ```
+    pub fn postprocess<F: FnMut(&mut NodeKind)>(&mut self, mut f: F) {
+        let mut visited = Vec::new();
+        postprocess_nodes(&self.root, &mut visited, &mut f)
+    }
+
     /// Calls a closure for each [`Paint`] in the tree.
     ///
     /// Doesn't guarantee to have unique paint servers. A caller must deduplicate them manually.
@@ -1065,6 +1424,21 @@ fn has_text_nodes(root: &Node) -> bool {
     false
 }
 
+fn postprocess_nodes(root: &Node, visited: &mut Vec<Node>, f: &mut dyn FnMut(&mut NodeKind)) {
+    for node in root.descendants() {
+        f(&mut node.borrow_mut());
+
+        let mut subroots = Vec::new();
+        node.subroots(|subroot| subroots.push(subroot));
+        for subroot in subroots {
+            if !visited.contains(&subroot) {
+                visited.push(subroot.clone());
+                postprocess_nodes(&subroot, visited, f);
+            }
+        }
+    }
+}
+
 fn loop_over_paint_servers(root: &Node, f: &mut dyn FnMut(&Paint)) {
     fn push(paint: Option<&Paint>, f: &mut dyn FnMut(&Paint)) {
         if let Some(paint) = paint {
@@ -1110,8 +1484,10 @@ fn loop_over_clip_paths(root: &Node, f: &mut dyn FnMut(Rc<ClipPath>)) {
             if let Some(ref clip) = g.clip_path {
                 f(clip.clone());
 
-                if let Some(ref sub_clip) = clip.clip_path {
-                    f(sub_clip.clone());
+                let mut sub_clip = clip.clip_path.clone();
+                while let Some(clip) = sub_clip {
+                    f(clip.clone());
+                    sub_clip = clip.clip_path.clone();
                 }
             }
         }
@@ -1126,8 +1502,10 @@ fn loop_over_masks(root: &Node, f: &mut dyn FnMut(Rc<Mask>)) {
             if let Some(ref mask) = g.mask {
                 f(mask.clone());
 
-                if let Some(ref sub_mask) = mask.mask {
-                    f(sub_mask.clone());
+                let mut sub_mask = mask.mask.clone();
+                while let Some(mask) = sub_mask {
+                    f(mask.clone());
+                    sub_mask = mask.mask.clone();
                 }
             }
         }
@@ -1174,16 +1552,20 @@ fn node_subroots(node: &Node, f: &mut dyn FnMut(Node)) {
             if let Some(ref clip) = g.clip_path {
                 f(clip.root.clone());
 
-                if let Some(ref sub_clip) = clip.clip_path {
-                    f(sub_clip.root.clone());
+                let mut sub_clip = clip.clip_path.as_ref();
+                while let Some(clip) = sub_clip {
+                    f(clip.root.clone());
+                    sub_clip = clip.clip_path.as_ref();
                 }
             }
 
             if let Some(ref mask) = g.mask {
                 f(mask.root.clone());
 
-                if let Some(ref sub_mask) = mask.mask {
-                    f(sub_mask.root.clone());
+                let mut sub_mask = mask.mask.as_ref();
+                while let Some(mask) = sub_mask {
+                    f(mask.root.clone());
+                    sub_mask = mask.mask.as_ref();
                 }
             }
 
@@ -1260,6 +1642,19 @@ pub trait NodeExt {
     /// without converting it into paths first.
     fn calculate_bbox(&self) -> Option<Rect>;
 
+    /// Calculates node's approximate absolute bounding box.
+    ///
+    /// Uses curves control points and transformed bounding boxes of children instead
+    /// of the exact geometry, without solving for curves extrema. This makes it cheap
+    /// and the result always contains the exact bounding box, but can be larger,
+    /// especially for curves and rotated paths.
+    ///
+    /// Currently returns the same result as [`NodeExt::calculate_bbox`].
+    ///
+    /// Always returns `None` for `NodeKind::Text` since we cannot calculate its bbox
+    /// without converting it into paths first.
+    fn calculate_approx_bbox(&self) -> Option<Rect>;
+
     /// Calls a closure for each subroot this `Node` has.
     ///
     /// The [`Tree::root`](Tree::root) field contain only render-able SVG elements.
@@ -1322,6 +1717,12 @@ impl NodeExt for Node {
         calc_node_bbox(self, self.abs_transform()).and_then(|r| r.to_rect())
     }
 
+    #[inline]
+    fn calculate_approx_bbox(&self) -> Option<Rect> {
+        // Path bounds are already based on control points.
+        self.calculate_bbox()
+    }
+
     fn subroots<F: FnMut(Node)>(&self, mut f: F) {
         node_subroots(self, &mut f)
     }
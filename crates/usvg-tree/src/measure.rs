@@ -0,0 +1,136 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use kurbo::{ParamCurve, ParamCurveArclen, ParamCurveDeriv};
+use tiny_skia_path::{PathSegment, Point};
+
+/// Arc length computation accuracy in user units.
+const ARCLEN_ACCURACY: f64 = 0.01;
+
+/// A point on a path, returned by [`point_at_length`].
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct PathPoint {
+    /// Point coordinates.
+    pub point: Point,
+
+    /// Tangent direction at this point, in degrees.
+    ///
+    /// Measured clockwise from the positive X axis, just like marker angles.
+    pub angle: f32,
+
+    /// Zero-based index of the subpath the point belongs to.
+    ///
+    /// Each `MoveTo` starts a new subpath.
+    pub subpath: usize,
+}
+
+/// Calculates the total length of a path.
+///
+/// The length of a path with multiple subpaths is the sum of its subpath lengths.
+/// Jumps caused by `MoveTo` are not included.
+pub fn path_length(path: &tiny_skia_path::Path) -> f64 {
+    segments(path)
+        .map(|(_, seg)| seg.arclen(ARCLEN_ACCURACY))
+        .sum()
+}
+
+/// Finds a point at the specified distance along a path.
+///
+/// Distance is measured from the start of the first subpath, the same way as in
+/// [`path_length`].
+///
+/// Returns `None` when distance is negative, not finite or larger than the path length.
+pub fn point_at_length(path: &tiny_skia_path::Path, distance: f64) -> Option<PathPoint> {
+    if !distance.is_finite() || distance < 0.0 {
+        return None;
+    }
+
+    let mut last = None;
+    let mut offset = 0.0;
+    for (subpath, seg) in segments(path) {
+        let length = seg.arclen(ARCLEN_ACCURACY);
+        if distance <= offset + length {
+            let t = seg.inv_arclen(distance - offset, ARCLEN_ACCURACY);
+            return Some(make_point(&seg, t, subpath));
+        }
+
+        offset += length;
+        last = Some((subpath, seg));
+    }
+
+    // Allow a small error caused by the arc length approximation.
+    let (subpath, seg) = last?;
+    if distance - offset <= ARCLEN_ACCURACY {
+        return Some(make_point(&seg, 1.0, subpath));
+    }
+
+    None
+}
+
+fn make_point(seg: &kurbo::PathSeg, t: f64, subpath: usize) -> PathPoint {
+    let p = seg.eval(t);
+    let d = tangent(seg, t);
+    PathPoint {
+        point: Point::from_xy(p.x as f32, p.y as f32),
+        angle: d.y.atan2(d.x).to_degrees() as f32,
+        subpath,
+    }
+}
+
+fn tangent(seg: &kurbo::PathSeg, t: f64) -> kurbo::Vec2 {
+    let d = match seg {
+        kurbo::PathSeg::Line(line) => line.p1 - line.p0,
+        kurbo::PathSeg::Quad(quad) => quad.deriv().eval(t).to_vec2(),
+        kurbo::PathSeg::Cubic(cubic) => cubic.deriv().eval(t).to_vec2(),
+    };
+
+    // A derivative can be zero at the ends of a curve with coincident control points.
+    // Fallback to the chord direction in this case.
+    if d.hypot2() > 0.0 {
+        d
+    } else {
+        seg.end() - seg.start()
+    }
+}
+
+/// Iterates over non-degenerate path segments together with their subpath index.
+fn segments(path: &tiny_skia_path::Path) -> impl Iterator<Item = (usize, kurbo::PathSeg)> + '_ {
+    let mut subpath = None;
+    let mut start = kurbo::Point::ZERO;
+    let mut prev = kurbo::Point::ZERO;
+    path.segments().filter_map(move |seg| {
+        let seg = match seg {
+            PathSegment::MoveTo(p) => {
+                subpath = Some(subpath.map_or(0, |n| n + 1));
+                start = to_point(p);
+                prev = start;
+                return None;
+            }
+            PathSegment::LineTo(p) => kurbo::PathSeg::Line(kurbo::Line::new(prev, to_point(p))),
+            PathSegment::QuadTo(p1, p) => {
+                kurbo::PathSeg::Quad(kurbo::QuadBez::new(prev, to_point(p1), to_point(p)))
+            }
+            PathSegment::CubicTo(p1, p2, p) => kurbo::PathSeg::Cubic(kurbo::CubicBez::new(
+                prev,
+                to_point(p1),
+                to_point(p2),
+                to_point(p),
+            )),
+            PathSegment::Close => kurbo::PathSeg::Line(kurbo::Line::new(prev, start)),
+        };
+
+        prev = seg.end();
+
+        // Zero-length segments do not affect the length and have no direction.
+        if seg.start() == seg.end() && seg.arclen(ARCLEN_ACCURACY) == 0.0 {
+            return None;
+        }
+
+        Some((subpath.unwrap_or(0), seg))
+    })
+}
+
+fn to_point(p: Point) -> kurbo::Point {
+    kurbo::Point::new(p.x as f64, p.y as f64)
+}
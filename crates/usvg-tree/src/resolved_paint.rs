@@ -0,0 +1,187 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use std::rc::Rc;
+
+use strict_num::PositiveF32;
+use tiny_skia_path::{NonZeroRect, Transform};
+
+use crate::{Color, Opacity, Paint, Pattern, SpreadMethod, Stop, Units};
+
+/// A paint resolved for a specific element.
+///
+/// Returned by [`Tree::resolved_paint`](crate::Tree::resolved_paint)
+/// and [`Tree::resolved_stroke_paint`](crate::Tree::resolved_stroke_paint).
+///
+/// All gradient geometry is in the element's user space,
+/// i.e. `objectBoundingBox` units are already applied.
+/// Stops, `currentColor` and `href` inheritance are already resolved.
+#[derive(Clone, Debug)]
+pub enum ResolvedPaint {
+    /// A solid color.
+    Color {
+        /// Paint color.
+        color: Color,
+        /// Paint opacity, i.e. `fill-opacity` or `stroke-opacity`.
+        opacity: Opacity,
+    },
+
+    /// A linear gradient.
+    LinearGradient {
+        /// Start point X coordinate.
+        x1: f32,
+        /// Start point Y coordinate.
+        y1: f32,
+        /// End point X coordinate.
+        x2: f32,
+        /// End point Y coordinate.
+        y2: f32,
+        /// Gradient transform in user space.
+        ///
+        /// Includes the object bounding box transform when needed.
+        transform: Transform,
+        /// Gradient spreading method.
+        spread_method: SpreadMethod,
+        /// Gradient stops.
+        stops: Vec<Stop>,
+        /// Paint opacity, i.e. `fill-opacity` or `stroke-opacity`.
+        opacity: Opacity,
+    },
+
+    /// A radial gradient.
+    RadialGradient {
+        /// Center X coordinate.
+        cx: f32,
+        /// Center Y coordinate.
+        cy: f32,
+        /// Radius.
+        r: PositiveF32,
+        /// Focal point X coordinate.
+        fx: f32,
+        /// Focal point Y coordinate.
+        fy: f32,
+        /// Gradient transform in user space.
+        ///
+        /// Includes the object bounding box transform when needed.
+        transform: Transform,
+        /// Gradient spreading method.
+        spread_method: SpreadMethod,
+        /// Gradient stops.
+        stops: Vec<Stop>,
+        /// Paint opacity, i.e. `fill-opacity` or `stroke-opacity`.
+        opacity: Opacity,
+    },
+
+    /// A conic gradient.
+    ConicGradient {
+        /// Center X coordinate.
+        cx: f32,
+        /// Center Y coordinate.
+        cy: f32,
+        /// Start angle in degrees.
+        angle: f32,
+        /// Gradient transform in user space.
+        ///
+        /// Includes the object bounding box transform when needed.
+        transform: Transform,
+        /// Gradient stops.
+        stops: Vec<Stop>,
+        /// Paint opacity, i.e. `fill-opacity` or `stroke-opacity`.
+        opacity: Opacity,
+    },
+
+    /// A pattern.
+    ///
+    /// Pattern content is not resolved, since it can contain arbitrary elements.
+    Pattern {
+        /// A referenced pattern.
+        pattern: Rc<Pattern>,
+        /// Paint opacity, i.e. `fill-opacity` or `stroke-opacity`.
+        opacity: Opacity,
+    },
+}
+
+impl ResolvedPaint {
+    /// Resolves a paint using the element's object bounding box.
+    ///
+    /// Returns `None` when a paint server uses `objectBoundingBox` units,
+    /// but the bounding box is not set.
+    pub(crate) fn new(paint: &Paint, opacity: Opacity, bbox: Option<NonZeroRect>) -> Option<Self> {
+        let resolve_transform = |units: Units, transform: Transform| -> Option<Transform> {
+            if units == Units::ObjectBoundingBox {
+                Some(Transform::from_bbox(bbox?).pre_concat(transform))
+            } else {
+                Some(transform)
+            }
+        };
+
+        let paint = match paint {
+            Paint::Color(color) => ResolvedPaint::Color {
+                color: *color,
+                opacity,
+            },
+            Paint::LinearGradient(ref lg) => ResolvedPaint::LinearGradient {
+                x1: lg.x1,
+                y1: lg.y1,
+                x2: lg.x2,
+                y2: lg.y2,
+                transform: resolve_transform(lg.units, lg.transform)?,
+                spread_method: lg.spread_method,
+                stops: lg.stops.clone(),
+                opacity,
+            },
+            Paint::RadialGradient(ref rg) => ResolvedPaint::RadialGradient {
+                cx: rg.cx,
+                cy: rg.cy,
+                r: rg.r,
+                fx: rg.fx,
+                fy: rg.fy,
+                transform: resolve_transform(rg.units, rg.transform)?,
+                spread_method: rg.spread_method,
+                stops: rg.stops.clone(),
+                opacity,
+            },
+            Paint::ConicGradient(ref cg) => ResolvedPaint::ConicGradient {
+                cx: cg.cx,
+                cy: cg.cy,
+                angle: cg.angle,
+                transform: resolve_transform(cg.units, cg.transform)?,
+                stops: cg.stops.clone(),
+                opacity,
+            },
+            Paint::Pattern(ref pattern) => {
+                if pattern.units == Units::ObjectBoundingBox {
+                    bbox?;
+                }
+
+                ResolvedPaint::Pattern {
+                    pattern: pattern.clone(),
+                    opacity,
+                }
+            }
+        };
+
+        Some(paint)
+    }
+
+    /// Maps gradients from the user space using `ts`.
+    ///
+    /// Patterns are left as is.
+    pub(crate) fn pre_transform(mut self, ts: Transform) -> Self {
+        match self {
+            ResolvedPaint::LinearGradient {
+                ref mut transform, ..
+            }
+            | ResolvedPaint::RadialGradient {
+                ref mut transform, ..
+            }
+            | ResolvedPaint::ConicGradient {
+                ref mut transform, ..
+            } => *transform = ts.pre_concat(*transform),
+            ResolvedPaint::Color { .. } | ResolvedPaint::Pattern { .. } => {}
+        }
+
+        self
+    }
+}
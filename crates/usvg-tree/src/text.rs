@@ -3,6 +3,7 @@
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
 use std::rc::Rc;
+use std::sync::Arc;
 
 use strict_num::NonZeroPositiveF32;
 
@@ -63,6 +64,36 @@ pub struct Font {
     pub weight: u16,
 }
 
+/// A font embedded into an SVG via a CSS `@font-face` rule.
+#[derive(Clone)]
+pub struct FontFace {
+    /// A family name under which the font should be available.
+    ///
+    /// `font-family` descriptor in CSS.
+    pub family: String,
+    /// A font style.
+    ///
+    /// `None` when not set, which means the font's own style should be used.
+    pub style: Option<FontStyle>,
+    /// A font weight.
+    ///
+    /// `None` when not set, which means the font's own weight should be used.
+    pub weight: Option<u16>,
+    /// Font data in the TrueType or OpenType format.
+    pub data: Arc<Vec<u8>>,
+}
+
+impl std::fmt::Debug for FontFace {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FontFace")
+            .field("family", &self.family)
+            .field("style", &self.style)
+            .field("weight", &self.weight)
+            .field("data", &format_args!("[{} bytes]", self.data.len()))
+            .finish()
+    }
+}
+
 /// A dominant baseline property.
 #[allow(missing_docs)]
 #[derive(Clone, Copy, PartialEq, Debug)]
@@ -167,6 +198,18 @@ pub struct TextDecoration {
     pub line_through: Option<TextDecorationStyle>,
 }
 
+/// An OpenType font feature.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct FontFeature {
+    /// A four-letter feature tag, like `liga`.
+    pub tag: [u8; 4],
+    /// A feature value.
+    ///
+    /// `0` disables a feature and `1` enables it.
+    /// Larger values select an alternate glyph.
+    pub value: u32,
+}
+
 /// A text style span.
 ///
 /// Spans do not overlap inside a text chunk.
@@ -198,6 +241,12 @@ pub struct TextSpan {
     ///
     /// Supports both `kerning` and `font-kerning` properties.
     pub apply_kerning: bool,
+    /// OpenType features that should be applied.
+    ///
+    /// Set by `font-feature-settings` and `font-variant`.
+    /// Small caps and kerning are controlled by `small_caps` and `apply_kerning` instead.
+    /// Features later in the list override earlier ones.
+    pub font_features: Vec<FontFeature>,
     /// A span decorations.
     pub decoration: TextDecoration,
     /// A span dominant baseline.
@@ -344,6 +393,11 @@ pub struct Text {
     /// A writing mode.
     pub writing_mode: WritingMode,
 
+    /// A maximum line length for automatic line wrapping.
+    ///
+    /// `inline-size` in SVG 2. `None` when text should not be wrapped.
+    pub inline_size: Option<f32>,
+
     /// A list of text chunks.
     pub chunks: Vec<TextChunk>,
 }
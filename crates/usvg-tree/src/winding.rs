@@ -0,0 +1,238 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use tiny_skia_path::{PathBuilder, PathSegment, Point};
+
+use crate::FillRule;
+
+/// Curves flattening tolerance in user units.
+const FLATTEN_TOLERANCE: f64 = 0.05;
+
+/// Subpaths with a smaller absolute area are left as is.
+const MIN_AREA: f64 = 1e-9;
+
+/// A contour orientation.
+///
+/// Orientation is defined as seen on the canvas, i.e. with the Y axis pointing down.
+#[allow(missing_docs)]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Orientation {
+    Clockwise,
+    CounterClockwise,
+}
+
+impl Orientation {
+    /// Returns the opposite orientation.
+    pub fn reversed(self) -> Self {
+        match self {
+            Orientation::Clockwise => Orientation::CounterClockwise,
+            Orientation::CounterClockwise => Orientation::Clockwise,
+        }
+    }
+}
+
+/// Normalizes subpaths orientation.
+///
+/// Outer contours are wound in the `outer` orientation and holes in the opposite one.
+/// A subpath is an outer contour when the area right inside of it is filled
+/// according to the fill `rule`, taking all other subpaths into account.
+///
+/// With the even-odd rule the filled area never changes. With the non-zero rule
+/// it stays the same as long as subpaths do not intersect each other.
+///
+/// Each subpath is treated as closed, like during filling.
+/// Self-intersecting subpaths do not have a single orientation. Such subpaths
+/// are classified using their signed area and the result is undefined.
+/// Subpaths without an area are left as is.
+///
+/// Reversing a subpath changes where a stroke dash pattern starts,
+/// so this should not be used on dashed paths. [`Path::normalize_winding`](crate::Path::normalize_winding)
+/// skips them.
+///
+/// Returns `None` when the path cannot be rebuilt.
+pub fn normalize_winding(
+    path: &tiny_skia_path::Path,
+    rule: FillRule,
+    outer: Orientation,
+) -> Option<tiny_skia_path::Path> {
+    let subpaths = split_subpaths(path);
+    let polygons: Vec<_> = subpaths.iter().map(|s| flatten(s)).collect();
+    let bboxes: Vec<_> = polygons.iter().map(|p| bounds(p)).collect();
+
+    let mut builder = PathBuilder::new();
+    for (i, subpath) in subpaths.iter().enumerate() {
+        let area = signed_area(&polygons[i]);
+        let sample = sample_point(&polygons[i]);
+        let (area, sample) = match sample {
+            Some(p) if area.abs() > MIN_AREA => (area, p),
+            _ => {
+                push_segments(subpath, &mut builder);
+                continue;
+            }
+        };
+
+        // A positive signed area is clockwise on a Y-down canvas.
+        let current = if area > 0.0 {
+            Orientation::Clockwise
+        } else {
+            Orientation::CounterClockwise
+        };
+
+        // A polygon cannot wind around a point outside of its bounding box.
+        let others: i32 = polygons
+            .iter()
+            .zip(bboxes.iter())
+            .enumerate()
+            .filter(|(j, (_, bbox))| *j != i && bbox.is_some_and(|r| r.contains(sample)))
+            .map(|(_, (polygon, _))| winding(polygon, sample))
+            .sum();
+        let winding = others + if area > 0.0 { 1 } else { -1 };
+        let is_filled = match rule {
+            FillRule::NonZero => winding != 0,
+            FillRule::EvenOdd => winding % 2 != 0,
+        };
+
+        let expected = if is_filled { outer } else { outer.reversed() };
+        if current == expected {
+            push_segments(subpath, &mut builder);
+        } else {
+            push_reversed_segments(subpath, &mut builder);
+        }
+    }
+
+    builder.finish()
+}
+
+/// Splits a path into subpaths, each starting with a `MoveTo`.
+fn split_subpaths(path: &tiny_skia_path::Path) -> Vec<Vec<PathSegment>> {
+    let mut subpaths: Vec<Vec<PathSegment>> = Vec::new();
+    for segment in path.segments() {
+        match (segment, subpaths.last_mut()) {
+            (PathSegment::MoveTo(_), _) | (_, None) => subpaths.push(vec![segment]),
+            (_, Some(subpath)) => subpath.push(segment),
+        }
+    }
+
+    subpaths
+}
+
+fn flatten(subpath: &[PathSegment]) -> Vec<kurbo::Point> {
+    let p = |p: Point| kurbo::Point::new(p.x as f64, p.y as f64);
+
+    let mut bez = kurbo::BezPath::new();
+    for segment in subpath {
+        match *segment {
+            PathSegment::MoveTo(p0) => bez.move_to(p(p0)),
+            PathSegment::LineTo(p0) => bez.line_to(p(p0)),
+            PathSegment::QuadTo(p1, p0) => bez.quad_to(p(p1), p(p0)),
+            PathSegment::CubicTo(p1, p2, p0) => bez.curve_to(p(p1), p(p2), p(p0)),
+            PathSegment::Close => bez.close_path(),
+        }
+    }
+
+    let mut polygon = Vec::new();
+    bez.flatten(FLATTEN_TOLERANCE, |el| match el {
+        kurbo::PathEl::MoveTo(p) | kurbo::PathEl::LineTo(p) => polygon.push(p),
+        _ => {}
+    });
+    polygon.dedup();
+
+    polygon
+}
+
+fn bounds(polygon: &[kurbo::Point]) -> Option<kurbo::Rect> {
+    let first = *polygon.first()?;
+    Some(
+        polygon
+            .iter()
+            .fold(kurbo::Rect::from_points(first, first), |r, p| {
+                r.union_pt(*p)
+            }),
+    )
+}
+
+/// Calculates a signed polygon area using the shoelace formula.
+fn signed_area(polygon: &[kurbo::Point]) -> f64 {
+    let mut area = 0.0;
+    for (i, p1) in polygon.iter().enumerate() {
+        let p2 = polygon[(i + 1) % polygon.len()];
+        area += p1.x * p2.y - p2.x * p1.y;
+    }
+
+    area * 0.5
+}
+
+/// Returns a point on a polygon edge, away from its vertices.
+fn sample_point(polygon: &[kurbo::Point]) -> Option<kurbo::Point> {
+    if polygon.len() < 2 {
+        return None;
+    }
+
+    Some(polygon[0].midpoint(polygon[1]))
+}
+
+/// Calculates the winding number of a closed polygon around a point.
+///
+/// A polygon with a positive signed area has a winding number of 1.
+fn winding(polygon: &[kurbo::Point], p: kurbo::Point) -> i32 {
+    let mut winding = 0;
+    for (i, p1) in polygon.iter().enumerate() {
+        let p2 = polygon[(i + 1) % polygon.len()];
+        let side = (p2 - *p1).cross(p - *p1);
+        if p1.y <= p.y {
+            if p2.y > p.y && side > 0.0 {
+                winding += 1;
+            }
+        } else if p2.y <= p.y && side < 0.0 {
+            winding -= 1;
+        }
+    }
+
+    winding
+}
+
+fn push_segments(subpath: &[PathSegment], builder: &mut PathBuilder) {
+    for segment in subpath {
+        match *segment {
+            PathSegment::MoveTo(p) => builder.move_to(p.x, p.y),
+            PathSegment::LineTo(p) => builder.line_to(p.x, p.y),
+            PathSegment::QuadTo(p1, p) => builder.quad_to(p1.x, p1.y, p.x, p.y),
+            PathSegment::CubicTo(p1, p2, p) => builder.cubic_to(p1.x, p1.y, p2.x, p2.y, p.x, p.y),
+            PathSegment::Close => builder.close(),
+        }
+    }
+}
+
+fn push_reversed_segments(subpath: &[PathSegment], builder: &mut PathBuilder) {
+    // Each segment with its start point.
+    let mut segments = Vec::new();
+    let mut prev = Point::zero();
+    let mut is_closed = false;
+    for segment in subpath {
+        match *segment {
+            PathSegment::MoveTo(p) => prev = p,
+            PathSegment::LineTo(p) | PathSegment::QuadTo(_, p) | PathSegment::CubicTo(_, _, p) => {
+                segments.push((prev, *segment));
+                prev = p;
+            }
+            PathSegment::Close => is_closed = true,
+        }
+    }
+
+    builder.move_to(prev.x, prev.y);
+    for (start, segment) in segments.iter().rev() {
+        match *segment {
+            PathSegment::LineTo(_) => builder.line_to(start.x, start.y),
+            PathSegment::QuadTo(p1, _) => builder.quad_to(p1.x, p1.y, start.x, start.y),
+            PathSegment::CubicTo(p1, p2, _) => {
+                builder.cubic_to(p2.x, p2.y, p1.x, p1.y, start.x, start.y)
+            }
+            PathSegment::MoveTo(_) | PathSegment::Close => {}
+        }
+    }
+
+    if is_closed {
+        builder.close();
+    }
+}
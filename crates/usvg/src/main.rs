@@ -408,6 +408,7 @@ fn process(args: Args) -> Result<(), String> {
             .unwrap_or("Times New Roman")
             .to_string(),
         font_size: args.font_size as f32,
+        font_substitutions: std::collections::HashMap::new(),
         languages: args.languages,
         shape_rendering: args.shape_rendering,
         text_rendering: args.text_rendering,
@@ -418,6 +419,14 @@ fn process(args: Args) -> Result<(), String> {
         )
         .unwrap(),
         image_href_resolver: usvg_parser::ImageHrefResolver::default(),
+        href_rewrite: None,
+        color_transform: None,
+        grid_snap: None,
+        preserve_use_sources: false,
+        preserve_unknown_attributes: false,
+        normalize_winding: false,
+        max_image_pixels: None,
+        max_image_bytes: None,
     };
 
     let input_svg = match in_svg {
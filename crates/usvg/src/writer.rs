@@ -66,6 +66,9 @@ pub(crate) fn convert(tree: &Tree, opt: &XmlOptions) -> String {
     xml.write_svg_attribute(AId::Width, &tree.size.width());
     xml.write_svg_attribute(AId::Height, &tree.size.height());
     xml.write_viewbox(&tree.view_box);
+    if !tree.clip_to_viewport {
+        xml.write_svg_attribute(AId::Overflow, "visible");
+    }
     xml.write_attribute("xmlns", "http://www.w3.org/2000/svg");
     if has_xlink(tree) {
         xml.write_attribute("xmlns:xlink", "http://www.w3.org/1999/xlink");
@@ -497,6 +500,7 @@ fn conv_defs(tree: &Tree, opt: &XmlOptions, xml: &mut XmlWriter) {
                 write_base_grad(&rg.base, xml, opt);
                 xml.end_element();
             }
+            Paint::ConicGradient(cg) => write_conic_gradient(&cg, tree, opt, xml),
             Paint::Pattern(pattern) => {
                 xml.start_svg_element(EId::Pattern);
                 xml.write_id_attribute(&pattern.id, opt);
@@ -557,6 +561,9 @@ fn conv_defs(tree: &Tree, opt: &XmlOptions, xml: &mut XmlWriter) {
         if mask.kind == MaskType::Alpha {
             xml.write_svg_attribute(AId::MaskType, "alpha");
         }
+        if mask.color_interpolation == filter::ColorInterpolation::LinearRGB {
+            xml.write_svg_attribute(AId::ColorInterpolation, "linearRGB");
+        }
         xml.write_units(AId::MaskUnits, mask.units, Units::ObjectBoundingBox);
         xml.write_units(
             AId::MaskContentUnits,
@@ -633,6 +640,8 @@ fn conv_element(node: &Node, is_clip_path: bool, opt: &XmlOptions, xml: &mut Xml
                 xml.write_id_attribute(&g.id, opt);
             };
 
+            write_unknown_attributes(&g.unknown_attributes, xml);
+
             if let Some(ref clip) = g.clip_path {
                 xml.write_func_iri(AId::ClipPath, &clip.id, opt);
             }
@@ -696,6 +705,64 @@ fn conv_element(node: &Node, is_clip_path: bool, opt: &XmlOptions, xml: &mut Xml
     }
 }
 
+fn write_unknown_attributes(attrs: &[UnknownAttribute], xml: &mut XmlWriter) {
+    const SVG_NS: &str = "http://www.w3.org/2000/svg";
+    const XML_NS: &str = "http://www.w3.org/XML/1998/namespace";
+
+    // Namespaces are declared on the element itself, since they are not known
+    // to the root element.
+    let mut prefixes: Vec<(&str, String)> = Vec::new();
+    for attr in attrs {
+        if attr.namespace.is_empty() || attr.namespace == SVG_NS || attr.namespace == XML_NS {
+            continue;
+        }
+
+        if prefixes.iter().any(|(ns, _)| *ns == attr.namespace) {
+            continue;
+        }
+
+        // A prefix can be missing or already taken by another namespace.
+        let is_taken =
+            |prefix: &str, prefixes: &[(&str, String)]| prefixes.iter().any(|(_, p)| p == prefix);
+        let prefix = if !attr.prefix.is_empty() && !is_taken(&attr.prefix, &prefixes) {
+            attr.prefix.clone()
+        } else {
+            (1..)
+                .map(|n| format!("ns{}", n))
+                .find(|p| !is_taken(p, &prefixes) && !attrs.iter().any(|a| a.prefix == *p))
+                .unwrap()
+        };
+
+        xml.write_attribute_fmt(
+            &format!("xmlns:{}", prefix),
+            format_args!("{}", escape_attribute_value(&attr.namespace)),
+        );
+        prefixes.push((&attr.namespace, prefix));
+    }
+
+    for attr in attrs {
+        let name = if attr.namespace.is_empty() || attr.namespace == SVG_NS {
+            attr.name.clone()
+        } else if attr.namespace == XML_NS {
+            format!("xml:{}", attr.name)
+        } else {
+            let prefix = prefixes
+                .iter()
+                .find(|(ns, _)| *ns == attr.namespace)
+                .map(|(_, prefix)| prefix.as_str())
+                .unwrap_or_default();
+            format!("{}:{}", prefix, attr.name)
+        };
+
+        xml.write_attribute(&name, &escape_attribute_value(&attr.value));
+    }
+}
+
+// `XmlWriter` escapes only quotes.
+fn escape_attribute_value(value: &str) -> String {
+    value.replace('&', "&amp;").replace('<', "&lt;")
+}
+
 trait XmlWriterExt {
     fn start_svg_element(&mut self, id: EId);
     fn write_svg_attribute<V: Display + ?Sized>(&mut self, id: AId, value: &V);
@@ -995,6 +1062,10 @@ fn write_base_grad(g: &BaseGradient, xml: &mut XmlWriter, opt: &XmlOptions) {
         SpreadMethod::Repeat => xml.write_svg_attribute(AId::SpreadMethod, "repeat"),
     }
 
+    if g.color_interpolation == filter::ColorInterpolation::LinearRGB {
+        xml.write_svg_attribute(AId::ColorInterpolation, "linearRGB");
+    }
+
     for s in &g.stops {
         xml.start_svg_element(EId::Stop);
         xml.write_svg_attribute(AId::Offset, &s.offset.get());
@@ -1142,7 +1213,14 @@ fn write_tspan(
             .families
             .iter()
             .filter(|&family| family != &default_options.font_family)
-            .cloned()
+            .map(|family| match family.as_str() {
+                "serif" | "sans-serif" | "cursive" | "fantasy" | "monospace" => family.clone(),
+                // Prefer a quote that is not a part of the name, since escapes are not
+                // resolved by the parser.
+                _ if !family.contains('\'') => format!("'{}'", family),
+                _ if !family.contains('"') => format!("\"{}\"", family),
+                _ => format!("'{}'", family.replace('\'', "\\'")),
+            })
             .collect::<Vec<_>>()
             .join(","),
     );
@@ -1187,6 +1265,14 @@ fn write_tspan(
     if span.small_caps {
         xml.write_svg_attribute(AId::FontVariant, "small-caps");
     }
+    if !span.font_features.is_empty() {
+        let list: Vec<_> = span
+            .font_features
+            .iter()
+            .map(|f| format!("\"{}\" {}", String::from_utf8_lossy(&f.tag), f.value))
+            .collect();
+        xml.write_svg_attribute(AId::FontFeatureSettings, &list.join(", "));
+    }
     if let Some(text_length) = span.text_length {
         xml.write_svg_attribute(AId::TextLength, &text_length);
     }
@@ -1227,6 +1313,9 @@ fn write_text_chunk(
     if let Some(y) = chunk.y {
         xml.write_svg_attribute(AId::Y, &y);
     }
+    if let Some(inline_size) = text.inline_size {
+        xml.write_svg_attribute(AId::InlineSize, &inline_size);
+    }
 
     match text.rendering_mode {
         TextRendering::OptimizeSpeed => {
@@ -1390,10 +1479,219 @@ fn write_paint(aid: AId, paint: &Paint, opt: &XmlOptions, xml: &mut XmlWriter) {
         Paint::Color(c) => xml.write_color(aid, *c),
         Paint::LinearGradient(ref lg) => xml.write_func_iri(aid, &lg.id, opt),
         Paint::RadialGradient(ref rg) => xml.write_func_iri(aid, &rg.id, opt),
+        Paint::ConicGradient(ref cg) => xml.write_func_iri(aid, &cg.id, opt),
         Paint::Pattern(ref patt) => xml.write_func_iri(aid, &patt.id, opt),
     }
 }
 
+/// Writes a conic gradient as a pattern of solid color wedges, since SVG has no conic gradients.
+///
+/// Wedges are split finely enough for neighbors to differ by at most one color step
+/// and are not anti-aliased, so the result matches the gradient and has no seams.
+/// Colors are always interpolated in sRGB.
+fn write_conic_gradient(
+    cg: &Rc<ConicGradient>,
+    tree: &Tree,
+    opt: &XmlOptions,
+    xml: &mut XmlWriter,
+) {
+    let radius = conic_gradient_radius(cg, tree);
+    let rect =
+        match NonZeroRect::from_xywh(cg.cx - radius, cg.cy - radius, radius * 2.0, radius * 2.0)
+            .and_then(|r| r.transform(cg.transform))
+        {
+            Some(v) => v,
+            None => {
+                log::warn!("Failed to write a conic gradient.");
+                return;
+            }
+        };
+
+    xml.start_svg_element(EId::Pattern);
+    xml.write_id_attribute(&cg.id, opt);
+    xml.write_rect_attrs(rect);
+    xml.write_units(AId::PatternUnits, cg.units, Units::ObjectBoundingBox);
+    xml.write_units(AId::PatternContentUnits, cg.units, Units::UserSpaceOnUse);
+
+    // Pattern content is positioned relative to the tile.
+    let ts = Transform::from_translate(-rect.x(), -rect.y()).pre_concat(cg.transform);
+    xml.start_svg_element(EId::G);
+    xml.write_transform(AId::Transform, ts, opt);
+    xml.write_svg_attribute(AId::ShapeRendering, "crispEdges");
+
+    // Pad the stops, so they cover the whole turn.
+    let first = cg.stops.first();
+    let last = cg.stops.last();
+    let stops: Vec<_> = first
+        .map(|s| (0.0, *s))
+        .into_iter()
+        .chain(cg.stops.iter().map(|s| (s.offset.get(), *s)))
+        .chain(last.map(|s| (1.0, *s)))
+        .collect();
+
+    for pair in stops.windows(2) {
+        let ((offset1, stop1), (offset2, stop2)) = (pair[0], pair[1]);
+        if offset2 <= offset1 {
+            continue;
+        }
+
+        let channels = |s: Stop| {
+            [
+                s.color.red as f32,
+                s.color.green as f32,
+                s.color.blue as f32,
+                s.opacity.get() * 255.0,
+            ]
+        };
+        let (c1, c2) = (channels(stop1), channels(stop2));
+        let max_diff = c1
+            .iter()
+            .zip(c2.iter())
+            .fold(0.0f32, |max, (a, b)| max.max((a - b).abs()));
+
+        // A wedge must be narrower than a half turn to be a triangle.
+        let count = max_diff.max((offset2 - offset1) * 4.0).ceil().max(1.0) as u32;
+        let step = (offset2 - offset1) / count as f32;
+        // The outer edge of a wedge is a chord, so it must be moved out to cover the circle.
+        let wedge_radius = radius / (step * std::f32::consts::PI).cos();
+        for i in 0..count {
+            let t = (i as f32 + 0.5) / count as f32;
+            let c: Vec<f32> = c1
+                .iter()
+                .zip(c2.iter())
+                .map(|(a, b)| a + (b - a) * t)
+                .collect();
+
+            xml.start_svg_element(EId::Path);
+            xml.write_color(
+                AId::Fill,
+                Color::new_rgb(c[0].round() as u8, c[1].round() as u8, c[2].round() as u8),
+            );
+            if c[3] < 254.5 {
+                xml.write_svg_attribute(AId::FillOpacity, &(c[3] / 255.0));
+            }
+
+            let point_at = |offset: f32| {
+                let angle = (cg.angle + offset * 360.0).to_radians();
+                (
+                    cg.cx + wedge_radius * angle.sin(),
+                    cg.cy - wedge_radius * angle.cos(),
+                )
+            };
+            let p1 = point_at(offset1 + step * i as f32);
+            let p2 = point_at(offset1 + step * (i + 1) as f32);
+
+            let mut builder = tiny_skia_path::PathBuilder::new();
+            builder.move_to(cg.cx, cg.cy);
+            builder.line_to(p1.0, p1.1);
+            builder.line_to(p2.0, p2.1);
+            builder.close();
+            if let Some(path) = builder.finish() {
+                write_path_data(&path, opt, xml);
+            }
+
+            xml.end_element();
+        }
+    }
+
+    xml.end_element();
+    xml.end_element();
+}
+
+/// Returns a radius around the gradient center which covers everything painted with it.
+fn conic_gradient_radius(cg: &Rc<ConicGradient>, tree: &Tree) -> f32 {
+    let mut areas = Vec::new();
+    let mut has_text = false;
+    collect_conic_gradient_areas(&tree.root, cg, &mut areas, &mut has_text);
+
+    // Text bounds are unknown before text-to-path conversion.
+    if has_text || areas.is_empty() {
+        let area = match cg.units {
+            Units::ObjectBoundingBox => Rect::from_xywh(0.0, 0.0, 1.0, 1.0),
+            Units::UserSpaceOnUse => Some(tree.view_box.rect.to_rect()),
+        };
+        areas.extend(area);
+    }
+
+    let ts = cg.transform.invert().unwrap_or_default();
+    let mut radius: f32 = 0.0;
+    for area in areas {
+        for (x, y) in [
+            (area.left(), area.top()),
+            (area.right(), area.top()),
+            (area.right(), area.bottom()),
+            (area.left(), area.bottom()),
+        ] {
+            let mut p = tiny_skia_path::Point::from_xy(x, y);
+            ts.map_point(&mut p);
+            radius = radius.max(p.distance(tiny_skia_path::Point::from_xy(cg.cx, cg.cy)));
+        }
+    }
+
+    if radius.is_finite() && radius > 0.0 {
+        radius
+    } else {
+        1.0
+    }
+}
+
+/// Collects the areas of paths painted with a conic gradient in the gradient units.
+fn collect_conic_gradient_areas(
+    root: &Node,
+    cg: &Rc<ConicGradient>,
+    areas: &mut Vec<Rect>,
+    has_text: &mut bool,
+) {
+    let is_gradient = |paint: &Paint| match paint {
+        Paint::ConicGradient(ref other) => Rc::ptr_eq(other, cg),
+        _ => false,
+    };
+
+    for node in root.descendants() {
+        match *node.borrow() {
+            NodeKind::Path(ref path) => {
+                let bbox = path.data.bounds();
+                let mut area = None;
+                if path.fill.as_ref().is_some_and(|f| is_gradient(&f.paint)) {
+                    area = Some(bbox);
+                }
+
+                if let Some(ref stroke) = path.stroke {
+                    if is_gradient(&stroke.paint) {
+                        // Enough for miter joins and square caps.
+                        let w = stroke.width.get() / 2.0
+                            * stroke.miterlimit.get().max(std::f32::consts::SQRT_2);
+                        area = Rect::from_ltrb(
+                            bbox.left() - w,
+                            bbox.top() - w,
+                            bbox.right() + w,
+                            bbox.bottom() + w,
+                        );
+                    }
+                }
+
+                if cg.units == Units::ObjectBoundingBox {
+                    area = bbox
+                        .to_non_zero_rect()
+                        .and_then(|bbox| Transform::from_bbox(bbox).invert())
+                        .and_then(|ts| area?.transform(ts));
+                }
+
+                areas.extend(area);
+            }
+            NodeKind::Text(ref text) => {
+                for span in text.chunks.iter().flat_map(|chunk| &chunk.spans) {
+                    *has_text |= span.fill.as_ref().is_some_and(|f| is_gradient(&f.paint))
+                        || span.stroke.as_ref().is_some_and(|s| is_gradient(&s.paint));
+                }
+            }
+            _ => {}
+        }
+
+        node.subroots(|subroot| collect_conic_gradient_areas(&subroot, cg, areas, has_text));
+    }
+}
+
 fn write_light_source(light: &filter::LightSource, xml: &mut XmlWriter) {
     match light {
         filter::LightSource::DistantLight(ref light) => {